@@ -9,6 +9,12 @@ use crate::name::crate_name_token;
 #[deluxe(attributes(component))]
 struct ComponentAttributes {
     storage: Path,
+    #[deluxe(default)]
+    checksum: bool,
+    #[deluxe(default)]
+    map_entities: bool,
+    #[deluxe(default = None)]
+    version: Option<u32>,
     #[deluxe(default = None)]
     #[deluxe(rename = crate)]
     crate_name: Option<Ident>,
@@ -23,6 +29,9 @@ where
 
     let ComponentAttributes {
         storage,
+        checksum,
+        map_entities,
+        version,
         crate_name,
     } = extract_attributes(&mut input)?;
     let crate_name = match crate_name {
@@ -30,6 +39,26 @@ where
         None => crate_name_token("stream-ecs")?,
     };
     let trait_ident = quote! { #crate_name::component::Component };
+    let checksum = checksum.then(|| {
+        quote! {
+            const CHECKSUM: ::core::option::Option<fn(&Self, &mut dyn ::core::hash::Hasher)> =
+                ::core::option::Option::Some(#crate_name::component::hash_checksum::<Self>);
+        }
+    });
+
+    let map_entities = map_entities.then(|| {
+        quote! {
+            const MAP_ENTITIES: ::core::option::Option<
+                fn(&mut Self, &dyn #crate_name::entity::map::EntityMapper),
+            > = ::core::option::Option::Some(#crate_name::component::map_entities::<Self>);
+        }
+    });
+
+    let version = version.map(|version| {
+        quote! {
+            const VERSION: u32 = #version;
+        }
+    });
 
     let DeriveInput {
         ident, generics, ..
@@ -39,6 +68,9 @@ where
     let output = quote! {
         impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {
             type Storage = #storage;
+            #checksum
+            #map_entities
+            #version
         }
     };
     Ok(output)
@@ -7,7 +7,9 @@ use proc_macro::TokenStream;
 use syn::Error;
 
 mod component;
+mod map_entities;
 mod name;
+mod reflect;
 mod resource;
 
 #[proc_macro_derive(Component, attributes(component))]
@@ -23,3 +25,17 @@ pub fn resource_derive(input: TokenStream) -> TokenStream {
         .unwrap_or_else(Error::into_compile_error)
         .into()
 }
+
+#[proc_macro_derive(Reflect, attributes(reflect))]
+pub fn reflect_derive(input: TokenStream) -> TokenStream {
+    reflect::derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(MapEntities, attributes(map_entities))]
+pub fn map_entities_derive(input: TokenStream) -> TokenStream {
+    map_entities::derive(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
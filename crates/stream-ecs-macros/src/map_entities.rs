@@ -0,0 +1,120 @@
+use deluxe::{ExtractAttributes, extract_attributes};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Fields, GenericParam, Ident, Index, Member, Result,
+    parse_quote,
+};
+
+use crate::name::crate_name_token;
+
+#[derive(ExtractAttributes)]
+#[deluxe(attributes(map_entities))]
+struct MapEntitiesAttributes {
+    #[deluxe(default = None)]
+    #[deluxe(rename = crate)]
+    crate_name: Option<Ident>,
+}
+
+#[derive(ExtractAttributes)]
+#[deluxe(attributes(map_entities))]
+struct FieldAttributes {
+    #[deluxe(default)]
+    skip: bool,
+}
+
+pub fn derive<Input>(input: Input) -> Result<TokenStream>
+where
+    Input: Into<TokenStream>,
+{
+    let input = input.into();
+    let mut input = syn::parse2(input)?;
+
+    let MapEntitiesAttributes { crate_name } = extract_attributes(&mut input)?;
+    let crate_name = match crate_name {
+        Some(crate_name) => quote! { #crate_name },
+        None => crate_name_token("stream-ecs")?,
+    };
+    let trait_ident = quote! { #crate_name::entity::map::MapEntities };
+    let mapper = quote! { #crate_name::entity::map::EntityMapper };
+
+    let DeriveInput {
+        ident,
+        mut generics,
+        mut data,
+        ..
+    } = input;
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote! { #trait_ident });
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &mut data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let (pattern, bindings) = destructure(fields)?;
+            quote! {
+                let Self #pattern = self;
+                #(#trait_ident::map_entities(#bindings, mapper);)*
+            }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let arms = variants
+                .iter_mut()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let (pattern, bindings) = destructure(&mut variant.fields)?;
+                    Ok(quote! {
+                        Self::#ident #pattern => {
+                            #(#trait_ident::map_entities(#bindings, mapper);)*
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            let message = "`MapEntities` cannot be derived for unions";
+            return Err(syn::Error::new(ident.span(), message));
+        }
+    };
+
+    let output = quote! {
+        impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {
+            fn map_entities(&mut self, mapper: &dyn #mapper) {
+                let _ = mapper;
+                #body
+            }
+        }
+    };
+    Ok(output)
+}
+
+/// Creates a pattern which binds all the fields which are not skipped,
+/// returning the pattern and names of the bindings.
+fn destructure(fields: &mut Fields) -> Result<(TokenStream, Vec<Ident>)> {
+    let mut members = Vec::new();
+    let mut bindings = Vec::new();
+    for (index, field) in fields.iter_mut().enumerate() {
+        let FieldAttributes { skip } = extract_attributes(field)?;
+        if skip {
+            continue;
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        members.push(member);
+        bindings.push(format_ident!("field_{index}"));
+    }
+    let pattern = match fields {
+        Fields::Unit => quote! {},
+        _ => quote! { { #(#members: #bindings,)* .. } },
+    };
+    Ok((pattern, bindings))
+}
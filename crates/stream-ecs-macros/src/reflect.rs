@@ -0,0 +1,102 @@
+use deluxe::{ExtractAttributes, extract_attributes};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DataStruct, DeriveInput, Error, GenericParam, Ident, Index, Member, Result, parse_quote,
+};
+
+use crate::name::crate_name_token;
+
+#[derive(ExtractAttributes)]
+#[deluxe(attributes(reflect))]
+struct ReflectAttributes {
+    #[deluxe(default = None)]
+    #[deluxe(rename = crate)]
+    crate_name: Option<Ident>,
+}
+
+pub fn derive<Input>(input: Input) -> Result<TokenStream>
+where
+    Input: Into<TokenStream>,
+{
+    let input = input.into();
+    let mut input = syn::parse2(input)?;
+
+    let ReflectAttributes { crate_name } = extract_attributes(&mut input)?;
+    let crate_name = match crate_name {
+        Some(crate_name) => quote! { #crate_name },
+        None => crate_name_token("stream-ecs")?,
+    };
+    let trait_ident = quote! { #crate_name::reflect::Reflect };
+    let field_info = quote! { #crate_name::reflect::FieldInfo };
+    let error = quote! { #crate_name::reflect::TypeMismatchError };
+
+    let DeriveInput {
+        ident,
+        mut generics,
+        data,
+        ..
+    } = input;
+    let Data::Struct(DataStruct { fields, .. }) = data else {
+        let message = "`Reflect` can only be derived for structs";
+        return Err(Error::new(ident.span(), message));
+    };
+    for param in &mut generics.params {
+        if let GenericParam::Type(param) = param {
+            param.bounds.push(parse_quote! { #trait_ident });
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let len = fields.len();
+    let (members, names): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.to_string()),
+            None => (Member::Unnamed(Index::from(index)), index.to_string()),
+        })
+        .unzip();
+    let types = fields.iter().map(|field| &field.ty);
+    let indices: Vec<_> = (0..len).map(Index::from).collect();
+    let unused = members.is_empty().then(|| quote! { let _ = value; });
+
+    let output = quote! {
+        impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {
+            fn field_len(&self) -> usize {
+                #len
+            }
+
+            fn field_info(&self, index: usize) -> ::core::option::Option<#field_info> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(#field_info::new::<#types>(#names)),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn field_at(&self, index: usize) -> ::core::option::Option<&dyn #trait_ident> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(&self.#members),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn field_at_mut(&mut self, index: usize) -> ::core::option::Option<&mut dyn #trait_ident> {
+                match index {
+                    #(#indices => ::core::option::Option::Some(&mut self.#members),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn set(&mut self, value: &dyn #trait_ident) -> ::core::result::Result<(), #error> {
+                let ::core::option::Option::Some(value) = <dyn #trait_ident>::downcast_ref::<Self>(value) else {
+                    return ::core::result::Result::Err(#error::new(self, value));
+                };
+                #(#trait_ident::set(&mut self.#members, &value.#members)?;)*
+                #unused
+                ::core::result::Result::Ok(())
+            }
+        }
+    };
+    Ok(output)
+}
@@ -0,0 +1,156 @@
+use arrayvec::ArrayVec;
+
+use crate::{
+    component::registry::Registry as Components,
+    entity::{Entity, registry::TryRegistry as TryEntities},
+    reflect::Reflect,
+};
+
+use crate::component::storage::ErasedStorage;
+
+use super::{
+    CapacityError, ComponentSchema, DecodeError, MAGIC, SchemaMismatchError, UnmigratableError,
+    VERSION, VersionMismatchError, io::Reader, value::Table,
+};
+
+/// Source of migrations of components encoded with older versions of the component type.
+pub trait Migrations<E> {
+    /// Returns hash of the schema of the component type of provided version.
+    /// Returns [`None`] if the component of such version cannot be migrated to the current version.
+    fn hash(&self, schema: &ComponentSchema<E>, version: u32) -> Option<u64>;
+
+    /// Decodes the component of provided version and migrates it to the current version.
+    fn decode(
+        &self,
+        schema: &ComponentSchema<E>,
+        version: u32,
+        reader: &mut Reader<'_>,
+        table: &Table<'_, E>,
+        target: Option<(&mut dyn ErasedStorage, E)>,
+    ) -> Result<(), DecodeError>;
+}
+
+impl<E> Migrations<E> for () {
+    fn hash(&self, _: &ComponentSchema<E>, _: u32) -> Option<u64> {
+        None
+    }
+
+    fn decode(
+        &self,
+        schema: &ComponentSchema<E>,
+        version: u32,
+        _: &mut Reader<'_>,
+        _: &Table<'_, E>,
+        _: Option<(&mut dyn ErasedStorage, E)>,
+    ) -> Result<(), DecodeError> {
+        let error = UnmigratableError::new(schema.type_name(), version, schema.version());
+        Err(error.into())
+    }
+}
+
+/// Decodes the world from the buffer, returning all the created entities
+/// in the order they were encoded.
+///
+/// Buffer is fully checked before decoding,
+/// so nothing is created if the buffer contains any error.
+pub fn decode<E, C, M, const N: usize>(
+    entities: &mut E,
+    components: &mut C,
+    schema: &[ComponentSchema<E::Entity>],
+    migrations: &M,
+    buf: &[u8],
+) -> Result<ArrayVec<E::Entity, N>, DecodeError>
+where
+    E: TryEntities,
+    E::Entity: Reflect,
+    C: Components,
+    M: Migrations<E::Entity>,
+{
+    let mut reader = Reader::new(buf);
+    let magic = reader.read()?;
+    if magic != MAGIC {
+        return Err(DecodeError::InvalidHeader);
+    }
+    let version = reader.read_u16()?;
+    if version != VERSION {
+        let error = VersionMismatchError::new(VERSION, version);
+        return Err(error.into());
+    }
+    let len = reader.read_u32()? as usize;
+    if len > N {
+        let error = CapacityError::new(len, N);
+        return Err(error.into());
+    }
+
+    let table = Table { len, created: &[] };
+    sections(reader.clone(), components, schema, migrations, &table)?;
+
+    let Ok(created) = entities.try_create_many(len) else {
+        return Err(DecodeError::Entities);
+    };
+    let created: ArrayVec<_, N> = created.collect();
+    let table = Table {
+        len,
+        created: &created,
+    };
+    sections(reader, components, schema, migrations, &table)?;
+    Ok(created)
+}
+
+fn sections<E, C, M>(
+    mut reader: Reader<'_>,
+    components: &mut C,
+    schema: &[ComponentSchema<E>],
+    migrations: &M,
+    table: &Table<'_, E>,
+) -> Result<(), DecodeError>
+where
+    E: Entity + Reflect,
+    C: Components,
+    M: Migrations<E>,
+{
+    let sections = reader.read_u16()?;
+    for _ in 0..sections {
+        let type_name_len = reader.read_u16()?;
+        let type_name = reader.read_slice(type_name_len.into())?;
+        let Some(schema) = schema
+            .iter()
+            .find(|schema| schema.type_name().as_bytes() == type_name)
+        else {
+            return Err(DecodeError::UnknownComponent);
+        };
+        let hash = reader.read_u64()?;
+        let version = reader.read_u32()?;
+        let expected = if version == schema.version() {
+            Some(schema.hash())
+        } else {
+            migrations.hash(schema, version)
+        };
+        let Some(expected) = expected else {
+            let error = UnmigratableError::new(schema.type_name(), version, schema.version());
+            return Err(error.into());
+        };
+        if hash != expected {
+            let error = SchemaMismatchError::new(schema.type_name(), expected, hash);
+            return Err(error.into());
+        }
+        let Some(storage) = components
+            .iter_mut()
+            .find(|storage| storage.component_type_id() == schema.type_id())
+        else {
+            return Err(schema.not_registered().into());
+        };
+
+        let count = reader.read_u32()?;
+        for _ in 0..count {
+            let position = table.resolve(reader.read_u32()?)?;
+            let target = position.map(|entity| (&mut *storage, entity));
+            if version == schema.version() {
+                schema.decode(&mut reader, table, target)?;
+            } else {
+                migrations.decode(schema, version, &mut reader, table, target)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use core::any::Any;
+
+use crate::{
+    component::registry::Registry as Components, entity::registry::Registry as Entities,
+    reflect::Reflect,
+};
+
+use super::{ComponentSchema, EncodeError, MAGIC, VERSION, io::Writer, value::encode_value};
+
+/// Encodes all the entities of the registry and their components described by provided schema
+/// into the buffer, returning count of written bytes.
+///
+/// Components which storages were not registered in the component registry are skipped.
+pub fn encode<E, C>(
+    entities: &E,
+    components: &C,
+    schema: &[ComponentSchema<E::Entity>],
+    buf: &mut [u8],
+) -> Result<usize, EncodeError<E::Entity>>
+where
+    E: Entities,
+    E::Entity: Reflect + PartialEq,
+    C: Components,
+{
+    let mut writer = Writer::new(buf);
+    let position = |entity| {
+        let position = entities.iter().position(|other| other == entity)?;
+        u32::try_from(position).ok()
+    };
+    let storage = |schema: &ComponentSchema<E::Entity>| {
+        components
+            .iter()
+            .find(|storage| storage.component_type_id() == schema.type_id())
+    };
+
+    writer.write(&MAGIC)?;
+    writer.write(&VERSION.to_le_bytes())?;
+    let len = u32::try_from(entities.len()).unwrap_or(u32::MAX);
+    writer.write(&len.to_le_bytes())?;
+    let sections = schema
+        .iter()
+        .filter(|schema| storage(schema).is_some())
+        .count();
+    let sections = u16::try_from(sections).unwrap_or(u16::MAX);
+    writer.write(&sections.to_le_bytes())?;
+
+    for schema in schema {
+        let Some(storage) = storage(schema) else {
+            continue;
+        };
+        let type_name = schema.type_name().as_bytes();
+        let type_name_len = u16::try_from(type_name.len()).unwrap_or(u16::MAX);
+        writer.write(&type_name_len.to_le_bytes())?;
+        writer.write(type_name)?;
+        writer.write(&schema.hash().to_le_bytes())?;
+        writer.write(&schema.version().to_le_bytes())?;
+
+        let count_position = writer.len();
+        writer.write(&0_u32.to_le_bytes())?;
+        let mut count = 0_u32;
+        for (position_of, entity) in entities.iter().enumerate() {
+            let Ok(Some(component)) = storage.get(&entity) else {
+                continue;
+            };
+            let component: &dyn Any = component;
+            let Some(component) = schema.reflect(component) else {
+                unreachable!("storage should contain components of its type");
+            };
+            let position_of = u32::try_from(position_of).unwrap_or(u32::MAX);
+            writer.write(&position_of.to_le_bytes())?;
+            encode_value(component, &mut writer, &position)?;
+            count += 1;
+        }
+        writer.patch(count_position, &count.to_le_bytes());
+    }
+    Ok(writer.len())
+}
@@ -0,0 +1,431 @@
+use core::fmt::Display;
+
+use derive_more::{Display, From};
+
+use crate::{
+    component::bundle::NotRegisteredError,
+    entity::{DefaultEntity, Entity},
+};
+
+/// The error type which is returned when the buffer is too small to encode the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("buffer of {capacity} bytes is too small to encode the world")]
+pub struct BufferTooSmallError {
+    capacity: usize,
+}
+
+impl BufferTooSmallError {
+    /// Creates new error when the buffer of provided capacity is too small to encode the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity }
+    }
+
+    /// Returns capacity of the buffer in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn capacity(self) -> usize {
+        self.capacity
+    }
+}
+
+/// The error type which is returned when value of some type cannot be encoded.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(r#"value of type "{type_name}" is not supported by the binary format"#)]
+pub struct UnsupportedTypeError {
+    type_name: &'static str,
+}
+
+impl UnsupportedTypeError {
+    /// Creates new error when value of provided type cannot be encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+
+    /// Returns name of the type which is not supported by the binary format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// The error type which is returned when component references an entity
+/// which does not present in the entity registry.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(bound(E: Display))]
+#[display("entity {entity} is referenced by a component but does not present in the registry")]
+pub struct DanglingEntityError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    entity: E,
+}
+
+impl<E> DanglingEntityError<E>
+where
+    E: Entity,
+{
+    /// Creates new error when provided entity is referenced but does not present in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(entity: E) -> Self {
+        Self { entity }
+    }
+
+    /// Returns the entity which does not present in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn entity(self) -> E {
+        self.entity
+    }
+}
+
+/// The error type which is returned when encoding the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(E: Display))]
+pub enum EncodeError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    /// Buffer is too small to encode the world.
+    BufferTooSmall(BufferTooSmallError),
+    /// Some value cannot be encoded.
+    UnsupportedType(UnsupportedTypeError),
+    /// Component references an entity which does not present in the registry.
+    DanglingEntity(DanglingEntityError<E>),
+}
+
+/// The error type which is returned when the world was encoded with another version of the format.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(
+    "world was encoded with version {found} of the format, but version {expected} is supported"
+)]
+pub struct VersionMismatchError {
+    expected: u16,
+    found: u16,
+}
+
+impl VersionMismatchError {
+    /// Creates new error when version of the format was mismatched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(expected: u16, found: u16) -> Self {
+        Self { expected, found }
+    }
+
+    /// Returns version of the format which is supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected(self) -> u16 {
+        self.expected
+    }
+
+    /// Returns version of the format which the world was encoded with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn found(self) -> u16 {
+        self.found
+    }
+}
+
+/// The error type which is returned when schema of the encoded component
+/// differs from the schema of the component type.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(
+    r#"schema of component "{type_name}" was mismatched: \
+        expected hash is {expected:#018x}, but encoded hash is {found:#018x}"#
+)]
+pub struct SchemaMismatchError {
+    type_name: &'static str,
+    expected: u64,
+    found: u64,
+}
+
+impl SchemaMismatchError {
+    /// Creates new error when schema of the component was mismatched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(type_name: &'static str, expected: u64, found: u64) -> Self {
+        Self {
+            type_name,
+            expected,
+            found,
+        }
+    }
+
+    /// Returns type name of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns hash of the schema of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected(self) -> u64 {
+        self.expected
+    }
+
+    /// Returns hash of the schema the component was encoded with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn found(self) -> u64 {
+        self.found
+    }
+}
+
+/// The error type which is returned when the encoded component
+/// cannot be migrated to the current version of the component type.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(
+    r#"component "{type_name}" of version {version} cannot be migrated to version {current}"#
+)]
+pub struct UnmigratableError {
+    type_name: &'static str,
+    version: u32,
+    current: u32,
+}
+
+impl UnmigratableError {
+    /// Creates new error when the component of provided version cannot be migrated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(type_name: &'static str, version: u32, current: u32) -> Self {
+        Self {
+            type_name,
+            version,
+            current,
+        }
+    }
+
+    /// Returns type name of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns version of the component it was encoded with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn version(self) -> u32 {
+        self.version
+    }
+
+    /// Returns current version of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn current(self) -> u32 {
+        self.current
+    }
+}
+
+/// The error type which is returned when the encoded world contains more entities
+/// than can be returned from the decoding.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("world contains {required} entities, but only {capacity} entities can be returned")]
+pub struct CapacityError {
+    required: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    /// Creates new error when the world contains more entities than can be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(required: usize, capacity: usize) -> Self {
+        Self { required, capacity }
+    }
+
+    /// Returns count of entities which are contained in the encoded world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn required(self) -> usize {
+        self.required
+    }
+
+    /// Returns count of entities which can be returned from the decoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn capacity(self) -> usize {
+        self.capacity
+    }
+}
+
+/// The error type which is returned when decoding the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+pub enum DecodeError {
+    /// Buffer does not start with the [magic bytes](super::MAGIC).
+    #[from(ignore)]
+    #[display("buffer does not contain an encoded world")]
+    InvalidHeader,
+    /// World was encoded with another version of the format.
+    VersionMismatch(VersionMismatchError),
+    /// Buffer ends unexpectedly.
+    #[from(ignore)]
+    #[display("unexpected end of the buffer")]
+    UnexpectedEnd,
+    /// Encoded component is not described by provided schema.
+    #[from(ignore)]
+    #[display("encoded component is not described by the schema")]
+    UnknownComponent,
+    /// Schema of the encoded component differs from the schema of the component type.
+    SchemaMismatch(SchemaMismatchError),
+    /// Encoded component cannot be migrated to the current version of the component type.
+    Unmigratable(UnmigratableError),
+    /// Component was not registered in the world.
+    NotRegistered(NotRegisteredError),
+    /// Encoded value is not valid for its type.
+    #[from(ignore)]
+    #[display("encoded value is not valid for its type")]
+    InvalidValue,
+    /// Encoded component references an entity which is not contained in the encoded world.
+    #[from(ignore)]
+    #[display("encoded component references unknown entity {_0}")]
+    UnknownEntity(u32),
+    /// Encoded world contains too many entities.
+    Capacity(CapacityError),
+    /// Entity registry failed to create entities of the encoded world.
+    #[from(ignore)]
+    #[display("entity registry failed to create entities of the encoded world")]
+    Entities,
+}
@@ -0,0 +1,80 @@
+use super::{BufferTooSmallError, DecodeError};
+
+/// Writer of bytes into the fixed-size buffer.
+pub struct Writer<'buf> {
+    buf: &'buf mut [u8],
+    len: usize,
+}
+
+impl<'buf> Writer<'buf> {
+    pub fn new(buf: &'buf mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) -> Result<(), BufferTooSmallError> {
+        let Self { buf, len } = self;
+        let capacity = buf.len();
+        let Some(dest) = buf.get_mut(*len..*len + bytes.len()) else {
+            return Err(BufferTooSmallError::new(capacity));
+        };
+        dest.copy_from_slice(bytes);
+        *len += bytes.len();
+        Ok(())
+    }
+
+    /// Overwrites bytes which were written earlier at provided position.
+    pub fn patch(&mut self, position: usize, bytes: &[u8]) {
+        let Self { buf, len } = self;
+        debug_assert!(position + bytes.len() <= *len);
+        buf[position..position + bytes.len()].copy_from_slice(bytes);
+    }
+}
+
+/// Reader of bytes from the buffer.
+#[derive(Clone)]
+pub struct Reader<'buf> {
+    buf: &'buf [u8],
+    position: usize,
+}
+
+impl<'buf> Reader<'buf> {
+    pub fn new(buf: &'buf [u8]) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    pub fn read<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        let bytes = self.read_slice(N)?;
+        let Ok(bytes) = bytes.try_into() else {
+            unreachable!("slice should have length of the array");
+        };
+        Ok(bytes)
+    }
+
+    pub fn read_slice(&mut self, len: usize) -> Result<&'buf [u8], DecodeError> {
+        let Self { buf, position } = self;
+        let Some(end) = position.checked_add(len) else {
+            return Err(DecodeError::UnexpectedEnd);
+        };
+        let Some(bytes) = buf.get(*position..end) else {
+            return Err(DecodeError::UnexpectedEnd);
+        };
+        *position = end;
+        Ok(bytes)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, DecodeError> {
+        self.read().map(u16::from_le_bytes)
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, DecodeError> {
+        self.read().map(u32::from_le_bytes)
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, DecodeError> {
+        self.read().map(u64::from_le_bytes)
+    }
+}
@@ -0,0 +1,315 @@
+use alloc::{boxed::Box, collections::btree_map::BTreeMap};
+use core::{
+    any::{Any, TypeId, type_name},
+    fmt,
+};
+
+use crate::{
+    component::{Component, storage::ErasedStorage},
+    entity::{DefaultEntity, Entity},
+    reflect::Reflect,
+};
+
+use super::{
+    ComponentSchema, DecodeError, UnmigratableError,
+    decode::Migrations,
+    io::Reader,
+    schema::schema_hash,
+    value::{Table, decode_value},
+};
+
+/// Registry of migrations of components encoded with older [versions](Component::VERSION)
+/// of the component type.
+///
+/// Each migration converts the component from some version to the next one,
+/// so components of any older version are migrated by the chain of migrations
+/// up to the current version of the component type.
+/// Older versions of the component are described by separate types
+/// which [reflect](Reflect) the layout of the component at that version.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub struct MigrationRegistry<E = DefaultEntity> {
+    migrations: BTreeMap<(TypeId, u32), Migration<E>>,
+}
+
+struct Migration<E> {
+    type_name: &'static str,
+    hash: u64,
+    decode: DecodeFn<E>,
+    migrate: Box<MigrateFn>,
+}
+
+type DecodeFn<E> = fn(&mut Reader<'_>, &Table<'_, E>) -> Result<Box<dyn Reflect>, DecodeError>;
+
+type MigrateFn = dyn Fn(Box<dyn Reflect>) -> Option<Box<dyn Reflect>>;
+
+impl<E> MigrationRegistry<E> {
+    /// Creates new empty migration registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let migrations = BTreeMap::new();
+        Self { migrations }
+    }
+
+    /// Returns count of migrations registered in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn len(&self) -> usize {
+        let Self { migrations } = self;
+        migrations.len()
+    }
+
+    /// Checks if the registry contains no migrations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let Self { migrations } = self;
+        migrations.is_empty()
+    }
+
+    /// Checks if the registry contains migration of the component from provided version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn contains<C>(&self, from: u32) -> bool
+    where
+        C: Component,
+    {
+        let Self { migrations } = self;
+        migrations.contains_key(&(TypeId::of::<C>(), from))
+    }
+}
+
+impl<E> MigrationRegistry<E>
+where
+    E: Entity + Reflect,
+{
+    /// Registers migration of the component from provided version to the next one.
+    /// Returns `true` if previous migration of the component from the same version was replaced.
+    ///
+    /// Type `Old` describes the layout of the component at provided version,
+    /// and type `New` describes the layout at the next version
+    /// (which is the component type itself if the next version is the current one).
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided version is not older than
+    /// the [current version](Component::VERSION) of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn register<C, Old, New>(&mut self, from: u32, migrate: fn(Old) -> New) -> bool
+    where
+        C: Component,
+        Old: Reflect + Default,
+        New: Reflect,
+    {
+        assert!(
+            from < C::VERSION,
+            "migration of `{}` should be registered from version older than {}",
+            type_name::<C>(),
+            C::VERSION,
+        );
+
+        let Self { migrations } = self;
+        let migration = Migration {
+            type_name: type_name::<Old>(),
+            hash: schema_hash(&Old::default()),
+            decode: |reader, table| {
+                let mut value = Old::default();
+                decode_value(&mut value, reader, table)?;
+                Ok(Box::new(value))
+            },
+            migrate: Box::new(move |value| {
+                let value = value as Box<dyn Any>;
+                let value = value.downcast::<Old>().ok()?;
+                Some(Box::new(migrate(*value)))
+            }),
+        };
+        migrations
+            .insert((TypeId::of::<C>(), from), migration)
+            .is_some()
+    }
+
+    /// Unregisters migration of the component from provided version.
+    /// Returns `true` if such migration was registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn unregister<C>(&mut self, from: u32) -> bool
+    where
+        C: Component,
+    {
+        let Self { migrations } = self;
+        migrations.remove(&(TypeId::of::<C>(), from)).is_some()
+    }
+}
+
+impl<E> Migrations<E> for MigrationRegistry<E>
+where
+    E: Entity + Reflect,
+{
+    fn hash(&self, schema: &ComponentSchema<E>, version: u32) -> Option<u64> {
+        let Self { migrations } = self;
+        if version >= schema.version() {
+            return None;
+        }
+        // every step of the chain should be registered to migrate the component
+        let mut chain = version..schema.version();
+        if !chain.all(|version| migrations.contains_key(&(schema.type_id(), version))) {
+            return None;
+        }
+        let migration = &migrations[&(schema.type_id(), version)];
+        Some(migration.hash)
+    }
+
+    fn decode(
+        &self,
+        schema: &ComponentSchema<E>,
+        version: u32,
+        reader: &mut Reader<'_>,
+        table: &Table<'_, E>,
+        target: Option<(&mut dyn ErasedStorage, E)>,
+    ) -> Result<(), DecodeError> {
+        let Self { migrations } = self;
+        let unmigratable = || UnmigratableError::new(schema.type_name(), version, schema.version());
+
+        let Some(Migration { decode, .. }) = migrations.get(&(schema.type_id(), version)) else {
+            return Err(unmigratable().into());
+        };
+        let mut value = decode(reader, table)?;
+        for version in version..schema.version() {
+            let Some(Migration { migrate, .. }) = migrations.get(&(schema.type_id(), version))
+            else {
+                return Err(unmigratable().into());
+            };
+            value = migrate(value).ok_or_else(unmigratable)?;
+        }
+        if !schema.attach(value.as_ref(), target) {
+            return Err(unmigratable().into());
+        }
+        Ok(())
+    }
+}
+
+impl<E> Default for MigrationRegistry<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> fmt::Debug for MigrationRegistry<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { migrations } = self;
+        let entries = migrations
+            .iter()
+            .map(|(&(_, version), migration)| (version, migration.type_name));
+        f.debug_map().entries(entries).finish()
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        reflect::Reflect,
+        world::World,
+    };
+
+    use super::{super::ComponentSchema, super::DecodeError, MigrationRegistry};
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+    #[component(storage = ArrayStorage<Self, 10>, version = 3)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct PositionV1 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct PositionV2 {
+        x: f32,
+        y: f32,
+    }
+
+    fn new_world() -> World<ArrayRegistry<10>, HList![ArrayStorage<Position, 10>], ()> {
+        World::with(ArrayRegistry::new(), hlist![ArrayStorage::new()], ())
+    }
+
+    #[test]
+    fn migrate() {
+        let schema = [ComponentSchema::of::<Position>()];
+        let mut world = new_world();
+        let entity = world.create();
+        let _ = world.attach(entity, Position { x: 1.0, y: 2.0 }).unwrap();
+        let mut buf = [0; 128];
+        let len = world.encode(&schema, &mut buf).unwrap();
+
+        // pretend that the component was encoded with the first version
+        let offset = 14 + core::any::type_name::<Position>().len() + 8;
+        buf[offset..offset + 4].copy_from_slice(&1u32.to_le_bytes());
+
+        let mut other = new_world();
+        let Err(DecodeError::Unmigratable(error)) = other.decode::<10>(&schema, &buf[..len]) else {
+            panic!("component should not be migrated without migrations");
+        };
+        assert_eq!(error.version(), 1);
+        assert_eq!(error.current(), 3);
+
+        let mut migrations = MigrationRegistry::new();
+        let _ =
+            migrations.register::<Position, PositionV1, PositionV2>(1, |PositionV1 { x, y }| {
+                PositionV2 { x, y: -y }
+            });
+        assert!(matches!(
+            other.decode_migrated::<10>(&schema, &migrations, &buf[..len]),
+            Err(DecodeError::Unmigratable(_)),
+        ));
+        assert_eq!(other.entities().len(), 0);
+
+        let _ = migrations
+            .register::<Position, PositionV2, Position>(2, |PositionV2 { x, y }| Position { x, y });
+        assert_eq!(migrations.len(), 2);
+        let created = other
+            .decode_migrated::<10>(&schema, &migrations, &buf[..len])
+            .unwrap();
+        assert_eq!(
+            other.get::<Position>(created[0]).unwrap(),
+            Some(&Position { x: 1.0, y: -2.0 }),
+        );
+    }
+}
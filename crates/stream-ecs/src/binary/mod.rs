@@ -0,0 +1,176 @@
+//! Provides utilities for compact binary representation of the world in ECS.
+//!
+//! Binary format is intended for network transfer and fast saves:
+//! it is much more compact than [scenes](crate::scene) and does not use heap allocation at all,
+//! so the world can be encoded into any byte buffer (for example, a stack-allocated array).
+//!
+//! Encoded world starts with the header which contains magic bytes, [version](VERSION) of the format
+//! and count of entities. Header is followed by sections of components,
+//! each of which contains type name of the component, a [hash of its schema](ComponentSchema::hash())
+//! and values of the component [reflected](crate::reflect::Reflect) field by field.
+//! Components encoded with older [versions](crate::component::Component::VERSION) of the component type
+//! can be migrated to the current version with the [migration registry](MigrationRegistry).
+//! Entities are referenced by their position in the entity registry,
+//! so entity references inside components are remapped to newly created entities when the world is decoded.
+
+pub use self::{
+    error::{
+        BufferTooSmallError, CapacityError, DanglingEntityError, DecodeError, EncodeError,
+        SchemaMismatchError, UnmigratableError, UnsupportedTypeError, VersionMismatchError,
+    },
+    schema::ComponentSchema,
+};
+
+#[cfg(feature = "alloc")]
+pub use self::migration::MigrationRegistry;
+
+pub(crate) use self::{decode::decode, encode::encode};
+
+mod decode;
+mod encode;
+mod error;
+mod io;
+#[cfg(feature = "alloc")]
+mod migration;
+mod schema;
+mod value;
+
+/// Magic bytes which every encoded world starts with.
+pub const MAGIC: [u8; 4] = *b"SECS";
+
+/// Version of the binary format.
+///
+/// Worlds encoded with another version of the format cannot be decoded.
+pub const VERSION: u16 = 2;
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::{DefaultEntity, registry::array::ArrayRegistry},
+        reflect::Reflect,
+        world::World,
+    };
+
+    use super::{ComponentSchema, DecodeError, EncodeError};
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Target {
+        entity: DefaultEntity,
+        active: bool,
+    }
+
+    impl Component for Target {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    type Components = HList![ArrayStorage<Position, 10>, ArrayStorage<Target, 10>];
+
+    fn new_world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn round_trip() {
+        let schema = [
+            ComponentSchema::of::<Position>(),
+            ComponentSchema::of::<Target>(),
+        ];
+        let mut world = new_world();
+        let first = world.create();
+        let second = world.create();
+        let _ = world.attach(first, Position { x: 1.0, y: 2.0 }).unwrap();
+        let target = Target {
+            entity: second,
+            active: true,
+        };
+        let _ = world.attach(second, target).unwrap();
+
+        let mut buf = [0; 256];
+        let len = world.encode(&schema, &mut buf).unwrap();
+        let mut small = buf;
+        assert!(matches!(
+            world.encode(&schema, &mut small[..len - 1]),
+            Err(EncodeError::BufferTooSmall(_)),
+        ));
+
+        let mut other = new_world();
+        let _ = other.create();
+        let created = other.decode::<10>(&schema, &buf[..len]).unwrap();
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            other.get::<Position>(created[0]).unwrap(),
+            Some(&Position { x: 1.0, y: 2.0 }),
+        );
+        assert_eq!(other.get::<Target>(created[0]).unwrap(), None);
+        let target = Target {
+            entity: created[1],
+            active: true,
+        };
+        assert_eq!(other.get::<Target>(created[1]).unwrap(), Some(&target));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let schema = [ComponentSchema::of::<Position>()];
+        let mut world = new_world();
+        let entity = world.create();
+        let _ = world.attach(entity, Position { x: 1.0, y: 2.0 }).unwrap();
+        let mut buf = [0; 128];
+        let len = world.encode(&schema, &mut buf).unwrap();
+
+        let mut other = new_world();
+        assert!(matches!(
+            other.decode::<0>(&schema, &buf[..len]),
+            Err(DecodeError::Capacity(_)),
+        ));
+        assert!(matches!(
+            other.decode::<10>(&schema, &buf[..len - 1]),
+            Err(DecodeError::UnexpectedEnd),
+        ));
+        assert!(matches!(
+            other.decode::<10>(&[ComponentSchema::of::<Target>()], &buf[..len]),
+            Err(DecodeError::UnknownComponent),
+        ));
+
+        let mut version = buf;
+        version[4] += 1;
+        assert!(matches!(
+            other.decode::<10>(&schema, &version[..len]),
+            Err(DecodeError::VersionMismatch(_)),
+        ));
+
+        let mut hash = buf;
+        let type_name_len = core::any::type_name::<Position>().len();
+        hash[14 + type_name_len] ^= 1;
+        let Err(DecodeError::SchemaMismatch(error)) = other.decode::<10>(&schema, &hash[..len])
+        else {
+            panic!("schema should be mismatched");
+        };
+        assert_eq!(error.expected(), schema[0].hash());
+        assert_eq!(error.type_name(), core::any::type_name::<Position>());
+        assert_eq!(other.entities().len(), 0);
+
+        for _ in 0..10 {
+            let _ = other.create();
+        }
+        assert!(matches!(
+            other.decode::<10>(&schema, &buf[..len]),
+            Err(DecodeError::Entities),
+        ));
+        assert_eq!(other.entities().len(), 10);
+    }
+}
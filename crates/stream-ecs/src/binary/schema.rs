@@ -0,0 +1,239 @@
+use core::{
+    any::{Any, TypeId, type_name},
+    fmt,
+};
+
+use crate::{
+    component::{Component, bundle::NotRegisteredError, storage::ErasedStorage},
+    entity::{DefaultEntity, Entity},
+    reflect::Reflect,
+};
+
+use super::{
+    DecodeError,
+    io::Reader,
+    value::{Table, decode_value},
+};
+
+/// Schema of some component type which describes how the component is encoded.
+///
+/// Schema is created from the [reflection](Reflect) of the default value of the component type.
+/// [Hash](ComponentSchema::hash()) of the schema is computed from names of all the fields
+/// and types of primitive fields, so it changes if the layout of the component changes,
+/// but does not depend on names of the component type and its nested types.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub struct ComponentSchema<E = DefaultEntity> {
+    type_id: TypeId,
+    type_name: &'static str,
+    hash: u64,
+    version: u32,
+    reflect: fn(&dyn Any) -> Option<&dyn Reflect>,
+    decode: DecodeFn<E>,
+    #[cfg(feature = "alloc")]
+    attach: AttachFn<E>,
+    not_registered: fn() -> NotRegisteredError,
+}
+
+#[cfg(feature = "alloc")]
+type AttachFn<E> = fn(&dyn Reflect, Option<(&mut dyn ErasedStorage, E)>) -> bool;
+
+type DecodeFn<E> = fn(
+    &mut Reader<'_>,
+    &Table<'_, E>,
+    Option<(&mut dyn ErasedStorage, E)>,
+) -> Result<(), DecodeError>;
+
+impl<E> ComponentSchema<E>
+where
+    E: Entity + Reflect,
+{
+    /// Creates new schema of provided component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn of<T>() -> Self
+    where
+        T: Component + Reflect + Default,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            hash: schema_hash(&T::default()),
+            version: T::VERSION,
+            reflect: |value| value.downcast_ref::<T>().map(|value| value as _),
+            decode: decode::<T, E>,
+            #[cfg(feature = "alloc")]
+            attach: attach::<T, E>,
+            not_registered: NotRegisteredError::new::<T>,
+        }
+    }
+}
+
+impl<E> ComponentSchema<E> {
+    /// Returns [`TypeId`] of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns type name of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns hash of the schema of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns [version](Component::VERSION) of the component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub(super) fn reflect<'a>(&self, component: &'a dyn Any) -> Option<&'a dyn Reflect> {
+        let Self { reflect, .. } = self;
+        reflect(component)
+    }
+
+    pub(super) fn decode(
+        &self,
+        reader: &mut Reader<'_>,
+        table: &Table<'_, E>,
+        target: Option<(&mut dyn ErasedStorage, E)>,
+    ) -> Result<(), DecodeError> {
+        let Self { decode, .. } = self;
+        decode(reader, table, target)
+    }
+
+    /// Attaches provided reflected component to the entity if the target is provided.
+    /// Returns `false` if provided value is not of the component type.
+    #[cfg(feature = "alloc")]
+    pub(super) fn attach(
+        &self,
+        component: &dyn Reflect,
+        target: Option<(&mut dyn ErasedStorage, E)>,
+    ) -> bool {
+        let Self { attach, .. } = self;
+        attach(component, target)
+    }
+
+    pub(super) fn not_registered(&self) -> NotRegisteredError {
+        let Self { not_registered, .. } = self;
+        not_registered()
+    }
+}
+
+impl<E> Clone for ComponentSchema<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for ComponentSchema<E> {}
+
+impl<E> fmt::Debug for ComponentSchema<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            type_name, hash, ..
+        } = self;
+        f.debug_struct("ComponentSchema")
+            .field("type_name", type_name)
+            .field("hash", hash)
+            .finish_non_exhaustive()
+    }
+}
+
+fn decode<T, E>(
+    reader: &mut Reader<'_>,
+    table: &Table<'_, E>,
+    target: Option<(&mut dyn ErasedStorage, E)>,
+) -> Result<(), DecodeError>
+where
+    T: Component + Reflect + Default,
+    E: Entity + Reflect,
+{
+    let mut component = T::default();
+    decode_value(&mut component, reader, table)?;
+    attach::<T, E>(&component, target);
+    Ok(())
+}
+
+fn attach<T, E>(component: &dyn Reflect, target: Option<(&mut dyn ErasedStorage, E)>) -> bool
+where
+    T: Component + Reflect,
+    E: Entity,
+{
+    let Some(component) = component.downcast_ref::<T>() else {
+        return false;
+    };
+    if let Some((storage, entity)) = target {
+        let Ok(()) = storage.attach(&entity, component) else {
+            unreachable!("storage should accept the component of its type");
+        };
+    }
+    true
+}
+
+/// Computes hash of the schema of provided reflected value
+/// with 64-bit FNV-1a hash function, which does not depend on the platform.
+///
+/// Names of types with fields are not hashed,
+/// so older versions of the component can be described by types with other names.
+pub(super) fn schema_hash(value: &dyn Reflect) -> u64 {
+    fn write(hash: &mut u64, bytes: &[u8]) {
+        for &byte in bytes {
+            *hash ^= u64::from(byte);
+            *hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn visit(hash: &mut u64, value: &dyn Reflect) {
+        if value.field_len() == 0 {
+            write(hash, value.type_name().as_bytes());
+        }
+        for (index, info) in value.fields().enumerate() {
+            write(hash, info.name().as_bytes());
+            let Some(field) = value.field_at(index) else {
+                unreachable!("index of the field should be in bounds");
+            };
+            visit(hash, field);
+        }
+        write(hash, &[0]);
+    }
+
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    visit(&mut hash, value);
+    hash
+}
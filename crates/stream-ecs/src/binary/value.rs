@@ -0,0 +1,165 @@
+use crate::{entity::Entity, reflect::Reflect};
+
+use super::{
+    BufferTooSmallError, DanglingEntityError, DecodeError, EncodeError, UnsupportedTypeError,
+    io::{Reader, Writer},
+};
+
+/// Entities which encoded entity references are resolved to.
+///
+/// Entities are not created while the buffer is checked, so only count of entities is known.
+pub struct Table<'a, E> {
+    pub len: usize,
+    pub created: &'a [E],
+}
+
+impl<E> Table<'_, E>
+where
+    E: Copy,
+{
+    /// Resolves encoded position of the entity,
+    /// returning [`None`] if entities were not created yet.
+    pub fn resolve(&self, position: u32) -> Result<Option<E>, DecodeError> {
+        let &Self { len, created } = self;
+        let Some(index) = usize::try_from(position).ok().filter(|&index| index < len) else {
+            return Err(DecodeError::UnknownEntity(position));
+        };
+        Ok(created.get(index).copied())
+    }
+}
+
+macro_rules! numbers {
+    ($($ty:ty),* $(,)?) => {
+        fn encode_number(
+            value: &dyn Reflect,
+            writer: &mut Writer<'_>,
+        ) -> Option<Result<(), BufferTooSmallError>> {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(writer.write(&value.to_le_bytes()));
+                }
+            )*
+            None
+        }
+
+        fn decode_number(
+            target: &mut dyn Reflect,
+            reader: &mut Reader<'_>,
+        ) -> Option<Result<(), DecodeError>> {
+            $(
+                if let Some(target) = target.downcast_mut::<$ty>() {
+                    let result = reader
+                        .read()
+                        .map(|bytes| *target = <$ty>::from_le_bytes(bytes));
+                    return Some(result);
+                }
+            )*
+            None
+        }
+    };
+}
+
+numbers!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Encodes provided reflected value field by field.
+pub fn encode_value<E, F>(
+    value: &dyn Reflect,
+    writer: &mut Writer<'_>,
+    position: &F,
+) -> Result<(), EncodeError<E>>
+where
+    E: Entity + Reflect,
+    F: Fn(E) -> Option<u32>,
+{
+    if let Some(result) = encode_number(value, writer) {
+        return result.map_err(Into::into);
+    }
+    if let Some(&entity) = value.downcast_ref::<E>() {
+        let Some(position) = position(entity) else {
+            let error = DanglingEntityError::new(entity);
+            return Err(error.into());
+        };
+        writer.write(&position.to_le_bytes())?;
+        return Ok(());
+    }
+    if let Some(&value) = value.downcast_ref::<bool>() {
+        writer.write(&[value.into()])?;
+        return Ok(());
+    }
+    if let Some(&value) = value.downcast_ref::<char>() {
+        writer.write(&u32::from(value).to_le_bytes())?;
+        return Ok(());
+    }
+    if let Some(&value) = value.downcast_ref::<usize>() {
+        writer.write(&(value as u64).to_le_bytes())?;
+        return Ok(());
+    }
+    if let Some(&value) = value.downcast_ref::<isize>() {
+        writer.write(&(value as i64).to_le_bytes())?;
+        return Ok(());
+    }
+    if value.is::<&'static str>() {
+        let error = UnsupportedTypeError::new(value.type_name());
+        return Err(error.into());
+    }
+
+    for index in 0..value.field_len() {
+        let Some(field) = value.field_at(index) else {
+            unreachable!("index of the field should be in bounds");
+        };
+        encode_value(field, writer, position)?;
+    }
+    Ok(())
+}
+
+/// Decodes provided reflected value field by field.
+pub fn decode_value<E>(
+    target: &mut dyn Reflect,
+    reader: &mut Reader<'_>,
+    table: &Table<'_, E>,
+) -> Result<(), DecodeError>
+where
+    E: Entity + Reflect,
+{
+    if let Some(result) = decode_number(target, reader) {
+        return result;
+    }
+    if let Some(target) = target.downcast_mut::<E>() {
+        if let Some(entity) = table.resolve(reader.read_u32()?)? {
+            *target = entity;
+        }
+        return Ok(());
+    }
+    if let Some(target) = target.downcast_mut::<bool>() {
+        let [byte] = reader.read()?;
+        *target = match byte {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::InvalidValue),
+        };
+        return Ok(());
+    }
+    if let Some(target) = target.downcast_mut::<char>() {
+        let value = reader.read_u32()?;
+        *target = char::from_u32(value).ok_or(DecodeError::InvalidValue)?;
+        return Ok(());
+    }
+    if let Some(target) = target.downcast_mut::<usize>() {
+        let value = reader.read_u64()?;
+        *target = value.try_into().map_err(|_| DecodeError::InvalidValue)?;
+        return Ok(());
+    }
+    if let Some(target) = target.downcast_mut::<isize>() {
+        let value = i64::from_le_bytes(reader.read()?);
+        *target = value.try_into().map_err(|_| DecodeError::InvalidValue)?;
+        return Ok(());
+    }
+
+    for index in 0..target.field_len() {
+        let Some(field) = target.field_at_mut(index) else {
+            unreachable!("index of the field should be in bounds");
+        };
+        decode_value(field, reader, table)?;
+    }
+    Ok(())
+}
@@ -1,8 +1,8 @@
-use core::any::{TypeId, type_name};
+use core::any::{Any, TypeId, type_name};
 
 use derive_more::{Display, From};
 
-use crate::component::Component;
+use crate::component::{Component, ErasedComponent};
 
 /// The error type which is returned when component type was not registered in the component registry.
 ///
@@ -36,6 +36,20 @@ impl NotRegisteredError {
         }
     }
 
+    /// Creates new error for the type of erased component that was not registered in the component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn from_erased(component: &dyn ErasedComponent) -> Self {
+        Self {
+            type_name: component.type_name(),
+            type_id: (component as &dyn Any).type_id(),
+        }
+    }
+
     /// Returns [`TypeId`] of component that was not registered in the component registry.
     ///
     /// # Examples
@@ -5,7 +5,7 @@ use crate::component::{
     Component,
     registry::{Provider, Registry as Components},
     storage::{
-        Storage, TryStorage,
+        Storage, StorageCapacityError, TryStorage,
         bundle::{
             Bundle as StorageBundle, GetBundleMut as StorageGetBundleMut,
             ProvideBundleMut as StorageProvideBundleMut,
@@ -69,6 +69,41 @@ where
         let is_attached = storage.is_attached(entity);
         Ok(is_attached)
     }
+
+    fn duplicate<C>(
+        components: &mut C,
+        from: <Self::Storages as StorageBundle>::Entity,
+        to: <Self::Storages as StorageBundle>::Entity,
+    ) -> Result<bool, NotRegisteredError>
+    where
+        C: Components,
+    {
+        let Some(storage) = components.get_mut::<T>() else {
+            return Err(NotRegisteredError::new::<Self>());
+        };
+        let is_cloned = storage.clone_component(from, to);
+        Ok(is_cloned)
+    }
+
+    fn check_capacity<C, I>(
+        components: &C,
+        entities: I,
+    ) -> Result<(), TryBundleError<StorageCapacityError>>
+    where
+        C: Components,
+        I: IntoIterator<Item = <Self::Storages as StorageBundle>::Entity>,
+        I::IntoIter: Clone,
+    {
+        let Some(storage) = components.get::<T>() else {
+            let error = NotRegisteredError::new::<Self>();
+            return Err(error.into());
+        };
+        if !storage.can_attach_all(entities) {
+            let error = StorageCapacityError::new::<Self>();
+            return Err(TryBundleError::Storage(error));
+        }
+        Ok(())
+    }
 }
 
 /// More complex implementation for heterogenous list with single element.
@@ -117,6 +152,29 @@ where
     {
         Head::is_attached(components, entity)
     }
+
+    fn duplicate<C>(
+        components: &mut C,
+        from: <Self::Storages as StorageBundle>::Entity,
+        to: <Self::Storages as StorageBundle>::Entity,
+    ) -> Result<bool, NotRegisteredError>
+    where
+        C: Components,
+    {
+        Head::duplicate(components, from, to)
+    }
+
+    fn check_capacity<C, I>(
+        components: &C,
+        entities: I,
+    ) -> Result<(), TryBundleError<StorageCapacityError>>
+    where
+        C: Components,
+        I: IntoIterator<Item = <Self::Storages as StorageBundle>::Entity>,
+        I::IntoIter: Clone,
+    {
+        Head::check_capacity(components, entities)
+    }
 }
 
 /// More complex implementation for heterogenous list with more than one element.
@@ -138,10 +196,9 @@ where
     {
         let _ = Self::is_attached(components, entity)?;
         let Cons(head, tail) = bundle;
-        let Some(head) = Head::attach(components, entity, head)? else {
-            return Ok(None);
-        };
-        let Some(tail) = Tail::attach(components, entity, tail)? else {
+        let head = Head::attach(components, entity, head)?;
+        let tail = Tail::attach(components, entity, tail)?;
+        let (Some(head), Some(tail)) = (head, tail) else {
             return Ok(None);
         };
         let bundle = Cons(head, tail);
@@ -177,6 +234,34 @@ where
         let tail = Tail::is_attached(components, entity)?;
         Ok(head && tail)
     }
+
+    fn duplicate<C>(
+        components: &mut C,
+        from: <Self::Storages as StorageBundle>::Entity,
+        to: <Self::Storages as StorageBundle>::Entity,
+    ) -> Result<bool, NotRegisteredError>
+    where
+        C: Components,
+    {
+        let _ = Self::is_attached(components, from)?;
+        let head = Head::duplicate(components, from, to)?;
+        let tail = Tail::duplicate(components, from, to)?;
+        Ok(head && tail)
+    }
+
+    fn check_capacity<C, I>(
+        components: &C,
+        entities: I,
+    ) -> Result<(), TryBundleError<StorageCapacityError>>
+    where
+        C: Components,
+        I: IntoIterator<Item = <Self::Storages as StorageBundle>::Entity>,
+        I::IntoIter: Clone,
+    {
+        let entities = entities.into_iter();
+        Head::check_capacity(components, entities.clone())?;
+        Tail::check_capacity(components, entities)
+    }
 }
 
 /// Trivial implementation for components, which forwards implementation to the component storage.
@@ -251,8 +336,7 @@ where
         let _ = Self::is_attached(components, entity)?;
         let Cons(head, tail) = bundle;
         let head = match Head::try_attach(components, entity, head) {
-            Ok(Some(head)) => head,
-            Ok(None) => return Ok(None),
+            Ok(head) => head,
             Err(error) => match error {
                 TryBundleError::NotRegistered(error) => return Err(error.into()),
                 TryBundleError::Storage(error) => {
@@ -262,8 +346,7 @@ where
             },
         };
         let tail = match Tail::try_attach(components, entity, tail) {
-            Ok(Some(head)) => head,
-            Ok(None) => return Ok(None),
+            Ok(tail) => tail,
             Err(error) => match error {
                 TryBundleError::NotRegistered(error) => return Err(error.into()),
                 TryBundleError::Storage(error) => {
@@ -272,6 +355,9 @@ where
                 }
             },
         };
+        let (Some(head), Some(tail)) = (head, tail) else {
+            return Ok(None);
+        };
         let bundle = Cons(head, tail);
         Ok(Some(bundle))
     }
@@ -4,7 +4,10 @@ use hlist::ops::Here;
 
 pub use self::error::{NotRegisteredError, TryBundleError};
 
-use super::{registry::Registry as Components, storage::bundle::Bundle as StorageBundle};
+use super::{
+    registry::Registry as Components,
+    storage::{StorageCapacityError, bundle::Bundle as StorageBundle},
+};
 
 mod error;
 mod impls;
@@ -86,6 +89,59 @@ pub trait Bundle: Sized + 'static {
     ) -> Result<bool, NotRegisteredError>
     where
         C: Components;
+
+    /// Copies components of the bundle attached to the `from` entity to the `to` entity.
+    ///
+    /// Returns `true` if all components of the bundle were attached to the `from` entity.
+    /// Components which are not attached to the `from` entity are left untouched on the `to` entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if one of bundle components
+    /// was not registered in the component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn duplicate<C>(
+        components: &mut C,
+        from: <Self::Storages as StorageBundle>::Entity,
+        to: <Self::Storages as StorageBundle>::Entity,
+    ) -> Result<bool, NotRegisteredError>
+    where
+        C: Components;
+
+    /// Checks if storages of all bundle components have enough capacity
+    /// to attach components of the bundle to all provided entities.
+    ///
+    /// Provided entities are expected to be distinct.
+    /// Bundles are not bounded by default, so this method never fails by default.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if one of bundle components
+    /// was not registered in the component registry
+    /// or its storage does not have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn check_capacity<C, I>(
+        components: &C,
+        entities: I,
+    ) -> Result<(), TryBundleError<StorageCapacityError>>
+    where
+        C: Components,
+        I: IntoIterator<Item = <Self::Storages as StorageBundle>::Entity>,
+        I::IntoIter: Clone,
+    {
+        let _ = (components, entities);
+        Ok(())
+    }
 }
 
 /// Extension of bundle which allows to implement fallible operations for the bundle.
@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+
+use super::{Access, AccessConflictError, ComponentId, DynamicQuery};
+
+/// Builder of the [dynamic query](DynamicQuery) from identifiers of components known only at runtime.
+///
+/// Builder checks that components written by the query are not accessed by it in any other way,
+/// so the query never fetches aliasing mutable references.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DynamicQueryBuilder {
+    terms: Vec<(ComponentId, Access)>,
+}
+
+impl DynamicQueryBuilder {
+    /// Creates new builder of the query without any components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let terms = Vec::new();
+        Self { terms }
+    }
+
+    /// Adds the component which is required and fetched by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn read(self, id: impl Into<ComponentId>) -> Self {
+        self.access(id.into(), Access::Read)
+    }
+
+    /// Adds the component which is required and fetched by mutable reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn write(self, id: impl Into<ComponentId>) -> Self {
+        self.access(id.into(), Access::Write)
+    }
+
+    /// Adds the component which is fetched by reference if it is attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn optional(self, id: impl Into<ComponentId>) -> Self {
+        self.access(id.into(), Access::Optional)
+    }
+
+    /// Adds the component which is required, but not fetched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn with(self, id: impl Into<ComponentId>) -> Self {
+        self.access(id.into(), Access::With)
+    }
+
+    /// Adds the component which should not be attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn without(self, id: impl Into<ComponentId>) -> Self {
+        self.access(id.into(), Access::Without)
+    }
+
+    /// Builds the query from components added to the builder.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of components written by the query
+    /// is fetched by it more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn build(self) -> Result<DynamicQuery, AccessConflictError> {
+        let Self { terms } = self;
+
+        for &(id, access) in &terms {
+            if access != Access::Write {
+                continue;
+            }
+            let fetched = terms
+                .iter()
+                .filter(|&&(other, access)| other == id && access.is_fetched())
+                .count();
+            if fetched > 1 {
+                return Err(AccessConflictError::new(id));
+            }
+        }
+        Ok(DynamicQuery::from_terms(terms))
+    }
+
+    fn access(mut self, id: ComponentId, access: Access) -> Self {
+        let Self { terms } = &mut self;
+        terms.push((id, access));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+
+    use super::DynamicQueryBuilder;
+
+    #[test]
+    fn conflict() {
+        let id = TypeId::of::<u32>();
+        let query = DynamicQueryBuilder::new().write(id).with(id).build();
+        assert!(query.is_ok());
+
+        let query = DynamicQueryBuilder::new().write(id).optional(id).build();
+        assert_eq!(query.unwrap_err().id(), id.into());
+
+        let query = DynamicQueryBuilder::new().read(id).read(id).build();
+        assert!(query.is_ok());
+    }
+}
@@ -0,0 +1,374 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use derive_more::{Display, From};
+
+use super::FieldKindMismatchError;
+
+/// Descriptor of the component described at runtime.
+///
+/// Descriptor defines the name of the component and the schema of its fields.
+/// Fields are laid out one after another in order of their declaration without any padding.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ComponentDescriptor {
+    name: String,
+    fields: Vec<FieldDescriptor>,
+    size: usize,
+}
+
+impl ComponentDescriptor {
+    /// Creates new descriptor of the component with provided name and no fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        let fields = Vec::new();
+        Self {
+            name,
+            fields,
+            size: 0,
+        }
+    }
+
+    /// Appends new field with provided name and kind to the descriptor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn with_field(mut self, name: impl Into<String>, kind: FieldKind) -> Self {
+        let Self { fields, size, .. } = &mut self;
+        let field = FieldDescriptor {
+            name: name.into(),
+            kind,
+            offset: *size,
+        };
+        fields.push(field);
+        *size += kind.size();
+        self
+    }
+
+    /// Returns the name of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn name(&self) -> &str {
+        let Self { name, .. } = self;
+        name
+    }
+
+    /// Returns all the fields of the component in order of their declaration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn fields(&self) -> &[FieldDescriptor] {
+        let Self { fields, .. } = self;
+        fields
+    }
+
+    /// Retrieves the field of the component by its name.
+    /// Returns [`None`] if the component has no field with such name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn field(&self, name: &str) -> Option<&FieldDescriptor> {
+        let Self { fields, .. } = self;
+        fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns size of the component in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn size(&self) -> usize {
+        let Self { size, .. } = self;
+        *size
+    }
+
+    /// Returns bytes of the component which fields are all zeroed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn zeroed(&self) -> Vec<u8> {
+        vec![0; self.size()]
+    }
+}
+
+/// Descriptor of the field of the component described at runtime.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldDescriptor {
+    name: String,
+    kind: FieldKind,
+    offset: usize,
+}
+
+impl FieldDescriptor {
+    /// Returns the name of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn name(&self) -> &str {
+        let Self { name, .. } = self;
+        name
+    }
+
+    /// Returns the kind of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn kind(&self) -> FieldKind {
+        let Self { kind, .. } = self;
+        *kind
+    }
+
+    /// Returns offset of the field in bytes from the start of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn offset(&self) -> usize {
+        let Self { offset, .. } = self;
+        *offset
+    }
+
+    /// Reads the value of the field from provided bytes of the component.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided bytes are too short to contain the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn read(&self, bytes: &[u8]) -> FieldValue {
+        let &Self { kind, offset, .. } = self;
+        let bytes = &bytes[offset..offset + kind.size()];
+        FieldValue::from_le_bytes(kind, bytes)
+    }
+
+    /// Writes provided value of the field into provided bytes of the component.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if kind of provided value does not match the kind of the field.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided bytes are too short to contain the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn write(&self, bytes: &mut [u8], value: FieldValue) -> Result<(), FieldKindMismatchError> {
+        let &Self { kind, offset, .. } = self;
+        if value.kind() != kind {
+            return Err(FieldKindMismatchError::new(kind, value.kind()));
+        }
+        let bytes = &mut bytes[offset..offset + kind.size()];
+        value.write_le_bytes(bytes);
+        Ok(())
+    }
+}
+
+/// Kind of the field of the component described at runtime.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldKind {
+    /// Boolean field which takes one byte.
+    #[display("bool")]
+    Bool,
+    /// Unsigned 8-bit integer field.
+    #[display("u8")]
+    U8,
+    /// Signed 32-bit integer field.
+    #[display("i32")]
+    I32,
+    /// Unsigned 32-bit integer field.
+    #[display("u32")]
+    U32,
+    /// Signed 64-bit integer field.
+    #[display("i64")]
+    I64,
+    /// Unsigned 64-bit integer field.
+    #[display("u64")]
+    U64,
+    /// 32-bit floating point field.
+    #[display("f32")]
+    F32,
+    /// 64-bit floating point field.
+    #[display("f64")]
+    F64,
+}
+
+impl FieldKind {
+    /// Returns size of the field of this kind in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Bool | Self::U8 => 1,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+        }
+    }
+}
+
+/// Value of the field of the component described at runtime.
+///
+/// Values are stored in little endian byte order.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, From)]
+pub enum FieldValue {
+    /// Value of the boolean field.
+    Bool(bool),
+    /// Value of the unsigned 8-bit integer field.
+    U8(u8),
+    /// Value of the signed 32-bit integer field.
+    I32(i32),
+    /// Value of the unsigned 32-bit integer field.
+    U32(u32),
+    /// Value of the signed 64-bit integer field.
+    I64(i64),
+    /// Value of the unsigned 64-bit integer field.
+    U64(u64),
+    /// Value of the 32-bit floating point field.
+    F32(f32),
+    /// Value of the 64-bit floating point field.
+    F64(f64),
+}
+
+impl FieldValue {
+    /// Returns the kind of this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn kind(self) -> FieldKind {
+        match self {
+            Self::Bool(_) => FieldKind::Bool,
+            Self::U8(_) => FieldKind::U8,
+            Self::I32(_) => FieldKind::I32,
+            Self::U32(_) => FieldKind::U32,
+            Self::I64(_) => FieldKind::I64,
+            Self::U64(_) => FieldKind::U64,
+            Self::F32(_) => FieldKind::F32,
+            Self::F64(_) => FieldKind::F64,
+        }
+    }
+
+    fn from_le_bytes(kind: FieldKind, bytes: &[u8]) -> Self {
+        fn array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+            let Ok(array) = bytes.try_into() else {
+                unreachable!("bytes should have the size of the field kind");
+            };
+            array
+        }
+
+        match kind {
+            FieldKind::Bool => Self::Bool(bytes[0] != 0),
+            FieldKind::U8 => Self::U8(bytes[0]),
+            FieldKind::I32 => Self::I32(i32::from_le_bytes(array(bytes))),
+            FieldKind::U32 => Self::U32(u32::from_le_bytes(array(bytes))),
+            FieldKind::I64 => Self::I64(i64::from_le_bytes(array(bytes))),
+            FieldKind::U64 => Self::U64(u64::from_le_bytes(array(bytes))),
+            FieldKind::F32 => Self::F32(f32::from_le_bytes(array(bytes))),
+            FieldKind::F64 => Self::F64(f64::from_le_bytes(array(bytes))),
+        }
+    }
+
+    fn write_le_bytes(self, bytes: &mut [u8]) {
+        match self {
+            Self::Bool(value) => bytes[0] = value.into(),
+            Self::U8(value) => bytes[0] = value,
+            Self::I32(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+            Self::U32(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+            Self::I64(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+            Self::U64(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+            Self::F32(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+            Self::F64(value) => bytes.copy_from_slice(&value.to_le_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentDescriptor, FieldKind, FieldValue};
+
+    #[test]
+    fn layout() {
+        let descriptor = ComponentDescriptor::new("Health")
+            .with_field("alive", FieldKind::Bool)
+            .with_field("value", FieldKind::F32)
+            .with_field("max", FieldKind::U64);
+        assert_eq!(descriptor.size(), 13);
+        assert_eq!(descriptor.field("max").unwrap().offset(), 5);
+
+        let mut bytes = descriptor.zeroed();
+        let value = descriptor.field("value").unwrap();
+        value.write(&mut bytes, FieldValue::F32(0.5)).unwrap();
+        assert_eq!(value.read(&bytes), FieldValue::F32(0.5));
+        assert!(value.write(&mut bytes, FieldValue::I32(1)).is_err());
+
+        let alive = descriptor.field("alive").unwrap();
+        assert_eq!(alive.read(&bytes), FieldValue::Bool(false));
+    }
+}
@@ -0,0 +1,141 @@
+use derive_more::Display;
+
+use super::{ComponentId, FieldKind};
+
+/// The error type which is returned when size of provided bytes
+/// does not match the size of the dynamic component.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("size of provided bytes is {actual}, but dynamic component has size {expected}")]
+pub struct SizeMismatchError {
+    expected: usize,
+    actual: usize,
+}
+
+impl SizeMismatchError {
+    /// Creates new error when size of provided bytes does not match the size of the dynamic component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(expected: usize, actual: usize) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Returns size of the dynamic component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected(self) -> usize {
+        self.expected
+    }
+
+    /// Returns size of provided bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn actual(self) -> usize {
+        self.actual
+    }
+}
+
+/// The error type which is returned when kind of provided value
+/// does not match the kind of the field of the dynamic component.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("value of kind {actual} was provided, but field has kind {expected}")]
+pub struct FieldKindMismatchError {
+    expected: FieldKind,
+    actual: FieldKind,
+}
+
+impl FieldKindMismatchError {
+    /// Creates new error when kind of provided value does not match the kind of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(expected: FieldKind, actual: FieldKind) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Returns kind of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected(self) -> FieldKind {
+        self.expected
+    }
+
+    /// Returns kind of provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn actual(self) -> FieldKind {
+        self.actual
+    }
+}
+
+/// The error type which is returned when the [dynamic query](super::DynamicQuery)
+/// writes the component and fetches it at the same time in some other way.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("component {id:?} is written by the query and fetched by it more than once")]
+pub struct AccessConflictError {
+    id: ComponentId,
+}
+
+impl AccessConflictError {
+    /// Creates new error when the query writes the component and fetches it more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(id: ComponentId) -> Self {
+        Self { id }
+    }
+
+    /// Returns identifier of the component which is accessed by the query more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn id(self) -> ComponentId {
+        self.id
+    }
+}
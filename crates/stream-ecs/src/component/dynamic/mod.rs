@@ -0,0 +1,64 @@
+//! Provides utilities for components which are described at runtime.
+//!
+//! Such components can not implement [`Component`](crate::component::Component) trait
+//! (for example, components defined by scripts), so they are stored as plain bytes
+//! laid out by their [descriptor](ComponentDescriptor).
+//! Fields of the component are packed one after another without any padding,
+//! so the bytes are read and written without any alignment requirements.
+//!
+//! Dynamic components are stored on the heap, so this module is only available with `alloc` feature enabled.
+
+use derive_more::Display;
+
+pub use self::{
+    builder::DynamicQueryBuilder,
+    descriptor::{ComponentDescriptor, FieldDescriptor, FieldKind, FieldValue},
+    error::{AccessConflictError, FieldKindMismatchError, SizeMismatchError},
+    query::{
+        Access, ComponentId, DynamicFetch, DynamicItem, DynamicItemMut, DynamicQuery,
+        DynamicQueryIter,
+    },
+    storage::{DynamicStorage, DynamicStorages},
+};
+
+mod builder;
+mod descriptor;
+mod error;
+mod query;
+mod storage;
+
+/// Unique identifier of the component described at runtime.
+///
+/// Identifiers are given out by the [dynamic storages](DynamicStorages) when registering new components.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynamicComponentId(u32);
+
+impl DynamicComponentId {
+    /// Creates new dynamic component identifier from provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the value of this dynamic component identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
@@ -0,0 +1,679 @@
+use alloc::vec::Vec;
+use core::{any::TypeId, fmt};
+
+use derive_more::From;
+
+use crate::{
+    component::{
+        Component, ErasedComponent, registry::Registry as Components, storage::ErasedStorage,
+    },
+    entity::{Entity, registry::Registry as Entities},
+};
+
+use super::{DynamicComponentId, DynamicQueryBuilder, DynamicStorage, DynamicStorages};
+
+/// Identifier of any component: either the component known at compile time
+/// or the component [described at runtime](super::ComponentDescriptor).
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, From)]
+pub enum ComponentId {
+    /// Identifier of the component which implements [`Component`] trait.
+    Static(TypeId),
+    /// Identifier of the component described at runtime.
+    Dynamic(DynamicComponentId),
+}
+
+impl ComponentId {
+    /// Returns identifier of the component which implements [`Component`] trait.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn of<C>() -> Self
+    where
+        C: Component,
+    {
+        Self::Static(TypeId::of::<C>())
+    }
+}
+
+/// Item fetched by the [dynamic query](DynamicQuery) for one of its components.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Clone, Copy)]
+pub enum DynamicItem<'data> {
+    /// Erased component which implements [`Component`] trait.
+    Static(&'data dyn ErasedComponent),
+    /// Bytes of the component described at runtime.
+    Dynamic(&'data [u8]),
+}
+
+impl<'data> DynamicItem<'data> {
+    /// Returns erased component if this item was fetched for the component known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn as_static(self) -> Option<&'data dyn ErasedComponent> {
+        match self {
+            Self::Static(component) => Some(component),
+            Self::Dynamic(_) => None,
+        }
+    }
+
+    /// Returns bytes of the component if this item was fetched for the component described at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn as_bytes(self) -> Option<&'data [u8]> {
+        match self {
+            Self::Static(_) => None,
+            Self::Dynamic(bytes) => Some(bytes),
+        }
+    }
+}
+
+impl fmt::Debug for DynamicItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static(component) => f
+                .debug_tuple("Static")
+                .field(&component.type_name())
+                .finish(),
+            Self::Dynamic(bytes) => f.debug_tuple("Dynamic").field(bytes).finish(),
+        }
+    }
+}
+
+/// Mutable item fetched by the [dynamic query](DynamicQuery) for one of its written components.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub enum DynamicItemMut<'data> {
+    /// Erased component which implements [`Component`] trait.
+    Static(&'data mut dyn ErasedComponent),
+    /// Bytes of the component described at runtime.
+    Dynamic(&'data mut [u8]),
+}
+
+impl<'data> DynamicItemMut<'data> {
+    /// Returns erased component if this item was fetched for the component known at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn into_static(self) -> Option<&'data mut dyn ErasedComponent> {
+        match self {
+            Self::Static(component) => Some(component),
+            Self::Dynamic(_) => None,
+        }
+    }
+
+    /// Returns bytes of the component if this item was fetched for the component described at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn into_bytes(self) -> Option<&'data mut [u8]> {
+        match self {
+            Self::Static(_) => None,
+            Self::Dynamic(bytes) => Some(bytes),
+        }
+    }
+}
+
+impl fmt::Debug for DynamicItemMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Static(component) => f
+                .debug_tuple("Static")
+                .field(&component.type_name())
+                .finish(),
+            Self::Dynamic(bytes) => f.debug_tuple("Dynamic").field(bytes).finish(),
+        }
+    }
+}
+
+/// Item fetched by the [dynamic query](DynamicQuery) when iterating over the world mutably.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug)]
+pub enum DynamicFetch<'data> {
+    /// Item of the component which is read by the query.
+    Ref(DynamicItem<'data>),
+    /// Item of the component which is written by the query.
+    Mut(DynamicItemMut<'data>),
+}
+
+/// Kind of access of the [dynamic query](DynamicQuery) to one of its components.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Component is required and fetched by reference.
+    Read,
+    /// Component is required and fetched by mutable reference.
+    Write,
+    /// Component is fetched by reference if it is attached to the entity.
+    Optional,
+    /// Component is required, but not fetched.
+    With,
+    /// Component should not be attached to the entity.
+    Without,
+}
+
+impl Access {
+    /// Checks if the component with this access is fetched by the query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn is_fetched(self) -> bool {
+        matches!(self, Self::Read | Self::Write | Self::Optional)
+    }
+}
+
+/// Query which fetches components by their [identifiers](ComponentId) known only at runtime.
+///
+/// Each component of the query has its own [access](Access) which defines
+/// whether the component is fetched and whether the entity is required to have it.
+/// Query can fetch both components known at compile time and components described at runtime.
+///
+/// Queries with mutable access are created by the [builder](DynamicQueryBuilder)
+/// which checks that components written by the query are not accessed by it in any other way.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DynamicQuery {
+    terms: Vec<(ComponentId, Access)>,
+}
+
+impl DynamicQuery {
+    /// Creates new query which reads components with provided identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new<I>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = ComponentId>,
+    {
+        let terms = ids.into_iter().map(|id| (id, Access::Read)).collect();
+        Self { terms }
+    }
+
+    /// Creates new [builder](DynamicQueryBuilder) of the query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn builder() -> DynamicQueryBuilder {
+        DynamicQueryBuilder::new()
+    }
+
+    pub(super) fn from_terms(terms: Vec<(ComponentId, Access)>) -> Self {
+        Self { terms }
+    }
+
+    /// Returns identifiers of components of the query with their access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn terms(&self) -> &[(ComponentId, Access)] {
+        let Self { terms } = self;
+        terms
+    }
+
+    /// Returns an iterator over entities which match the query, and components fetched for them.
+    ///
+    /// Items of components are returned in order of components of the query which are [fetched](Access::is_fetched()).
+    /// Item is [`None`] only if the component is [optional](Access::Optional) and is not attached to the entity.
+    /// Components which are written by the query are fetched by reference.
+    ///
+    /// Returns [`None`] if any of components required by the query was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter<'data, E, C>(
+        &self,
+        entities: &'data E,
+        components: &'data C,
+        dynamic: Option<&'data DynamicStorages<E::Entity>>,
+    ) -> Option<DynamicQueryIter<'data, E>>
+    where
+        E: Entities,
+        C: Components,
+    {
+        let Self { terms } = self;
+
+        let mut sources = Vec::with_capacity(terms.len());
+        for &(id, access) in terms {
+            let source = match id {
+                ComponentId::Static(type_id) => components
+                    .iter()
+                    .find(|storage| storage.component_type_id() == type_id)
+                    .map(Source::Static),
+                ComponentId::Dynamic(id) => dynamic
+                    .and_then(|dynamic| dynamic.get(id))
+                    .map(Source::Dynamic),
+            };
+            if source.is_none() && is_required(access) {
+                return None;
+            }
+            sources.push((access, source));
+        }
+        let entities = entities.iter();
+        Some(DynamicQueryIter { entities, sources })
+    }
+
+    /// Calls provided closure for each entity which matches the query, and components fetched for it.
+    ///
+    /// Items of components are provided in order of components of the query which are [fetched](Access::is_fetched()).
+    /// Item is [`None`] only if the component is [optional](Access::Optional) and is not attached to the entity.
+    ///
+    /// Returns `false` if any of components required by the query was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn for_each_mut<E, C, F>(
+        &self,
+        entities: &E,
+        components: &mut C,
+        dynamic: Option<&mut DynamicStorages<E::Entity>>,
+        mut f: F,
+    ) -> bool
+    where
+        E: Entities,
+        E::Entity: PartialEq,
+        usize: TryFrom<<E::Entity as Entity>::Index>,
+        C: Components,
+        F: FnMut(E::Entity, &mut [Option<DynamicFetch<'_>>]),
+    {
+        let Self { terms } = self;
+
+        let is_used = |id: ComponentId| terms.iter().any(|&(term, _)| term == id);
+        let mut slots: Vec<_> = components
+            .iter_mut()
+            .map(|storage| {
+                let id = ComponentId::Static(storage.component_type_id());
+                (id, SourceMut::Static(storage))
+            })
+            .filter(|&(id, _)| is_used(id))
+            .collect();
+        if let Some(dynamic) = dynamic {
+            let dynamic = dynamic
+                .iter_mut()
+                .map(|(id, storage)| (ComponentId::Dynamic(id), SourceMut::Dynamic(storage)))
+                .filter(|&(id, _)| is_used(id));
+            slots.extend(dynamic);
+        }
+        let is_registered = |id| slots.iter().any(|&(slot, _)| slot == id);
+        if !terms
+            .iter()
+            .all(|&(id, access)| !is_required(access) || is_registered(id))
+        {
+            return false;
+        }
+
+        let fetched = terms
+            .iter()
+            .filter(|(_, access)| access.is_fetched())
+            .count();
+        for entity in entities.iter() {
+            let is_matched = terms.iter().all(|&(id, access)| {
+                let is_attached = slots
+                    .iter()
+                    .find(|&&(slot, _)| slot == id)
+                    .is_some_and(|(_, source)| source.is_attached(entity));
+                match access {
+                    Access::Read | Access::Write | Access::With => is_attached,
+                    Access::Optional => true,
+                    Access::Without => !is_attached,
+                }
+            });
+            if !is_matched {
+                continue;
+            }
+
+            let mut row: Vec<_> = (0..fetched).map(|_| None).collect();
+            for (slot, source) in &mut slots {
+                let mut positions = terms
+                    .iter()
+                    .filter(|(_, access)| access.is_fetched())
+                    .zip(0..)
+                    .filter(|&(&(id, _), _)| id == *slot);
+                let is_written = terms
+                    .iter()
+                    .any(|&(id, access)| id == *slot && access == Access::Write);
+                if is_written {
+                    // builder ensures that written component is fetched only once
+                    if let Some((_, position)) = positions.next() {
+                        row[position] = source.get_mut(entity).map(DynamicFetch::Mut);
+                    }
+                    continue;
+                }
+                let source = &*source;
+                for (_, position) in positions {
+                    row[position] = source.get(entity).map(DynamicFetch::Ref);
+                }
+            }
+            f(entity, &mut row);
+        }
+        true
+    }
+}
+
+impl FromIterator<ComponentId> for DynamicQuery {
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = ComponentId>,
+    {
+        Self::new(iter)
+    }
+}
+
+fn is_required(access: Access) -> bool {
+    matches!(access, Access::Read | Access::Write | Access::With)
+}
+
+enum Source<'data, E> {
+    Static(&'data dyn ErasedStorage),
+    Dynamic(&'data DynamicStorage<E>),
+}
+
+impl<'data, E> Source<'data, E>
+where
+    E: Entity + PartialEq,
+    usize: TryFrom<E::Index>,
+{
+    fn get(&self, entity: E) -> Option<DynamicItem<'data>> {
+        match *self {
+            Self::Static(storage) => {
+                let component = storage.get(&entity).ok().flatten()?;
+                Some(DynamicItem::Static(component))
+            }
+            Self::Dynamic(storage) => {
+                let bytes = storage.get(entity)?;
+                Some(DynamicItem::Dynamic(bytes))
+            }
+        }
+    }
+}
+
+enum SourceMut<'data, E> {
+    Static(&'data mut dyn ErasedStorage),
+    Dynamic(&'data mut DynamicStorage<E>),
+}
+
+impl<E> SourceMut<'_, E>
+where
+    E: Entity + PartialEq,
+    usize: TryFrom<E::Index>,
+{
+    fn is_attached(&self, entity: E) -> bool {
+        match self {
+            Self::Static(storage) => storage.is_attached(&entity).unwrap_or(false),
+            Self::Dynamic(storage) => storage.is_attached(entity),
+        }
+    }
+
+    fn get(&self, entity: E) -> Option<DynamicItem<'_>> {
+        match self {
+            Self::Static(storage) => {
+                let component = storage.get(&entity).ok().flatten()?;
+                Some(DynamicItem::Static(component))
+            }
+            Self::Dynamic(storage) => {
+                let bytes = storage.get(entity)?;
+                Some(DynamicItem::Dynamic(bytes))
+            }
+        }
+    }
+
+    fn get_mut(&mut self, entity: E) -> Option<DynamicItemMut<'_>> {
+        match self {
+            Self::Static(storage) => {
+                let component = storage.get_mut(&entity).ok().flatten()?;
+                Some(DynamicItemMut::Static(component))
+            }
+            Self::Dynamic(storage) => {
+                let bytes = storage.get_mut(entity)?;
+                Some(DynamicItemMut::Dynamic(bytes))
+            }
+        }
+    }
+}
+
+/// Iterator over entities which match the [dynamic query](DynamicQuery),
+/// and components fetched for them in order of components of the query.
+///
+/// This struct is created by [`iter`][DynamicQuery::iter()] method of the query.
+pub struct DynamicQueryIter<'data, E>
+where
+    E: Entities + 'data,
+{
+    entities: E::Iter<'data>,
+    sources: Vec<(Access, Option<Source<'data, E::Entity>>)>,
+}
+
+impl<'data, E> Iterator for DynamicQueryIter<'data, E>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+{
+    type Item = (E::Entity, Vec<Option<DynamicItem<'data>>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { entities, sources } = self;
+
+        let fetch = |entity| {
+            let mut row = Vec::new();
+            for (access, source) in sources.iter() {
+                let item = source.as_ref().and_then(|source| source.get(entity));
+                match access {
+                    Access::Read | Access::Write => row.push(Some(item?)),
+                    Access::Optional => row.push(item),
+                    Access::With => {
+                        let _ = item?;
+                    }
+                    Access::Without if item.is_some() => return None,
+                    Access::Without => {}
+                }
+            }
+            Some(row)
+        };
+        entities.find_map(|entity| Some((entity, fetch(entity)?)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { entities, .. } = self;
+        let (_, upper) = entities.size_hint();
+        (0, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::{Any, TypeId};
+
+    use hlist::hlist;
+
+    use crate::{
+        component::{
+            Component,
+            dynamic::{ComponentDescriptor, DynamicStorages, FieldKind},
+            storage::array::ArrayStorage,
+        },
+        entity::{DefaultEntity, registry::array::ArrayRegistry},
+        resource::registry::{dynamic::DynamicResources, hybrid::Hybrid},
+        world::World,
+    };
+
+    use super::{ComponentId, DynamicFetch, DynamicItemMut, DynamicQuery};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Velocity(i32);
+
+    impl Component for Velocity {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Frozen;
+
+    impl Component for Frozen {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[test]
+    fn fetch() {
+        let mut dynamic = DynamicStorages::<DefaultEntity>::new();
+        let descriptor = ComponentDescriptor::new("Mana").with_field("value", FieldKind::U32);
+        let mana = dynamic.register(descriptor);
+        assert_eq!(dynamic.id_of("Mana"), Some(mana));
+
+        let resources = Hybrid::new(hlist![dynamic]);
+        let components = hlist![ArrayStorage::<Position, 10>::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, resources);
+        let first = world.create();
+        let second = world.create();
+        let _ = world.attach(first, Position(1)).unwrap();
+        let _ = world.attach(second, Position(2)).unwrap();
+
+        let dynamic = world
+            .get_res_mut::<DynamicStorages<DefaultEntity>>()
+            .unwrap();
+        let storage = dynamic.get_mut(mana).unwrap();
+        let _ = storage.attach(second, &7u32.to_le_bytes()).unwrap();
+
+        let query = DynamicQuery::new([ComponentId::of::<Position>(), mana.into()]);
+        let mut iter = world.dynamic_query(&query).unwrap();
+        let (entity, items) = iter.next().unwrap();
+        assert_eq!(entity, second);
+        let position = items[0].unwrap().as_static().unwrap() as &dyn core::any::Any;
+        assert_eq!(position.downcast_ref(), Some(&Position(2)));
+        assert_eq!(items[1].unwrap().as_bytes(), Some(&7u32.to_le_bytes()[..]));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn write() {
+        let components = hlist![
+            ArrayStorage::<Position, 10>::new(),
+            ArrayStorage::<Velocity, 10>::new(),
+            ArrayStorage::<Frozen, 10>::new(),
+        ];
+        let resources = DynamicResources::new();
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, resources);
+        let moving = world.create();
+        let _ = world.attach(moving, Position(0)).unwrap();
+        let _ = world.attach(moving, Velocity(2)).unwrap();
+        let frozen = world.create();
+        let _ = world.attach(frozen, Position(0)).unwrap();
+        let _ = world.attach(frozen, Velocity(2)).unwrap();
+        let _ = world.attach(frozen, Frozen).unwrap();
+        let still = world.create();
+        let _ = world.attach(still, Position(0)).unwrap();
+
+        let query = DynamicQuery::builder()
+            .write(TypeId::of::<Position>())
+            .optional(TypeId::of::<Velocity>())
+            .without(TypeId::of::<Frozen>())
+            .build()
+            .unwrap();
+        let mut count = 0;
+        let is_registered = world.dynamic_query_mut(&query, |_, row| {
+            count += 1;
+            let [position, velocity] = row else {
+                unreachable!("query fetches two components");
+            };
+            let velocity = match velocity {
+                Some(DynamicFetch::Ref(velocity)) => velocity.as_static(),
+                _ => None,
+            };
+            let velocity = velocity.and_then(|velocity| (velocity as &dyn Any).downcast_ref());
+            let Some(DynamicFetch::Mut(DynamicItemMut::Static(position))) = position else {
+                unreachable!("position is written by the query");
+            };
+            let position: &mut dyn Any = &mut **position;
+            let position = position.downcast_mut::<Position>();
+            let position = position.unwrap();
+            position.0 += velocity.map_or(0, |&Velocity(velocity)| velocity);
+        });
+        assert!(is_registered);
+        assert_eq!(count, 2);
+        assert_eq!(world.get::<Position>(moving).unwrap(), Some(&Position(2)));
+        assert_eq!(world.get::<Position>(frozen).unwrap(), Some(&Position(0)));
+        assert_eq!(world.get::<Position>(still).unwrap(), Some(&Position(0)));
+
+        let query = DynamicQuery::builder()
+            .read(TypeId::of::<Position>())
+            .with(TypeId::of::<Frozen>())
+            .build()
+            .unwrap();
+        let entities: std::vec::Vec<_> = world
+            .dynamic_query(&query)
+            .unwrap()
+            .map(|(entity, _)| entity)
+            .collect();
+        assert_eq!(entities, [frozen]);
+    }
+}
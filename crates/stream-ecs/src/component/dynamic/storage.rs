@@ -0,0 +1,486 @@
+use alloc::vec::Vec;
+
+use crate::{entity::Entity, resource::Resource};
+
+use super::{ComponentDescriptor, DynamicComponentId, SizeMismatchError};
+
+/// Storage of the component described at runtime.
+///
+/// Components are stored as bytes densely packed one after another,
+/// with the size of each component provided by the [descriptor](ComponentDescriptor) of the storage.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicStorage<E> {
+    descriptor: ComponentDescriptor,
+    sparse: Vec<Option<usize>>,
+    entities: Vec<E>,
+    data: Vec<u8>,
+}
+
+impl<E> DynamicStorage<E> {
+    /// Creates new empty storage of the component with provided descriptor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(descriptor: ComponentDescriptor) -> Self {
+        Self {
+            descriptor,
+            sparse: Vec::new(),
+            entities: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns descriptor of the component stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn descriptor(&self) -> &ComponentDescriptor {
+        let Self { descriptor, .. } = self;
+        descriptor
+    }
+
+    /// Returns count of components which are stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn len(&self) -> usize {
+        let Self { entities, .. } = self;
+        entities.len()
+    }
+
+    /// Checks if the storage is empty, or has no components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Clears the storage, removing all components in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn clear(&mut self) {
+        let Self {
+            sparse,
+            entities,
+            data,
+            ..
+        } = self;
+
+        sparse.clear();
+        entities.clear();
+        data.clear();
+    }
+
+    fn bytes(&self, row: usize) -> &[u8] {
+        let Self {
+            descriptor, data, ..
+        } = self;
+        let size = descriptor.size();
+        &data[row * size..(row + 1) * size]
+    }
+
+    fn bytes_mut(&mut self, row: usize) -> &mut [u8] {
+        let Self {
+            descriptor, data, ..
+        } = self;
+        let size = descriptor.size();
+        &mut data[row * size..(row + 1) * size]
+    }
+}
+
+impl<E> DynamicStorage<E>
+where
+    E: Entity + PartialEq,
+    usize: TryFrom<E::Index>,
+{
+    /// Attaches provided bytes of the component to the entity.
+    /// Returns `true` if the component was attached to the entity earlier and was replaced.
+    ///
+    /// Note that this method can reuse existing entities when provided entity
+    /// has the same index as an entity stored in the storage.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if size of provided bytes
+    /// does not match the size of the component stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn attach(&mut self, entity: E, bytes: &[u8]) -> Result<bool, SizeMismatchError> {
+        let Self {
+            descriptor,
+            sparse,
+            entities,
+            data,
+        } = self;
+
+        let size = descriptor.size();
+        if bytes.len() != size {
+            return Err(SizeMismatchError::new(size, bytes.len()));
+        }
+
+        let index = index_of(entity);
+        if let Some(&Some(row)) = sparse.get(index) {
+            let replaced = entities[row] == entity;
+            entities[row] = entity;
+            data[row * size..(row + 1) * size].copy_from_slice(bytes);
+            return Ok(replaced);
+        }
+
+        if sparse.len() <= index {
+            sparse.resize(index + 1, None);
+        }
+        sparse[index] = Some(entities.len());
+        entities.push(entity);
+        data.extend_from_slice(bytes);
+        Ok(false)
+    }
+
+    /// Checks if the component is attached to provided entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_attached(&self, entity: E) -> bool {
+        self.row(entity).is_some()
+    }
+
+    /// Retrieves bytes of the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self, entity: E) -> Option<&[u8]> {
+        let row = self.row(entity)?;
+        Some(self.bytes(row))
+    }
+
+    /// Retrieves mutable bytes of the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_mut(&mut self, entity: E) -> Option<&mut [u8]> {
+        let row = self.row(entity)?;
+        Some(self.bytes_mut(row))
+    }
+
+    /// Removes the component from provided entity.
+    /// Returns `true` if the component was attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove(&mut self, entity: E) -> bool {
+        let Some(row) = self.row(entity) else {
+            return false;
+        };
+        let Self {
+            descriptor,
+            sparse,
+            entities,
+            data,
+        } = self;
+
+        let size = descriptor.size();
+        let last = entities.len() - 1;
+        sparse[index_of(entity)] = None;
+        let _ = entities.swap_remove(row);
+        if row != last {
+            data.copy_within(last * size..(last + 1) * size, row * size);
+            sparse[index_of(entities[row])] = Some(row);
+        }
+        data.truncate(last * size);
+        true
+    }
+
+    /// Returns an iterator over entities and bytes of their components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (E, &[u8])> + '_ {
+        let Self { entities, .. } = self;
+        let rows = entities.iter().enumerate();
+        rows.map(|(row, &entity)| (entity, self.bytes(row)))
+    }
+
+    fn row(&self, entity: E) -> Option<usize> {
+        let Self {
+            sparse, entities, ..
+        } = self;
+
+        let index = usize::try_from(entity.index()).ok()?;
+        let row = (*sparse.get(index)?)?;
+        (entities[row] == entity).then_some(row)
+    }
+}
+
+fn index_of<E>(entity: E) -> usize
+where
+    E: Entity,
+    usize: TryFrom<E::Index>,
+{
+    let Ok(index) = usize::try_from(entity.index()) else {
+        panic!("index of the entity should fit into `usize`")
+    };
+    index
+}
+
+/// Collection of storages of all the components described at runtime.
+///
+/// This collection is a [resource](Resource), so it can be stored in the world
+/// alongside of storages of the static components.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicStorages<E> {
+    storages: Vec<DynamicStorage<E>>,
+}
+
+impl<E> DynamicStorages<E> {
+    /// Creates new empty collection of dynamic storages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let storages = Vec::new();
+        Self { storages }
+    }
+
+    /// Registers new component with provided descriptor, returning its unique identifier.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the count of registered components overflows [`u32`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn register(&mut self, descriptor: ComponentDescriptor) -> DynamicComponentId {
+        let Self { storages } = self;
+
+        let Ok(id) = u32::try_from(storages.len()) else {
+            panic!("too many dynamic components were registered")
+        };
+        storages.push(DynamicStorage::new(descriptor));
+        DynamicComponentId::new(id)
+    }
+
+    /// Retrieves the identifier of the component by its name.
+    /// Returns [`None`] if no component with such name was registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn id_of(&self, name: &str) -> Option<DynamicComponentId> {
+        self.iter()
+            .find(|(_, storage)| storage.descriptor().name() == name)
+            .map(|(id, _)| id)
+    }
+
+    /// Retrieves a reference to the storage of the component with provided identifier.
+    /// Returns [`None`] if such component was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self, id: DynamicComponentId) -> Option<&DynamicStorage<E>> {
+        let Self { storages } = self;
+        let index = usize::try_from(id.get()).ok()?;
+        storages.get(index)
+    }
+
+    /// Retrieves a mutable reference to the storage of the component with provided identifier.
+    /// Returns [`None`] if such component was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_mut(&mut self, id: DynamicComponentId) -> Option<&mut DynamicStorage<E>> {
+        let Self { storages } = self;
+        let index = usize::try_from(id.get()).ok()?;
+        storages.get_mut(index)
+    }
+
+    /// Returns count of components which were registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn len(&self) -> usize {
+        let Self { storages } = self;
+        storages.len()
+    }
+
+    /// Checks if no components were registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over identifiers of registered components and their storages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (DynamicComponentId, &DynamicStorage<E>)> {
+        let Self { storages } = self;
+        storages
+            .iter()
+            .zip(0..)
+            .map(|(storage, id)| (DynamicComponentId::new(id), storage))
+    }
+
+    /// Returns an iterator over identifiers of registered components and mutable references of their storages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (DynamicComponentId, &mut DynamicStorage<E>)> {
+        let Self { storages } = self;
+        storages
+            .iter_mut()
+            .zip(0..)
+            .map(|(storage, id)| (DynamicComponentId::new(id), storage))
+    }
+}
+
+impl<E> DynamicStorages<E>
+where
+    E: Entity + PartialEq,
+    usize: TryFrom<E::Index>,
+{
+    /// Removes all the dynamic components attached to provided entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove_all(&mut self, entity: E) {
+        let Self { storages } = self;
+        for storage in storages {
+            let _ = storage.remove(entity);
+        }
+    }
+}
+
+impl<E> Default for DynamicStorages<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Resource for DynamicStorages<E> where E: Entity {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        component::dynamic::{ComponentDescriptor, FieldKind},
+        entity::DefaultEntity,
+    };
+
+    use super::DynamicStorage;
+
+    fn storage() -> DynamicStorage<DefaultEntity> {
+        let descriptor = ComponentDescriptor::new("Counter").with_field("value", FieldKind::U8);
+        DynamicStorage::new(descriptor)
+    }
+
+    #[test]
+    fn attach() {
+        let mut storage = storage();
+        let entity = DefaultEntity::new(0, 0);
+        assert!(!storage.attach(entity, &[1]).unwrap());
+        assert!(storage.attach(entity, &[2]).unwrap());
+        assert!(storage.attach(entity, &[1, 2]).is_err());
+        assert_eq!(storage.get(entity), Some(&[2][..]));
+
+        let newer = DefaultEntity::new(0, 1);
+        assert_eq!(storage.get(newer), None);
+        assert!(!storage.attach(newer, &[3]).unwrap());
+        assert_eq!(storage.get(entity), None);
+        assert_eq!(storage.len(), 1);
+    }
+
+    #[test]
+    fn remove() {
+        let mut storage = storage();
+        for index in 0..3 {
+            let entity = DefaultEntity::new(index, 0);
+            let _ = storage.attach(entity, &[index as u8]).unwrap();
+        }
+        assert!(storage.remove(DefaultEntity::new(0, 0)));
+        assert!(!storage.remove(DefaultEntity::new(0, 0)));
+        assert_eq!(storage.get(DefaultEntity::new(2, 0)), Some(&[2][..]));
+        assert_eq!(storage.get(DefaultEntity::new(1, 0)), Some(&[1][..]));
+        assert_eq!(storage.iter().len(), 2);
+    }
+}
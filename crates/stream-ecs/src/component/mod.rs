@@ -1,6 +1,14 @@
 //! Provides utilities for components in ECS.
 
-use core::any::Any;
+use core::{
+    any::Any,
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use crate::entity::map::{EntityMapper, MapEntities};
 
 /// Derive macro for [`Component`] trait.
 #[cfg(feature = "derive")]
@@ -9,6 +17,8 @@ pub use stream_ecs_macros::Component;
 use self::storage::Storage;
 
 pub mod bundle;
+#[cfg(feature = "alloc")]
+pub mod dynamic;
 pub mod registry;
 pub mod storage;
 
@@ -28,6 +38,62 @@ pub mod storage;
 pub trait Component: Copy + 'static {
     /// Type of storage which will be used to store this type of component.
     type Storage: Storage<Item = Self>;
+
+    /// Function which hashes the component when computing the checksum of the world.
+    ///
+    /// Components do not take part in the checksum by default.
+    /// Components which implement [`Hash`] trait can opt in with [`hash_checksum`] function
+    /// (or with `#[component(checksum)]` attribute of the derive macro).
+    const CHECKSUM: Option<fn(&Self, &mut dyn Hasher)> = None;
+
+    /// Version of the component type layout.
+    ///
+    /// Version should be increased each time the layout of the component changes,
+    /// so components saved with older versions can be [migrated](crate::binary::MigrationRegistry) on load.
+    /// Version of the component is `1` by default (or can be set with `#[component(version = N)]` attribute of the derive macro).
+    const VERSION: u32 = 1;
+
+    /// Function which rewrites entity keys stored in the component
+    /// when entities are moved from one world to another.
+    ///
+    /// Components are not remapped by default.
+    /// Components which implement [`MapEntities`] trait can opt in with [`map_entities`] function
+    /// (or with `#[component(map_entities)]` attribute of the derive macro).
+    const MAP_ENTITIES: Option<fn(&mut Self, &dyn EntityMapper)> = None;
+}
+
+/// Hashes provided component with its [`Hash`] implementation.
+///
+/// This function can be used to opt in the component to the checksum of the world
+/// by [`CHECKSUM`][Component::CHECKSUM] constant of the [`Component`] trait.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub fn hash_checksum<T>(component: &T, mut state: &mut dyn Hasher)
+where
+    T: Component + Hash,
+{
+    component.hash(&mut state)
+}
+
+/// Rewrites entity keys stored in provided component with its [`MapEntities`] implementation.
+///
+/// This function can be used to opt in the component to remapping of entity references
+/// by [`MAP_ENTITIES`][Component::MAP_ENTITIES] constant of the [`Component`] trait.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub fn map_entities<T>(component: &mut T, mapper: &dyn EntityMapper)
+where
+    T: Component + MapEntities,
+{
+    component.map_entities(mapper)
 }
 
 /// Erased variant of component of some component type in ECS.
@@ -40,6 +106,73 @@ pub trait Component: Copy + 'static {
 /// ```
 /// todo!()
 /// ```
-pub trait ErasedComponent: Any {}
+pub trait ErasedComponent: Any {
+    /// Returns type name of the component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn type_name(&self) -> &'static str;
+
+    /// Hashes the component when computing the checksum of the world.
+    /// Returns `false` if this type of component does not take part in the checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn checksum(&self, state: &mut dyn Hasher) -> bool;
 
-impl<T> ErasedComponent for T where T: Component {}
+    /// Rewrites entity keys stored in the component with provided mapper.
+    /// Returns `false` if this type of component does not store entity keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) -> bool;
+
+    /// Copies the component into the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_boxed(&self) -> Box<dyn ErasedComponent>;
+}
+
+impl<T> ErasedComponent for T
+where
+    T: Component,
+{
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    fn checksum(&self, state: &mut dyn Hasher) -> bool {
+        let Some(checksum) = T::CHECKSUM else {
+            return false;
+        };
+        checksum(self, state);
+        true
+    }
+
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) -> bool {
+        let Some(map_entities) = T::MAP_ENTITIES else {
+            return false;
+        };
+        map_entities(self, mapper);
+        true
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_boxed(&self) -> Box<dyn ErasedComponent> {
+        Box::new(*self)
+    }
+}
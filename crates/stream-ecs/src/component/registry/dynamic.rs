@@ -0,0 +1,494 @@
+//! Component registry implementation backed by a map of storages.
+//!
+//! Unlike heterogenous list of storages, this registry allows to register new components at runtime
+//! without changing the type of the registry (for example, components of plugins loaded at runtime).
+//! Storages of the registry are allocated on the heap, so it is only available with `alloc` feature enabled.
+
+use alloc::{
+    boxed::Box,
+    collections::btree_map::{BTreeMap, Values, ValuesMut},
+};
+use core::{
+    any::{Any, TypeId},
+    convert::Infallible,
+    fmt,
+    iter::FusedIterator,
+};
+
+use crate::component::{Component, storage::ErasedStorage};
+
+use super::{Registry, RegistryMut, TryRegistryMut, With};
+
+/// Component registry which stores storages of components in a map
+/// with [type identifier](TypeId) of the component as a key.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Default)]
+pub struct DynamicComponents {
+    storages: BTreeMap<TypeId, Box<dyn ErasedStorage>>,
+}
+
+impl DynamicComponents {
+    /// Creates new empty dynamic component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let storages = BTreeMap::new();
+        Self { storages }
+    }
+
+    /// Registers provided erased storage in the registry.
+    /// Returns previous storage of the same component type, or [`None`] if such component was not registered.
+    ///
+    /// Type of the component is determined by the [type identifier](ErasedStorage::component_type_id())
+    /// of provided storage. If the type of provided storage differs from the [storage type](Component::Storage)
+    /// of its component, the component is not considered registered by typed methods of the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn register_erased(
+        &mut self,
+        storage: Box<dyn ErasedStorage>,
+    ) -> Option<Box<dyn ErasedStorage>> {
+        let Self { storages } = self;
+        let type_id = storage.component_type_id();
+        storages.insert(type_id, storage)
+    }
+
+    /// Unregisters the component with provided [type identifier](TypeId) and returns its erased storage.
+    /// Returns [`None`] if such component was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn unregister_erased(&mut self, type_id: TypeId) -> Option<Box<dyn ErasedStorage>> {
+        let Self { storages } = self;
+        storages.remove(&type_id)
+    }
+
+    /// Retrieves a reference to the erased storage of the component with provided [type identifier](TypeId).
+    /// Returns [`None`] if such component was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_erased(&self, type_id: TypeId) -> Option<&dyn ErasedStorage> {
+        let Self { storages } = self;
+        let storage = storages.get(&type_id)?;
+        Some(storage.as_ref())
+    }
+
+    /// Retrieves a mutable reference to the erased storage of the component with provided [type identifier](TypeId).
+    /// Returns [`None`] if such component was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_erased_mut(&mut self, type_id: TypeId) -> Option<&mut dyn ErasedStorage> {
+        let Self { storages } = self;
+        let storage = storages.get_mut(&type_id)?;
+        Some(storage.as_mut())
+    }
+}
+
+impl Registry for DynamicComponents {
+    fn is_registered<C>(&self) -> bool
+    where
+        C: Component,
+    {
+        self.get::<C>().is_some()
+    }
+
+    fn len(&self) -> usize {
+        let Self { storages } = self;
+        storages.len()
+    }
+
+    fn get<C>(&self) -> Option<&C::Storage>
+    where
+        C: Component,
+    {
+        let storage = self.get_erased(TypeId::of::<C>())?;
+        let storage = storage as &dyn Any;
+        storage.downcast_ref()
+    }
+
+    fn get_mut<C>(&mut self) -> Option<&mut C::Storage>
+    where
+        C: Component,
+    {
+        let storage = self.get_erased_mut(TypeId::of::<C>())?;
+        let storage = storage as &mut dyn Any;
+        storage.downcast_mut()
+    }
+
+    type Iter<'me>
+        = Iter<'me>
+    where
+        Self: 'me;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        let Self { storages } = self;
+        let iter = storages.values();
+        Iter { iter }
+    }
+
+    type IterMut<'me>
+        = IterMut<'me>
+    where
+        Self: 'me;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        let Self { storages } = self;
+        let iter = storages.values_mut();
+        IterMut { iter }
+    }
+}
+
+impl With for DynamicComponents {
+    type Output<C>
+        = Self
+    where
+        C: Component;
+
+    fn with<C>(mut self, storage: C::Storage) -> Self::Output<C>
+    where
+        C: Component,
+    {
+        let _ = self.register::<C>(storage);
+        self
+    }
+}
+
+impl RegistryMut for DynamicComponents {
+    fn register<C>(&mut self, storage: C::Storage) -> Option<C::Storage>
+    where
+        C: Component,
+    {
+        let Self { storages } = self;
+        let storage = storages.insert(TypeId::of::<C>(), Box::new(storage))?;
+        let storage = storage as Box<dyn Any>;
+        let storage = storage.downcast().ok()?;
+        Some(*storage)
+    }
+
+    fn unregister<C>(&mut self) -> Option<C::Storage>
+    where
+        C: Component,
+    {
+        if !self.is_registered::<C>() {
+            return None;
+        }
+        let storage = self.unregister_erased(TypeId::of::<C>())?;
+        let storage = storage as Box<dyn Any>;
+        let Ok(storage) = storage.downcast() else {
+            unreachable!("storage type should be checked before unregistering");
+        };
+        Some(*storage)
+    }
+
+    fn clear(&mut self) {
+        let Self { storages } = self;
+        storages.clear()
+    }
+}
+
+impl TryRegistryMut for DynamicComponents {
+    type Err = Infallible;
+
+    fn try_register<C>(&mut self, storage: C::Storage) -> Result<Option<C::Storage>, Self::Err>
+    where
+        C: Component,
+    {
+        let storage = self.register::<C>(storage);
+        Ok(storage)
+    }
+}
+
+impl fmt::Debug for DynamicComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { storages } = self;
+        let type_names = storages
+            .values()
+            .map(|storage| storage.component_type_name());
+        f.debug_set().entries(type_names).finish()
+    }
+}
+
+/// Iterator over references of all the storages of [dynamic component registry](DynamicComponents).
+///
+/// This struct is created by [`iter`][Registry::iter()] method of the registry.
+pub struct Iter<'me> {
+    iter: Values<'me, TypeId, Box<dyn ErasedStorage>>,
+}
+
+impl<'me> Iterator for Iter<'me> {
+    type Item = &'me dyn ErasedStorage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let storage = iter.next()?;
+        Some(storage.as_ref())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let storage = iter.next_back()?;
+        Some(storage.as_ref())
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+/// Iterator over mutable references of all the storages of [dynamic component registry](DynamicComponents).
+///
+/// This struct is created by [`iter_mut`][Registry::iter_mut()] method of the registry.
+pub struct IterMut<'me> {
+    iter: ValuesMut<'me, TypeId, Box<dyn ErasedStorage>>,
+}
+
+impl<'me> Iterator for IterMut<'me> {
+    type Item = &'me mut dyn ErasedStorage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let storage = iter.next()?;
+        Some(storage.as_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IterMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let storage = iter.next_back()?;
+        Some(storage.as_mut())
+    }
+}
+
+impl ExactSizeIterator for IterMut<'_> {
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl FusedIterator for IterMut<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use core::any::{TypeId, type_name};
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{
+            Component, ErasedComponent,
+            registry::{Registry, RegistryMut},
+            storage::{AttachError, EntityMismatchError, ErasedStorage, array::ArrayStorage},
+        },
+        entity::{DefaultEntity, ErasedEntity, map::EntityMapper, registry::array::ArrayRegistry},
+        world::World,
+    };
+
+    use super::DynamicComponents;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Velocity(i32);
+
+    impl Component for Velocity {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    /// Storage of velocities which claims to store positions.
+    struct Mislabeled(ArrayStorage<Velocity, 10>);
+
+    impl ErasedStorage for Mislabeled {
+        fn attach(
+            &mut self,
+            entity: &dyn ErasedEntity,
+            component: &dyn ErasedComponent,
+        ) -> Result<(), AttachError> {
+            ErasedStorage::attach(&mut self.0, entity, component)
+        }
+
+        fn is_attached(&self, entity: &dyn ErasedEntity) -> Result<bool, EntityMismatchError> {
+            ErasedStorage::is_attached(&self.0, entity)
+        }
+
+        fn get(
+            &self,
+            entity: &dyn ErasedEntity,
+        ) -> Result<Option<&dyn ErasedComponent>, EntityMismatchError> {
+            ErasedStorage::get(&self.0, entity)
+        }
+
+        fn get_mut(
+            &mut self,
+            entity: &dyn ErasedEntity,
+        ) -> Result<Option<&mut dyn ErasedComponent>, EntityMismatchError> {
+            ErasedStorage::get_mut(&mut self.0, entity)
+        }
+
+        fn remove(&mut self, entity: &dyn ErasedEntity) -> Result<(), EntityMismatchError> {
+            ErasedStorage::remove(&mut self.0, entity)
+        }
+
+        fn retain(
+            &mut self,
+            f: &mut dyn FnMut(&dyn ErasedEntity, &mut dyn ErasedComponent) -> bool,
+        ) {
+            ErasedStorage::retain(&mut self.0, f)
+        }
+
+        fn swap(
+            &mut self,
+            a: &dyn ErasedEntity,
+            b: &dyn ErasedEntity,
+        ) -> Result<(), EntityMismatchError> {
+            ErasedStorage::swap(&mut self.0, a, b)
+        }
+
+        fn clone_component(
+            &mut self,
+            from: &dyn ErasedEntity,
+            to: &dyn ErasedEntity,
+        ) -> Result<bool, EntityMismatchError> {
+            ErasedStorage::clone_component(&mut self.0, from, to)
+        }
+
+        fn component_type_id(&self) -> TypeId {
+            TypeId::of::<Position>()
+        }
+
+        fn component_type_name(&self) -> &'static str {
+            type_name::<Position>()
+        }
+
+        fn has_checksum(&self) -> bool {
+            ErasedStorage::has_checksum(&self.0)
+        }
+
+        fn map_entities(&mut self, mapper: &dyn EntityMapper) -> usize {
+            ErasedStorage::map_entities(&mut self.0, mapper)
+        }
+
+        fn for_each(&self, f: &mut dyn FnMut(&dyn ErasedEntity, &dyn ErasedComponent)) {
+            ErasedStorage::for_each(&self.0, f)
+        }
+
+        fn clear(&mut self) {
+            ErasedStorage::clear(&mut self.0)
+        }
+
+        fn len(&self) -> usize {
+            ErasedStorage::len(&self.0)
+        }
+    }
+
+    #[test]
+    fn register() {
+        let mut components = DynamicComponents::new();
+        assert!(
+            components
+                .register::<Position>(ArrayStorage::new())
+                .is_none()
+        );
+        assert!(
+            components
+                .register::<Position>(ArrayStorage::new())
+                .is_some()
+        );
+        assert!(components.is_registered::<Position>());
+        assert!(!components.is_registered::<Velocity>());
+        assert_eq!(components.len(), 1);
+
+        assert!(components.unregister::<Velocity>().is_none());
+        assert!(components.unregister::<Position>().is_some());
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn register_erased() {
+        let mut components = DynamicComponents::new();
+        let storage = Mislabeled(ArrayStorage::new());
+        assert!(components.register_erased(Box::new(storage)).is_none());
+        assert!(!components.is_registered::<Position>());
+        assert!(components.get::<Position>().is_none());
+        assert!(components.unregister::<Position>().is_none());
+        assert_eq!(components.len(), 1);
+
+        assert!(
+            components
+                .register::<Position>(ArrayStorage::new())
+                .is_none()
+        );
+        assert!(components.is_registered::<Position>());
+        assert!(components.unregister::<Position>().is_some());
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn world() {
+        let mut world = World::with(ArrayRegistry::<10>::new(), DynamicComponents::new(), ());
+        let entity = world.create();
+        assert!(world.attach(entity, Position(1)).is_err());
+
+        let _ = world.register::<Position>(ArrayStorage::new());
+        let _ = world.register::<Velocity>(ArrayStorage::new());
+        let _ = world.attach(entity, Position(1)).unwrap();
+        let _ = world.attach(entity, Velocity(2)).unwrap();
+        let bundle = world.get::<HList![Position, Velocity]>(entity).unwrap();
+        assert_eq!(bundle, Some(hlist![&Position(1), &Velocity(2)]));
+
+        let view = world.view::<HList![DefaultEntity, &Position]>().unwrap();
+        let items: std::vec::Vec<_> = view.iter().collect();
+        assert_eq!(items, [hlist![entity, &Position(1)]]);
+
+        let _ = world.unregister::<Velocity>();
+        assert!(world.get::<Velocity>(entity).is_err());
+    }
+}
@@ -4,6 +4,9 @@ use hlist::ops::Here;
 
 use super::{Component, storage::ErasedStorage};
 
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+
 mod impls;
 
 /// Component registry of the world.
@@ -9,7 +9,10 @@ use core::{
 use crate::{
     component::{
         Component,
-        storage::{Storage, TryStorage},
+        storage::{
+            ChangeTick, CheckedStorage, OccupiedEntry as OccupiedStorageEntry, StaleEntityError,
+            Storage, StorageEntry, TrackedStorage, TryStorage, VacantEntry as VacantStorageEntry,
+        },
     },
     entity::{DefaultEntity, Entity},
 };
@@ -67,6 +70,7 @@ where
 {
     slots: [Slot<T, E::Generation>; N],
     len: usize,
+    change_tick: ChangeTick,
 }
 
 impl<T, E, const N: usize> ArrayStorage<T, N, E>
@@ -113,6 +117,7 @@ where
         Self {
             slots: Self::FREE_ARRAY,
             len: 0,
+            change_tick: ChangeTick::new(0),
         }
     }
 
@@ -213,9 +218,41 @@ where
     /// assert!(storage.is_empty());
     /// ```
     pub fn clear(&mut self) {
+        self.mark_changed();
         self.slots = Self::FREE_ARRAY;
         self.len = 0;
     }
+
+    /// Returns current change tick of the array storage.
+    ///
+    /// Change tick is advanced each time the storage is accessed mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// let tick = storage.change_tick();
+    ///
+    /// storage.attach(DefaultEntity::new(5, 1), Position { x: 0.0, y: 0.0 });
+    /// assert!(storage.change_tick() > tick);
+    /// ```
+    pub const fn change_tick(&self) -> ChangeTick {
+        self.change_tick
+    }
+
+    fn mark_changed(&mut self) {
+        self.change_tick = self.change_tick.next();
+    }
 }
 
 impl<T, E, const N: usize> ArrayStorage<T, N, E>
@@ -298,6 +335,7 @@ where
     ///
     /// This is the fallible version of [`attach`][Self::attach()] method.
     pub fn try_attach(&mut self, entity: E, component: T) -> Result<Option<T>, ArrayStorageError> {
+        self.mark_changed();
         let Ok(index) = usize::try_from(entity.index()) else {
             return Err(ArrayStorageError);
         };
@@ -323,6 +361,43 @@ where
             }
         }
     }
+
+    /// Checks if the storage has enough capacity to attach components to all provided entities.
+    ///
+    /// Storage has enough capacity if index of each provided entity is less than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let storage = ArrayStorage::<Position, 10>::new();
+    ///
+    /// let entities = [DefaultEntity::new(0, 0), DefaultEntity::new(9, 0)];
+    /// assert!(storage.can_attach_all(entities));
+    ///
+    /// let entities = [DefaultEntity::new(0, 0), DefaultEntity::new(10, 0)];
+    /// assert!(!storage.can_attach_all(entities));
+    /// ```
+    pub fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = E>,
+    {
+        entities.into_iter().all(|entity| {
+            let Ok(index) = usize::try_from(entity.index()) else {
+                return false;
+            };
+            index < N
+        })
+    }
 }
 
 impl<T, E, const N: usize> ArrayStorage<T, N, E>
@@ -437,6 +512,7 @@ where
     /// assert_eq!(storage.get_mut(entity), None);
     /// ```
     pub fn get_mut(&mut self, entity: E) -> Option<&mut T> {
+        self.mark_changed();
         let index = usize::try_from(entity.index()).ok()?;
         let slot = self.slots.get_mut(index)?;
         let &mut Slot::Occupied {
@@ -479,6 +555,7 @@ where
     /// assert_eq!(component, Some(Position { x: 0.0, y: -10.0 }));
     /// ```
     pub fn remove(&mut self, entity: E) -> Option<T> {
+        self.mark_changed();
         let index = usize::try_from(entity.index()).ok()?;
         let slot = self.slots.get_mut(index)?;
         let Slot::Occupied { value, generation } = mem::replace(slot, Slot::Free) else {
@@ -491,6 +568,132 @@ where
         self.len -= 1;
         Some(value)
     }
+
+    fn attached_index(&self, entity: E) -> Option<usize> {
+        let index = usize::try_from(entity.index()).ok()?;
+        let &Slot::Occupied { generation, .. } = self.slots.get(index)? else {
+            return None;
+        };
+        (generation == entity.generation()).then_some(index)
+    }
+}
+
+impl<T, E, const N: usize> ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    /// Retrieves a reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// let error = storage.get_checked(DefaultEntity::new(9, 0)).unwrap_err();
+    /// assert_eq!(error.stored_generation(), 1);
+    /// assert_eq!(storage.get_checked(DefaultEntity::new(9, 2)).unwrap(), None);
+    /// ```
+    pub fn get_checked(&self, entity: E) -> Result<Option<&T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.get(entity))
+    }
+
+    /// Retrieves a mutable reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.get_mut_checked(DefaultEntity::new(9, 0)).is_err());
+    /// ```
+    pub fn get_mut_checked(&mut self, entity: E) -> Result<Option<&mut T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.get_mut(entity))
+    }
+
+    /// Removes component from provided entity.
+    /// Returns previous component data, or [`None`] if there was no component attached to the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.remove_checked(DefaultEntity::new(9, 0)).is_err());
+    /// assert!(storage.is_attached(DefaultEntity::new(9, 1)));
+    /// ```
+    pub fn remove_checked(&mut self, entity: E) -> Result<Option<T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.remove(entity))
+    }
+
+    fn check_generation(&self, entity: E) -> Result<(), StaleEntityError<E>> {
+        let Ok(index) = usize::try_from(entity.index()) else {
+            return Ok(());
+        };
+        let Some(&Slot::Occupied { generation, .. }) = self.slots.get(index) else {
+            return Ok(());
+        };
+        if generation > entity.generation() {
+            let stored = E::with(entity.index(), generation);
+            return Err(StaleEntityError::new(entity, stored));
+        }
+        Ok(())
+    }
 }
 
 impl<T, E, const N: usize> ArrayStorage<T, N, E>
@@ -558,6 +761,270 @@ where
     pub fn iter_mut(&mut self) -> IterMut<'_, T, N, E> {
         self.into_iter()
     }
+
+    /// Removes all components from the array storage,
+    /// returning them with their entity keys as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed,
+    /// it removes the remaining components from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    ///
+    /// let mut drain = storage.drain();
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 })));
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 })));
+    /// assert_eq!(drain.next(), None);
+    /// drop(drain);
+    ///
+    /// assert!(storage.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, N, E> {
+        self.mark_changed();
+        let Self { slots, len, .. } = self;
+        let iter = slots.iter_mut().enumerate();
+        Drain { iter, len }
+    }
+}
+
+impl<T, E, const N: usize> ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    /// Retains only the components specified by the predicate.
+    ///
+    /// In other words, removes all components for which `f(entity, &mut component)` returns `false`.
+    /// This method visits each component exactly once in the order of entity indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    /// storage.attach(DefaultEntity::new(9, 10), Position { x: 1.0, y: 23.0 });
+    ///
+    /// storage.retain(|_, position| position.x > 0.0);
+    /// assert_eq!(storage.len(), 2);
+    /// assert!(!storage.is_attached(DefaultEntity::new(1, 0)));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(E, &mut T) -> bool,
+    {
+        self.mark_changed();
+        let Self { slots, len, .. } = self;
+        for (index, slot) in slots.iter_mut().enumerate() {
+            let &mut Slot::Occupied {
+                ref mut value,
+                generation,
+            } = slot
+            else {
+                continue;
+            };
+            let Ok(index) = index.try_into() else {
+                continue;
+            };
+            let entity = E::with(index, generation);
+            if f(entity, value) {
+                continue;
+            }
+            *slot = Slot::Free;
+            *len -= 1;
+        }
+    }
+
+    /// Exchanges components attached to provided entities.
+    ///
+    /// If only one of provided entities has a component attached,
+    /// the component is moved to another entity.
+    /// Does nothing if neither of entities has a component attached.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the component should be moved to the entity
+    /// which index is larger than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// let first = DefaultEntity::new(1, 0);
+    /// let second = DefaultEntity::new(7, 15);
+    /// storage.attach(first, Position { x: 0.0, y: -10.0 });
+    /// storage.attach(second, Position { x: 10.0, y: 0.0 });
+    ///
+    /// storage.swap(first, second);
+    /// assert_eq!(storage.get(first), Some(&Position { x: 10.0, y: 0.0 }));
+    /// assert_eq!(storage.get(second), Some(&Position { x: 0.0, y: -10.0 }));
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: E, b: E) {
+        self.mark_changed();
+        if let (Some(a_index), Some(b_index)) = (self.attached_index(a), self.attached_index(b)) {
+            if a_index == b_index {
+                return;
+            }
+            let Ok([a_slot, b_slot]) = self.slots.get_disjoint_mut([a_index, b_index]) else {
+                unreachable!("indices should be valid and distinct");
+            };
+            let (Slot::Occupied { value: a_value, .. }, Slot::Occupied { value: b_value, .. }) =
+                (a_slot, b_slot)
+            else {
+                unreachable!("both slots should be occupied");
+            };
+            mem::swap(a_value, b_value);
+            return;
+        }
+
+        let a_component = self.remove(a);
+        let b_component = self.remove(b);
+        if let Some(component) = a_component {
+            self.attach(b, component);
+        }
+        if let Some(component) = b_component {
+            self.attach(a, component);
+        }
+    }
+}
+
+impl<T, E, const N: usize> ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    /// Retrieves an entry of provided entity in the array storage for in-place manipulation.
+    ///
+    /// If the slot of provided entity is occupied by the component of an entity
+    /// with the same index but different generation, the entry is vacant,
+    /// and inserting into it replaces that component.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided entity index is larger than capacity of the storage.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_entry`][Self::try_entry()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::new();
+    /// let entity = DefaultEntity::new(3, 0);
+    ///
+    /// storage.entry(entity).or_insert(Position { x: 0.0, y: 0.0 }).x += 1.0;
+    /// storage
+    ///     .entry(entity)
+    ///     .and_modify(|position| position.y -= 1.0)
+    ///     .or_insert(Position { x: 10.0, y: 10.0 });
+    /// assert_eq!(storage.get(entity), Some(&Position { x: 1.0, y: -1.0 }));
+    /// ```
+    #[track_caller]
+    pub fn entry(&mut self, entity: E) -> StorageEntry<'_, Self> {
+        match self.try_entry(entity) {
+            Ok(entry) => entry,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Tries to retrieve an entry of provided entity in the array storage for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity index is larger than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::ArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = ArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = ArrayStorage::<Position, 10>::new();
+    ///
+    /// let entity = DefaultEntity::new(11, 0);
+    /// let result = storage.try_entry(entity);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// This is the fallible version of [`entry`][Self::entry()] method.
+    pub fn try_entry(&mut self, entity: E) -> Result<StorageEntry<'_, Self>, ArrayStorageError> {
+        let Ok(index) = usize::try_from(entity.index()) else {
+            return Err(ArrayStorageError);
+        };
+        self.mark_changed();
+        let Self { slots, len, .. } = self;
+        let Some(slot) = slots.get_mut(index) else {
+            return Err(ArrayStorageError);
+        };
+        let is_attached = match *slot {
+            Slot::Occupied { generation, .. } => generation == entity.generation(),
+            Slot::Free => false,
+        };
+        let entry = if is_attached {
+            StorageEntry::Occupied(OccupiedEntry { entity, slot, len })
+        } else {
+            StorageEntry::Vacant(VacantEntry { entity, slot, len })
+        };
+        Ok(entry)
+    }
 }
 
 impl<T, E, const N: usize> Default for ArrayStorage<T, N, E>
@@ -613,6 +1080,13 @@ where
         ArrayStorage::is_empty(self)
     }
 
+    fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        ArrayStorage::can_attach_all(self, entities)
+    }
+
     type Iter<'me>
         = Iter<'me, Self::Item, N, Self::Entity>
     where
@@ -630,6 +1104,43 @@ where
     fn iter_mut(&mut self) -> Self::IterMut<'_> {
         ArrayStorage::iter_mut(self)
     }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(Self::Entity, &mut Self::Item) -> bool,
+    {
+        ArrayStorage::retain(self, f)
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = (Self::Entity, Self::Item)> {
+        ArrayStorage::drain(self)
+    }
+
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Self::Entity, Self::Item)>,
+    {
+        Extend::extend(self, iter)
+    }
+
+    fn swap(&mut self, a: Self::Entity, b: Self::Entity) {
+        ArrayStorage::swap(self, a, b)
+    }
+
+    type OccupiedEntry<'me>
+        = OccupiedEntry<'me, Self::Item, N, Self::Entity>
+    where
+        Self: 'me;
+
+    type VacantEntry<'me>
+        = VacantEntry<'me, Self::Item, N, Self::Entity>
+    where
+        Self: 'me;
+
+    #[track_caller]
+    fn entry(&mut self, entity: Self::Entity) -> StorageEntry<'_, Self> {
+        ArrayStorage::entry(self, entity)
+    }
 }
 
 impl<T, E, const N: usize> TryStorage for ArrayStorage<T, N, E>
@@ -649,6 +1160,75 @@ where
     ) -> Result<Option<Self::Item>, Self::Err> {
         ArrayStorage::try_attach(self, entity, component)
     }
+
+    fn try_entry(&mut self, entity: Self::Entity) -> Result<StorageEntry<'_, Self>, Self::Err> {
+        ArrayStorage::try_entry(self, entity)
+    }
+}
+
+impl<T, E, const N: usize> CheckedStorage for ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn get_checked(
+        &self,
+        entity: Self::Entity,
+    ) -> Result<Option<&Self::Item>, StaleEntityError<Self::Entity>> {
+        ArrayStorage::get_checked(self, entity)
+    }
+
+    fn get_mut_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<&mut Self::Item>, StaleEntityError<Self::Entity>> {
+        ArrayStorage::get_mut_checked(self, entity)
+    }
+
+    fn remove_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<Self::Item>, StaleEntityError<Self::Entity>> {
+        ArrayStorage::remove_checked(self, entity)
+    }
+}
+
+impl<T, E, const N: usize> TrackedStorage for ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn change_tick(&self) -> ChangeTick {
+        ArrayStorage::change_tick(self)
+    }
+
+    fn set_change_tick(&mut self, tick: ChangeTick) {
+        self.change_tick = tick;
+    }
+}
+
+impl<T, E, const N: usize> Extend<(E, T)> for ArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    #[track_caller]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (E, T)>,
+    {
+        for (entity, component) in iter {
+            let _ = self.attach(entity, component);
+        }
+    }
 }
 
 impl<'me, T, E, const N: usize> IntoIterator for &'me ArrayStorage<T, N, E>
@@ -679,6 +1259,7 @@ where
     type IntoIter = IterMut<'me, T, N, E>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.mark_changed();
         let iter = self.slots.iter_mut().enumerate();
         let num_left = self.len;
         IterMut { iter, num_left }
@@ -957,9 +1538,225 @@ where
 {
 }
 
+/// Draining iterator of entities with components attached to them in the array storage.
+///
+/// This struct is created by [`drain`][ArrayStorage::drain()] method of the array storage.
+#[derive(Debug)]
+pub struct Drain<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    iter: Enumerate<slice::IterMut<'data, Slot<T, E::Generation>>>,
+    len: &'data mut usize,
+}
+
+impl<T, E, const N: usize> Iterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    type Item = (E, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = loop {
+            let (index, slot) = self.iter.next()?;
+            if let Slot::Free = slot {
+                continue;
+            }
+            // convert the index before taking the component out of the slot
+            // so the component is never dropped without being yielded
+            let Ok(index) = index.try_into() else {
+                continue;
+            };
+            let Slot::Occupied { value, generation } = mem::replace(slot, Slot::Free) else {
+                continue;
+            };
+            *self.len -= 1;
+            let entity = E::with(index, generation);
+            break (entity, value);
+        };
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, E, const N: usize> DoubleEndedIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = loop {
+            let (index, slot) = self.iter.next_back()?;
+            if let Slot::Free = slot {
+                continue;
+            }
+            // convert the index before taking the component out of the slot
+            // so the component is never dropped without being yielded
+            let Ok(index) = index.try_into() else {
+                continue;
+            };
+            let Slot::Occupied { value, generation } = mem::replace(slot, Slot::Free) else {
+                continue;
+            };
+            *self.len -= 1;
+            let entity = E::with(index, generation);
+            break (entity, value);
+        };
+        Some(item)
+    }
+}
+
+impl<T, E, const N: usize> ExactSizeIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        *self.len
+    }
+}
+
+impl<T, E, const N: usize> FusedIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+}
+
+impl<T, E, const N: usize> Drop for Drain<'_, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    fn drop(&mut self) {
+        self.iter.by_ref().for_each(|(_, slot)| *slot = Slot::Free);
+        *self.len = 0;
+    }
+}
+
+/// View into an occupied entry of the array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][ArrayStorage::entry()] method of the array storage.
+#[derive(Debug)]
+pub struct OccupiedEntry<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    slot: &'data mut Slot<T, E::Generation>,
+    len: &'data mut usize,
+}
+
+impl<'data, T, E, const N: usize> OccupiedStorageEntry<'data> for OccupiedEntry<'data, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn get(&self) -> &Self::Item {
+        let Slot::Occupied { value, .. } = &*self.slot else {
+            unreachable!("slot of occupied entry should be occupied");
+        };
+        value
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Item {
+        let Slot::Occupied { value, .. } = &mut *self.slot else {
+            unreachable!("slot of occupied entry should be occupied");
+        };
+        value
+    }
+
+    fn into_mut(self) -> &'data mut Self::Item {
+        let Slot::Occupied { value, .. } = self.slot else {
+            unreachable!("slot of occupied entry should be occupied");
+        };
+        value
+    }
+
+    fn insert(&mut self, component: Self::Item) -> Self::Item {
+        mem::replace(self.get_mut(), component)
+    }
+
+    fn remove(self) -> Self::Item {
+        let Slot::Occupied { value, .. } = mem::replace(self.slot, Slot::Free) else {
+            unreachable!("slot of occupied entry should be occupied");
+        };
+        *self.len -= 1;
+        value
+    }
+}
+
+/// View into a vacant entry of the array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][ArrayStorage::entry()] method of the array storage.
+#[derive(Debug)]
+pub struct VacantEntry<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    slot: &'data mut Slot<T, E::Generation>,
+    len: &'data mut usize,
+}
+
+impl<'data, T, E, const N: usize> VacantStorageEntry<'data> for VacantEntry<'data, T, N, E>
+where
+    T: Component<Storage = ArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn insert(self, component: Self::Item) -> &'data mut Self::Item {
+        let Self { entity, slot, len } = self;
+        if let Slot::Free = slot {
+            *len += 1;
+        }
+        *slot = Slot::Occupied {
+            value: component,
+            generation: entity.generation(),
+        };
+        let Slot::Occupied { value, .. } = slot else {
+            unreachable!("slot should be occupied after insertion");
+        };
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{component::Component, entity::DefaultEntity as Entity};
+    use crate::{
+        component::{
+            Component,
+            storage::{OccupiedEntry, StorageEntry, VacantEntry},
+        },
+        entity::DefaultEntity as Entity,
+    };
 
     use super::ArrayStorage;
 
@@ -1055,4 +1852,113 @@ mod tests {
         let entity = iter.find(|(entity, _)| entity.index() == 2);
         assert!(entity.is_none());
     }
+
+    #[test]
+    fn retain() {
+        let mut storage = ArrayStorage::new();
+        for index in 0..5 {
+            let _ = storage.attach(Entity::new(index, 0), Marker);
+        }
+
+        let mut calls = 0;
+        crate::component::storage::Storage::retain(&mut storage, |entity, _| {
+            calls += 1;
+            entity.index() % 2 == 0
+        });
+        assert_eq!(calls, 5);
+        assert_eq!(storage.len(), 3);
+        assert!(!storage.is_attached(Entity::new(1, 0)));
+        assert!(storage.is_attached(Entity::new(2, 0)));
+    }
+
+    #[test]
+    fn drain() {
+        let mut storage = ArrayStorage::new();
+        storage.extend((0..5).map(|index| (Entity::new(index, 0), Marker)));
+
+        let mut drain = storage.drain();
+        assert_eq!(drain.len(), 5);
+        let _ = drain.next();
+        drop(drain);
+        assert!(storage.is_empty());
+        assert_eq!(storage.iter().count(), 0);
+    }
+
+    #[test]
+    fn swap() {
+        let mut storage = ArrayStorage::new();
+        let first = Entity::new(1, 0);
+        let second = Entity::new(3, 2);
+
+        let _ = storage.attach(first, Marker);
+        storage.swap(first, second);
+        assert!(!storage.is_attached(first));
+        assert!(storage.is_attached(second));
+
+        storage.swap(first, second);
+        assert!(storage.is_attached(first));
+        assert!(!storage.is_attached(second));
+    }
+
+    #[test]
+    fn clone_component() {
+        use crate::component::storage::Storage;
+
+        let mut storage = ArrayStorage::new();
+        let first = Entity::new(1, 0);
+        let second = Entity::new(3, 2);
+
+        assert!(!storage.clone_component(first, second));
+        assert!(storage.is_empty());
+
+        let _ = storage.attach(first, Marker);
+        assert!(storage.clone_component(first, second));
+        assert!(storage.is_attached(first));
+        assert!(storage.is_attached(second));
+        assert_eq!(storage.len(), 2);
+    }
+
+    #[test]
+    fn entry() {
+        let mut storage = ArrayStorage::new();
+        let entity = Entity::new(1, 0);
+
+        let _ = storage.entry(entity).or_insert(Marker);
+        assert!(storage.is_attached(entity));
+        assert_eq!(storage.len(), 1);
+
+        let StorageEntry::Occupied(entry) = storage.entry(entity) else {
+            panic!("entry should be occupied");
+        };
+        let _ = entry.remove();
+        assert!(!storage.is_attached(entity));
+        assert!(storage.is_empty());
+
+        let _ = storage.attach(entity, Marker);
+        let newer = Entity::new(1, 1);
+        let StorageEntry::Vacant(entry) = storage.entry(newer) else {
+            panic!("entry should be vacant");
+        };
+        let _ = entry.insert(Marker);
+        assert!(storage.is_attached(newer));
+        assert!(!storage.is_attached(entity));
+        assert_eq!(storage.len(), 1);
+    }
+
+    #[test]
+    fn checked() {
+        let mut storage = ArrayStorage::new();
+        let _ = storage.attach(Entity::new(1, 1), Marker);
+
+        let error = storage.get_checked(Entity::new(1, 0)).unwrap_err();
+        assert_eq!(error.requested_generation(), 0);
+        assert_eq!(error.stored_generation(), 1);
+        assert!(storage.get_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 2)).unwrap().is_none());
+        assert!(storage.get_checked(Entity::new(2, 0)).unwrap().is_none());
+
+        assert!(storage.remove_checked(Entity::new(1, 0)).is_err());
+        assert!(storage.remove_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 0)).unwrap().is_none());
+    }
 }
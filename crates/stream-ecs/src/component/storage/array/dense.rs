@@ -7,7 +7,10 @@ use arrayvec::ArrayVec;
 use crate::{
     component::{
         Component,
-        storage::{Storage, TryStorage},
+        storage::{
+            ChangeTick, CheckedStorage, OccupiedEntry as OccupiedStorageEntry, StaleEntityError,
+            Storage, StorageEntry, TrackedStorage, TryStorage, VacantEntry as VacantStorageEntry,
+        },
     },
     entity::{DefaultEntity, Entity},
 };
@@ -24,7 +27,7 @@ where
     value: T,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 enum Slot<G> {
     Occupied { dense_index: usize, generation: G },
     Free,
@@ -83,6 +86,7 @@ where
 {
     dense: ArrayVec<Dense<T, E::Generation>, N>,
     sparse: [Slot<E::Generation>; N],
+    change_tick: ChangeTick,
 }
 
 impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
@@ -129,6 +133,7 @@ where
         Self {
             dense: ArrayVec::new_const(),
             sparse: Self::FREE_ARRAY,
+            change_tick: ChangeTick::new(0),
         }
     }
 
@@ -229,9 +234,62 @@ where
     /// assert!(storage.is_empty());
     /// ```
     pub fn clear(&mut self) {
+        self.mark_changed();
         self.dense.clear();
         self.sparse = Self::FREE_ARRAY;
     }
+
+    /// Returns current change tick of the dense array storage.
+    ///
+    /// Change tick is advanced each time the storage is accessed mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// let tick = storage.change_tick();
+    ///
+    /// storage.attach(DefaultEntity::new(5, 1), Position { x: 0.0, y: 0.0 });
+    /// assert!(storage.change_tick() > tick);
+    /// ```
+    pub const fn change_tick(&self) -> ChangeTick {
+        self.change_tick
+    }
+
+    fn mark_changed(&mut self) {
+        self.change_tick = self.change_tick.next();
+    }
+
+    fn swap_remove_dense(&mut self, dense_index: usize) -> Dense<T, E::Generation> {
+        let dense = self
+            .dense
+            .swap_pop(dense_index)
+            .expect("dense index should point to the valid item");
+        if let Some(&Dense { index, .. }) = self.dense.get(dense_index) {
+            let slot = self
+                .sparse
+                .get_mut(index)
+                .expect("index should point to the valid slot");
+            if let Slot::Occupied {
+                dense_index: moved_index,
+                ..
+            } = slot
+            {
+                *moved_index = dense_index;
+            }
+        }
+        dense
+    }
 }
 
 impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
@@ -314,6 +372,7 @@ where
     ///
     /// This is the fallible version of [`attach`][Self::attach()] method.
     pub fn try_attach(&mut self, entity: E, component: T) -> Result<Option<T>, ArrayStorageError> {
+        self.mark_changed();
         let Ok(index) = usize::try_from(entity.index()) else {
             return Err(ArrayStorageError);
         };
@@ -355,6 +414,43 @@ where
             }
         }
     }
+
+    /// Checks if the storage has enough capacity to attach components to all provided entities.
+    ///
+    /// Storage has enough capacity if index of each provided entity is less than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let storage = DenseArrayStorage::<Position, 10>::new();
+    ///
+    /// let entities = [DefaultEntity::new(0, 0), DefaultEntity::new(9, 0)];
+    /// assert!(storage.can_attach_all(entities));
+    ///
+    /// let entities = [DefaultEntity::new(0, 0), DefaultEntity::new(10, 0)];
+    /// assert!(!storage.can_attach_all(entities));
+    /// ```
+    pub fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = E>,
+    {
+        entities.into_iter().all(|entity| {
+            let Ok(index) = usize::try_from(entity.index()) else {
+                return false;
+            };
+            index < N
+        })
+    }
 }
 
 impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
@@ -477,6 +573,7 @@ where
     /// assert_eq!(storage.get_mut(entity), None);
     /// ```
     pub fn get_mut(&mut self, entity: E) -> Option<&mut T> {
+        self.mark_changed();
         let index = usize::try_from(entity.index()).ok()?;
         let slot = self.sparse.get(index)?;
         let &Slot::Occupied {
@@ -520,6 +617,7 @@ where
     /// assert_eq!(component, Some(Position { x: 0.0, y: -10.0 }));
     /// ```
     pub fn remove(&mut self, entity: E) -> Option<T> {
+        self.mark_changed();
         let index = usize::try_from(entity.index()).ok()?;
         let slot = self.sparse.get_mut(index)?;
         let Slot::Occupied {
@@ -536,25 +634,139 @@ where
             };
             return None;
         }
-        let Dense { value, .. } = self
-            .dense
-            .swap_pop(dense_index)
-            .expect("dense index should point to the valid item");
-        if let Some(&Dense { index, .. }) = self.dense.get(dense_index) {
-            let slot = self
-                .sparse
-                .get_mut(index)
-                .expect("index should point to the valid slot");
-            *slot = match slot {
-                Slot::Occupied { .. } => Slot::Occupied {
-                    dense_index,
-                    generation,
-                },
-                Slot::Free => Slot::Free,
-            };
-        }
+        let Dense { value, .. } = self.swap_remove_dense(dense_index);
         Some(value)
     }
+
+    fn dense_index(&self, entity: E) -> Option<usize> {
+        let index = usize::try_from(entity.index()).ok()?;
+        let &Slot::Occupied {
+            dense_index,
+            generation,
+        } = self.sparse.get(index)?
+        else {
+            return None;
+        };
+        (generation == entity.generation()).then_some(dense_index)
+    }
+}
+
+impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    /// Retrieves a reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// let error = storage.get_checked(DefaultEntity::new(9, 0)).unwrap_err();
+    /// assert_eq!(error.stored_generation(), 1);
+    /// assert_eq!(storage.get_checked(DefaultEntity::new(9, 2)).unwrap(), None);
+    /// ```
+    pub fn get_checked(&self, entity: E) -> Result<Option<&T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.get(entity))
+    }
+
+    /// Retrieves a mutable reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.get_mut_checked(DefaultEntity::new(9, 0)).is_err());
+    /// ```
+    pub fn get_mut_checked(&mut self, entity: E) -> Result<Option<&mut T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.get_mut(entity))
+    }
+
+    /// Removes component from provided entity.
+    /// Returns previous component data, or [`None`] if there was no component attached to the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.remove_checked(DefaultEntity::new(9, 0)).is_err());
+    /// assert!(storage.is_attached(DefaultEntity::new(9, 1)));
+    /// ```
+    pub fn remove_checked(&mut self, entity: E) -> Result<Option<T>, StaleEntityError<E>> {
+        self.check_generation(entity)?;
+        Ok(self.remove(entity))
+    }
+
+    fn check_generation(&self, entity: E) -> Result<(), StaleEntityError<E>> {
+        let Ok(index) = usize::try_from(entity.index()) else {
+            return Ok(());
+        };
+        let Some(&Slot::Occupied { generation, .. }) = self.sparse.get(index) else {
+            return Ok(());
+        };
+        if generation > entity.generation() {
+            let stored = E::with(entity.index(), generation);
+            return Err(StaleEntityError::new(entity, stored));
+        }
+        Ok(())
+    }
 }
 
 impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
@@ -622,52 +834,326 @@ where
     pub fn iter_mut(&mut self) -> IterMut<'_, T, N, E> {
         self.into_iter()
     }
-}
 
-impl<T, E, const N: usize> Default for DenseArrayStorage<T, N, E>
-where
-    T: Component<Storage = Self>,
-    E: Entity,
-{
-    fn default() -> Self {
-        Self::new()
+    /// Removes all components from the dense array storage,
+    /// returning them with their entity keys as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed,
+    /// it removes the remaining components from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    ///
+    /// let mut drain = storage.drain();
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 })));
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 })));
+    /// assert_eq!(drain.next(), None);
+    /// drop(drain);
+    ///
+    /// assert!(storage.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, N, E> {
+        self.mark_changed();
+        self.sparse = Self::FREE_ARRAY;
+        let iter = self.dense.drain(..);
+        Drain { iter }
     }
 }
 
-impl<T, E, const N: usize> Storage for DenseArrayStorage<T, N, E>
+impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
 where
     T: Component<Storage = Self>,
     E: Entity,
-    E::Index: TryFrom<usize> + PartialEq,
+    E::Index: TryFrom<usize>,
     E::Generation: PartialOrd,
     usize: TryFrom<E::Index>,
 {
-    type Item = T;
-    type Entity = E;
-
-    fn attach(&mut self, entity: Self::Entity, component: Self::Item) -> Option<Self::Item> {
-        DenseArrayStorage::attach(self, entity, component)
-    }
-
-    fn is_attached(&self, entity: Self::Entity) -> bool {
-        DenseArrayStorage::is_attached(self, entity)
-    }
-
-    fn get(&self, entity: Self::Entity) -> Option<&Self::Item> {
-        DenseArrayStorage::get(self, entity)
-    }
-
-    fn get_mut(&mut self, entity: Self::Entity) -> Option<&mut Self::Item> {
-        DenseArrayStorage::get_mut(self, entity)
-    }
-
-    fn remove(&mut self, entity: Self::Entity) -> Option<Self::Item> {
-        DenseArrayStorage::remove(self, entity)
+    /// Retains only the components specified by the predicate.
+    ///
+    /// In other words, removes all components for which `f(entity, &mut component)` returns `false`.
+    /// This method visits each component exactly once, but the order of visiting is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    /// storage.attach(DefaultEntity::new(9, 10), Position { x: 1.0, y: 23.0 });
+    ///
+    /// storage.retain(|_, position| position.x > 0.0);
+    /// assert_eq!(storage.len(), 2);
+    /// assert!(!storage.is_attached(DefaultEntity::new(1, 0)));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(E, &mut T) -> bool,
+    {
+        self.mark_changed();
+        let mut dense_index = 0;
+        while let Some(dense) = self.dense.get_mut(dense_index) {
+            let &mut Dense {
+                index,
+                generation,
+                ref mut value,
+            } = dense;
+            let retain = match index.try_into() {
+                Ok(entity_index) => f(E::with(entity_index, generation), value),
+                Err(_) => true,
+            };
+            if retain {
+                dense_index += 1;
+                continue;
+            }
+            let slot = self
+                .sparse
+                .get_mut(index)
+                .expect("index should point to the valid slot");
+            *slot = Slot::Free;
+            let _ = self.swap_remove_dense(dense_index);
+        }
     }
 
-    fn clear(&mut self) {
-        DenseArrayStorage::clear(self)
-    }
+    /// Exchanges components attached to provided entities.
+    ///
+    /// If only one of provided entities has a component attached,
+    /// the component is moved to another entity.
+    /// Does nothing if neither of entities has a component attached.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the component should be moved to the entity
+    /// which index is larger than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// let first = DefaultEntity::new(1, 0);
+    /// let second = DefaultEntity::new(7, 15);
+    /// storage.attach(first, Position { x: 0.0, y: -10.0 });
+    /// storage.attach(second, Position { x: 10.0, y: 0.0 });
+    ///
+    /// storage.swap(first, second);
+    /// assert_eq!(storage.get(first), Some(&Position { x: 10.0, y: 0.0 }));
+    /// assert_eq!(storage.get(second), Some(&Position { x: 0.0, y: -10.0 }));
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: E, b: E) {
+        self.mark_changed();
+        if let (Some(a_index), Some(b_index)) = (self.dense_index(a), self.dense_index(b)) {
+            if a_index == b_index {
+                return;
+            }
+            let Ok([a_dense, b_dense]) = self.dense.get_disjoint_mut([a_index, b_index]) else {
+                unreachable!("dense indices should be valid and distinct");
+            };
+            mem::swap(&mut a_dense.value, &mut b_dense.value);
+            return;
+        }
+
+        let a_component = self.remove(a);
+        let b_component = self.remove(b);
+        if let Some(component) = a_component {
+            self.attach(b, component);
+        }
+        if let Some(component) = b_component {
+            self.attach(a, component);
+        }
+    }
+}
+
+impl<T, E, const N: usize> DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    /// Retrieves an entry of provided entity in the dense array storage for in-place manipulation.
+    ///
+    /// If the slot of provided entity is occupied by the component of an entity
+    /// with the same index but different generation, the entry is vacant,
+    /// and inserting into it replaces that component.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided entity index is larger than capacity of the storage.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_entry`][Self::try_entry()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::new();
+    /// let entity = DefaultEntity::new(3, 0);
+    ///
+    /// storage.entry(entity).or_insert(Position { x: 0.0, y: 0.0 }).x += 1.0;
+    /// storage
+    ///     .entry(entity)
+    ///     .and_modify(|position| position.y -= 1.0)
+    ///     .or_insert(Position { x: 10.0, y: 10.0 });
+    /// assert_eq!(storage.get(entity), Some(&Position { x: 1.0, y: -1.0 }));
+    /// ```
+    #[track_caller]
+    pub fn entry(&mut self, entity: E) -> StorageEntry<'_, Self> {
+        match self.try_entry(entity) {
+            Ok(entry) => entry,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Tries to retrieve an entry of provided entity in the dense array storage for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity index is larger than capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::{component::storage::array::DenseArrayStorage, entity::DefaultEntity};
+    /// # use stream_ecs::component::Component;
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = DenseArrayStorage<Self, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = DenseArrayStorage::<Position, 10>::new();
+    ///
+    /// let entity = DefaultEntity::new(11, 0);
+    /// let result = storage.try_entry(entity);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// This is the fallible version of [`entry`][Self::entry()] method.
+    pub fn try_entry(&mut self, entity: E) -> Result<StorageEntry<'_, Self>, ArrayStorageError> {
+        self.mark_changed();
+        let Ok(index) = usize::try_from(entity.index()) else {
+            return Err(ArrayStorageError);
+        };
+        let Some(&slot) = self.sparse.get(index) else {
+            return Err(ArrayStorageError);
+        };
+        let entry = match slot {
+            Slot::Occupied {
+                dense_index,
+                generation,
+            } if generation == entity.generation() => {
+                let storage = self;
+                StorageEntry::Occupied(OccupiedEntry {
+                    entity,
+                    dense_index,
+                    storage,
+                })
+            }
+            Slot::Free if self.dense.is_full() => return Err(ArrayStorageError),
+            _ => {
+                let storage = self;
+                StorageEntry::Vacant(VacantEntry {
+                    entity,
+                    index,
+                    storage,
+                })
+            }
+        };
+        Ok(entry)
+    }
+}
+
+impl<T, E, const N: usize> Default for DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E, const N: usize> Storage for DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn attach(&mut self, entity: Self::Entity, component: Self::Item) -> Option<Self::Item> {
+        DenseArrayStorage::attach(self, entity, component)
+    }
+
+    fn is_attached(&self, entity: Self::Entity) -> bool {
+        DenseArrayStorage::is_attached(self, entity)
+    }
+
+    fn get(&self, entity: Self::Entity) -> Option<&Self::Item> {
+        DenseArrayStorage::get(self, entity)
+    }
+
+    fn get_mut(&mut self, entity: Self::Entity) -> Option<&mut Self::Item> {
+        DenseArrayStorage::get_mut(self, entity)
+    }
+
+    fn remove(&mut self, entity: Self::Entity) -> Option<Self::Item> {
+        DenseArrayStorage::remove(self, entity)
+    }
+
+    fn clear(&mut self) {
+        DenseArrayStorage::clear(self)
+    }
 
     fn len(&self) -> usize {
         DenseArrayStorage::len(self)
@@ -677,6 +1163,13 @@ where
         DenseArrayStorage::is_empty(self)
     }
 
+    fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        DenseArrayStorage::can_attach_all(self, entities)
+    }
+
     type Iter<'me>
         = Iter<'me, Self::Item, N, Self::Entity>
     where
@@ -694,6 +1187,43 @@ where
     fn iter_mut(&mut self) -> Self::IterMut<'_> {
         DenseArrayStorage::iter_mut(self)
     }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(Self::Entity, &mut Self::Item) -> bool,
+    {
+        DenseArrayStorage::retain(self, f)
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = (Self::Entity, Self::Item)> {
+        DenseArrayStorage::drain(self)
+    }
+
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Self::Entity, Self::Item)>,
+    {
+        Extend::extend(self, iter)
+    }
+
+    fn swap(&mut self, a: Self::Entity, b: Self::Entity) {
+        DenseArrayStorage::swap(self, a, b)
+    }
+
+    type OccupiedEntry<'me>
+        = OccupiedEntry<'me, Self::Item, N, Self::Entity>
+    where
+        Self: 'me;
+
+    type VacantEntry<'me>
+        = VacantEntry<'me, Self::Item, N, Self::Entity>
+    where
+        Self: 'me;
+
+    #[track_caller]
+    fn entry(&mut self, entity: Self::Entity) -> StorageEntry<'_, Self> {
+        DenseArrayStorage::entry(self, entity)
+    }
 }
 
 impl<T, E, const N: usize> TryStorage for DenseArrayStorage<T, N, E>
@@ -713,6 +1243,75 @@ where
     ) -> Result<Option<Self::Item>, Self::Err> {
         DenseArrayStorage::try_attach(self, entity, component)
     }
+
+    fn try_entry(&mut self, entity: Self::Entity) -> Result<StorageEntry<'_, Self>, Self::Err> {
+        DenseArrayStorage::try_entry(self, entity)
+    }
+}
+
+impl<T, E, const N: usize> CheckedStorage for DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn get_checked(
+        &self,
+        entity: Self::Entity,
+    ) -> Result<Option<&Self::Item>, StaleEntityError<Self::Entity>> {
+        DenseArrayStorage::get_checked(self, entity)
+    }
+
+    fn get_mut_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<&mut Self::Item>, StaleEntityError<Self::Entity>> {
+        DenseArrayStorage::get_mut_checked(self, entity)
+    }
+
+    fn remove_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<Self::Item>, StaleEntityError<Self::Entity>> {
+        DenseArrayStorage::remove_checked(self, entity)
+    }
+}
+
+impl<T, E, const N: usize> TrackedStorage for DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn change_tick(&self) -> ChangeTick {
+        DenseArrayStorage::change_tick(self)
+    }
+
+    fn set_change_tick(&mut self, tick: ChangeTick) {
+        self.change_tick = tick;
+    }
+}
+
+impl<T, E, const N: usize> Extend<(E, T)> for DenseArrayStorage<T, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    #[track_caller]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (E, T)>,
+    {
+        for (entity, component) in iter {
+            let _ = self.attach(entity, component);
+        }
+    }
 }
 
 impl<'me, T, E, const N: usize> IntoIterator for &'me DenseArrayStorage<T, N, E>
@@ -742,6 +1341,7 @@ where
     type IntoIter = IterMut<'me, T, N, E>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.mark_changed();
         let iter = self.dense.iter_mut();
         IterMut { iter }
     }
@@ -978,9 +1578,231 @@ where
 {
 }
 
+/// Draining iterator of entities with components attached to them in the dense array storage.
+///
+/// This struct is created by [`drain`][DenseArrayStorage::drain()] method of the dense array storage.
+pub struct Drain<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    iter: arrayvec::Drain<'data, Dense<T, E::Generation>, N>,
+}
+
+impl<T, E, const N: usize> Iterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    type Item = (E, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Dense {
+            index,
+            generation,
+            value,
+        } = self.iter.next()?;
+        let index = index.try_into().ok()?;
+        let entity = E::with(index, generation);
+        Some((entity, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, E, const N: usize> DoubleEndedIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Dense {
+            index,
+            generation,
+            value,
+        } = self.iter.next_back()?;
+        let index = index.try_into().ok()?;
+        let entity = E::with(index, generation);
+        Some((entity, value))
+    }
+}
+
+impl<T, E, const N: usize> ExactSizeIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, E, const N: usize> FusedIterator for Drain<'_, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+    E::Index: TryFrom<usize>,
+{
+}
+
+/// View into an occupied entry of the dense array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][DenseArrayStorage::entry()] method of the dense array storage.
+pub struct OccupiedEntry<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    dense_index: usize,
+    storage: &'data mut DenseArrayStorage<T, N, E>,
+}
+
+impl<'data, T, E, const N: usize> OccupiedStorageEntry<'data> for OccupiedEntry<'data, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn get(&self) -> &Self::Item {
+        let Dense { value, .. } = self
+            .storage
+            .dense
+            .get(self.dense_index)
+            .expect("dense index should point to the valid item");
+        value
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Item {
+        let Dense { value, .. } = self
+            .storage
+            .dense
+            .get_mut(self.dense_index)
+            .expect("dense index should point to the valid item");
+        value
+    }
+
+    fn into_mut(self) -> &'data mut Self::Item {
+        let Dense { value, .. } = self
+            .storage
+            .dense
+            .get_mut(self.dense_index)
+            .expect("dense index should point to the valid item");
+        value
+    }
+
+    fn insert(&mut self, component: Self::Item) -> Self::Item {
+        mem::replace(self.get_mut(), component)
+    }
+
+    fn remove(self) -> Self::Item {
+        let Self {
+            dense_index,
+            storage,
+            ..
+        } = self;
+        let Dense { index, value, .. } = storage.swap_remove_dense(dense_index);
+        let slot = storage
+            .sparse
+            .get_mut(index)
+            .expect("index should point to the valid slot");
+        *slot = Slot::Free;
+        value
+    }
+}
+
+/// View into a vacant entry of the dense array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][DenseArrayStorage::entry()] method of the dense array storage.
+pub struct VacantEntry<'data, T, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    index: usize,
+    storage: &'data mut DenseArrayStorage<T, N, E>,
+}
+
+impl<'data, T, E, const N: usize> VacantStorageEntry<'data> for VacantEntry<'data, T, N, E>
+where
+    T: Component<Storage = DenseArrayStorage<T, N, E>>,
+    E: Entity,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn insert(self, component: Self::Item) -> &'data mut Self::Item {
+        let Self {
+            entity,
+            index,
+            storage,
+        } = self;
+        let DenseArrayStorage { dense, sparse, .. } = storage;
+        let slot = sparse
+            .get_mut(index)
+            .expect("index should point to the valid slot");
+        let dense_index = match *slot {
+            Slot::Occupied { dense_index, .. } => {
+                let dense = dense
+                    .get_mut(dense_index)
+                    .expect("dense index should point to the valid item");
+                *dense = Dense {
+                    index,
+                    generation: entity.generation(),
+                    value: component,
+                };
+                dense_index
+            }
+            Slot::Free => {
+                let dense_item = Dense {
+                    index,
+                    generation: entity.generation(),
+                    value: component,
+                };
+                dense
+                    .try_push(dense_item)
+                    .expect("vacant entry should have free space in the storage");
+                dense.len() - 1
+            }
+        };
+        *slot = Slot::Occupied {
+            dense_index,
+            generation: entity.generation(),
+        };
+        let Dense { value, .. } = dense
+            .get_mut(dense_index)
+            .expect("dense index should point to the valid item");
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{component::Component, entity::DefaultEntity as Entity};
+    use crate::{
+        component::{
+            Component,
+            storage::{OccupiedEntry, StorageEntry, VacantEntry},
+        },
+        entity::DefaultEntity as Entity,
+    };
 
     use super::DenseArrayStorage;
 
@@ -1076,4 +1898,106 @@ mod tests {
         let entity = iter.find(|(entity, _)| entity.index() == 2);
         assert!(entity.is_none());
     }
+
+    #[test]
+    fn retain() {
+        let mut storage = DenseArrayStorage::new();
+        for index in 0..5 {
+            let _ = storage.attach(Entity::new(index, 0), Marker);
+        }
+
+        let mut calls = 0;
+        crate::component::storage::Storage::retain(&mut storage, |entity, _| {
+            calls += 1;
+            entity.index() % 2 == 0
+        });
+        assert_eq!(calls, 5);
+        assert_eq!(storage.len(), 3);
+        assert!(!storage.is_attached(Entity::new(1, 0)));
+        assert!(storage.is_attached(Entity::new(2, 0)));
+        assert!(storage.is_attached(Entity::new(4, 0)));
+    }
+
+    #[test]
+    fn drain() {
+        let mut storage = DenseArrayStorage::new();
+        storage.extend((0..5).map(|index| (Entity::new(index, 0), Marker)));
+
+        let mut drain = storage.drain();
+        assert_eq!(drain.len(), 5);
+        let _ = drain.next();
+        drop(drain);
+        assert!(storage.is_empty());
+        assert!(!storage.is_attached(Entity::new(4, 0)));
+    }
+
+    #[test]
+    fn swap() {
+        let mut storage = DenseArrayStorage::new();
+        let first = Entity::new(1, 0);
+        let second = Entity::new(3, 2);
+
+        let _ = storage.attach(first, Marker);
+        storage.swap(first, second);
+        assert!(!storage.is_attached(first));
+        assert!(storage.is_attached(second));
+
+        storage.swap(first, second);
+        assert!(storage.is_attached(first));
+        assert!(!storage.is_attached(second));
+    }
+
+    #[test]
+    fn entry() {
+        let mut storage = DenseArrayStorage::new();
+        let entity = Entity::new(1, 0);
+        let _ = storage.attach(Entity::new(0, 0), Marker);
+
+        let _ = storage.entry(entity).or_insert(Marker);
+        assert!(storage.is_attached(entity));
+        assert_eq!(storage.len(), 2);
+
+        let StorageEntry::Occupied(entry) = storage.entry(entity) else {
+            panic!("entry should be occupied");
+        };
+        let _ = entry.remove();
+        assert!(!storage.is_attached(entity));
+        assert_eq!(storage.len(), 1);
+
+        let _ = storage.attach(entity, Marker);
+        let newer = Entity::new(1, 1);
+        let StorageEntry::Vacant(entry) = storage.entry(newer) else {
+            panic!("entry should be vacant");
+        };
+        let _ = entry.insert(Marker);
+        assert!(storage.is_attached(newer));
+        assert!(!storage.is_attached(entity));
+        assert_eq!(storage.len(), 2);
+    }
+
+    #[test]
+    fn entry_full() {
+        let mut storage = DenseArrayStorage::<Marker, 10>::new();
+        storage.extend((0..10).map(|index| (Entity::new(index, 0), Marker)));
+
+        assert!(storage.try_entry(Entity::new(0, 0)).is_ok());
+        assert!(storage.try_entry(Entity::new(0, 1)).is_ok());
+    }
+
+    #[test]
+    fn checked() {
+        let mut storage = DenseArrayStorage::new();
+        let _ = storage.attach(Entity::new(1, 1), Marker);
+
+        let error = storage.get_checked(Entity::new(1, 0)).unwrap_err();
+        assert_eq!(error.requested_generation(), 0);
+        assert_eq!(error.stored_generation(), 1);
+        assert!(storage.get_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 2)).unwrap().is_none());
+        assert!(storage.get_checked(Entity::new(2, 0)).unwrap().is_none());
+
+        assert!(storage.remove_checked(Entity::new(1, 0)).is_err());
+        assert!(storage.remove_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 0)).unwrap().is_none());
+    }
 }
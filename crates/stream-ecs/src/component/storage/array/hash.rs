@@ -1,6 +1,7 @@
 //! Hash component storage implementation backed by an array.
 
 use core::{
+    cmp::Ordering,
     hash::{BuildHasher, Hash},
     iter::{self, FusedIterator},
     mem, slice,
@@ -11,7 +12,10 @@ use arrayvec::ArrayVec;
 use crate::{
     component::{
         Component,
-        storage::{Storage, TryStorage},
+        storage::{
+            ChangeTick, CheckedStorage, OccupiedEntry as OccupiedStorageEntry, StaleEntityError,
+            Storage, StorageEntry, TrackedStorage, TryStorage, VacantEntry as VacantStorageEntry,
+        },
     },
     entity::{DefaultEntity, Entity},
 };
@@ -38,7 +42,11 @@ impl HashValue {
 
     fn probe_distance(self, len: u64, current: u64) -> u64 {
         let desired = self.desired_index(len);
-        current.wrapping_sub(desired) % len
+        if current >= desired {
+            current - desired
+        } else {
+            len - desired + current
+        }
     }
 }
 
@@ -115,6 +123,7 @@ where
     buckets: ArrayVec<Bucket<E, T>, N>,
     indices: [HashIndex; N],
     build_hasher: S,
+    change_tick: ChangeTick,
 }
 
 impl<T, E, S, const N: usize> HashArrayStorage<T, S, N, E>
@@ -232,6 +241,7 @@ where
             buckets: ArrayVec::new_const(),
             indices: Self::EMPTY_ARRAY,
             build_hasher,
+            change_tick: ChangeTick::new(0),
         }
     }
 
@@ -336,10 +346,43 @@ where
     /// assert!(storage.is_empty());
     /// ```
     pub fn clear(&mut self) {
+        self.mark_changed();
         self.buckets.clear();
         self.indices = Self::EMPTY_ARRAY;
     }
 
+    /// Returns current change tick of the hash array storage.
+    ///
+    /// Change tick is advanced each time the storage is accessed mutably.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// let tick = storage.change_tick();
+    ///
+    /// storage.attach(DefaultEntity::new(15, 1), Position { x: 0.0, y: 0.0 });
+    /// assert!(storage.change_tick() > tick);
+    /// ```
+    pub const fn change_tick(&self) -> ChangeTick {
+        self.change_tick
+    }
+
+    fn mark_changed(&mut self) {
+        self.change_tick = self.change_tick.next();
+    }
+
     /// Returns an iterator over entity keys with references of components attached to them.
     ///
     /// # Examples
@@ -401,6 +444,45 @@ where
     pub fn iter_mut(&mut self) -> IterMut<'_, T, S, N, E> {
         self.into_iter()
     }
+
+    /// Removes all components from the hash array storage,
+    /// returning them with their entity keys as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed,
+    /// it removes the remaining components from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    ///
+    /// let mut drain = storage.drain();
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 })));
+    /// assert_eq!(drain.next(), Some((DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 })));
+    /// assert_eq!(drain.next(), None);
+    /// drop(drain);
+    ///
+    /// assert!(storage.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, S, N, E> {
+        self.mark_changed();
+        self.indices = Self::EMPTY_ARRAY;
+        let iter = self.buckets.drain(..);
+        Drain { iter }
+    }
 }
 
 impl<T, E, S, const N: usize> Default for HashArrayStorage<T, S, N, E>
@@ -511,6 +593,17 @@ where
     ///
     /// This is the fallible version of [`attach`][Self::attach()] method.
     pub fn try_attach(&mut self, entity: E, component: T) -> Result<Option<T>, ArrayStorageError> {
+        self.mark_changed();
+        let entity_hash = HashValue::new(&self.build_hasher, entity.index());
+        self.try_attach_hashed(entity, entity_hash, component)
+    }
+
+    fn try_attach_hashed(
+        &mut self,
+        entity: E,
+        entity_hash: HashValue,
+        component: T,
+    ) -> Result<Option<T>, ArrayStorageError> {
         enum AttachOperation<'a> {
             Replace { hash_index: &'a mut HashIndex },
             TakeFromRich { start_index: usize },
@@ -518,11 +611,8 @@ where
 
         let probe_len = self.capacity().try_into().map_err(|_| ArrayStorageError)?;
         let Self {
-            buckets,
-            indices,
-            build_hasher,
+            buckets, indices, ..
         } = self;
-        let entity_hash = HashValue::new(build_hasher, entity.index());
         let desired_index = entity_hash
             .desired_index(probe_len)
             .try_into()
@@ -532,10 +622,10 @@ where
         let mut distances = 0..;
         let operation = 'outer: loop {
             let zipped = iter::zip(
-                distances.by_ref(),
                 indices.iter_mut().enumerate().skip(skip),
+                distances.by_ref(),
             );
-            for (distance, (current, hash_index)) in zipped {
+            for ((current, hash_index), distance) in zipped {
                 let &mut HashIndex::Occupied { hash, index } = hash_index else {
                     break 'outer AttachOperation::Replace { hash_index };
                 };
@@ -579,9 +669,10 @@ where
                     return Ok(None);
                 }
                 &mut HashIndex::Occupied { index, .. } => {
-                    let Bucket { value, .. } = buckets
+                    let Bucket { key, value, .. } = buckets
                         .get_mut(index)
                         .expect("index should point to the valid bucket");
+                    *key = entity;
                     let component = mem::replace(value, component);
                     return Ok(Some(component));
                 }
@@ -589,31 +680,83 @@ where
             AttachOperation::TakeFromRich { start_index } => start_index,
         };
 
+        let bucket = Bucket {
+            hash: entity_hash,
+            key: entity,
+            value: component,
+        };
+        if buckets.try_push(bucket).is_err() {
+            return Err(ArrayStorageError);
+        }
         let mut hash_index = HashIndex::Occupied {
             hash: entity_hash,
-            index: buckets.len(),
+            index: buckets.len() - 1,
         };
         skip = start_index;
         loop {
             for next_hash_index in indices.iter_mut().skip(skip) {
-                if let &mut HashIndex::Free = next_hash_index {
+                if let &mut HashIndex::Occupied { .. } = next_hash_index {
                     hash_index = mem::replace(next_hash_index, hash_index);
                     continue;
                 }
-                let bucket = Bucket {
-                    hash: entity_hash,
-                    key: entity,
-                    value: component,
-                };
-                if buckets.try_push(bucket).is_err() {
-                    return Err(ArrayStorageError);
-                }
                 *next_hash_index = hash_index;
                 return Ok(None);
             }
             skip = 0;
         }
     }
+
+    /// Checks if the storage has enough capacity to attach components to all provided entities.
+    ///
+    /// Components of provided entities which replace existing components
+    /// do not require additional capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::hash::BuildHasherDefault;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, BuildHasherDefault<DefaultHasher>, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// for i in 0..9 {
+    ///     let entity = DefaultEntity::new(i + 10, 0);
+    ///     storage.attach(entity, Position { x: 10.0, y: 10.0 });
+    /// }
+    ///
+    /// let entities = [DefaultEntity::new(10, 1), DefaultEntity::new(36, 0)];
+    /// assert!(storage.can_attach_all(entities));
+    ///
+    /// let entities = [DefaultEntity::new(36, 0), DefaultEntity::new(37, 0)];
+    /// assert!(!storage.can_attach_all(entities));
+    /// ```
+    pub fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let additional = entities
+            .into_iter()
+            .filter(|&entity| {
+                let entity_hash = HashValue::new(&self.build_hasher, entity.index());
+                let find_bucket = self.probe_bucket(entity_hash, |key| {
+                    let ordering = entity.generation().partial_cmp(&key.generation());
+                    entity.index() == key.index() && ordering != Some(Ordering::Less)
+                });
+                find_bucket.is_none()
+            })
+            .count();
+        self.len()
+            .checked_add(additional)
+            .is_some_and(|len| len <= self.capacity())
+    }
 }
 
 impl<T, E, S, const N: usize> HashArrayStorage<T, S, N, E>
@@ -624,24 +767,33 @@ where
     S: BuildHasher,
 {
     fn find_bucket(&self, entity: E) -> Option<FindBucket> {
+        let entity_hash = HashValue::new(&self.build_hasher, entity.index());
+        self.find_hashed_bucket(entity, entity_hash)
+    }
+
+    fn find_hashed_bucket(&self, entity: E, entity_hash: HashValue) -> Option<FindBucket> {
+        self.probe_bucket(entity_hash, |key| key == entity)
+    }
+
+    fn probe_bucket<F>(&self, entity_hash: HashValue, mut predicate: F) -> Option<FindBucket>
+    where
+        F: FnMut(E) -> bool,
+    {
         let Self {
-            buckets,
-            indices,
-            build_hasher,
+            buckets, indices, ..
         } = self;
 
         if buckets.is_empty() {
             return None;
         }
-        let entity_hash = HashValue::new(build_hasher, entity.index());
         let probe_len = self.capacity().try_into().ok()?;
         let desired_index = entity_hash.desired_index(probe_len).try_into().ok()?;
 
         let mut skip = desired_index;
         let mut distances = 0..;
         'outer: loop {
-            let zipped = iter::zip(distances.by_ref(), indices.iter().enumerate().skip(skip));
-            for (distance, (current, hash_index)) in zipped {
+            let zipped = iter::zip(indices.iter().enumerate().skip(skip), distances.by_ref());
+            for ((current, hash_index), distance) in zipped {
                 let &HashIndex::Occupied { hash, index } = hash_index else {
                     continue;
                 };
@@ -655,7 +807,7 @@ where
                 let &Bucket { key, .. } = buckets
                     .get(index)
                     .expect("index should point to the valid bucket");
-                if entity != key {
+                if !predicate(key) {
                     continue;
                 }
                 let find_bucket = FindBucket {
@@ -763,6 +915,7 @@ where
     /// assert_eq!(storage.get_mut(entity), None);
     /// ```
     pub fn get_mut(&mut self, entity: E) -> Option<&mut T> {
+        self.mark_changed();
         let FindBucket { bucket_index, .. } = self.find_bucket(entity)?;
         let Bucket { value, .. } = self
             .buckets
@@ -800,10 +953,16 @@ where
     /// assert_eq!(component, Some(Position { x: 0.0, y: -10.0 }));
     /// ```
     pub fn remove(&mut self, entity: E) -> Option<T> {
+        self.mark_changed();
+        let find_bucket = self.find_bucket(entity)?;
+        self.remove_bucket(find_bucket)
+    }
+
+    fn remove_bucket(&mut self, find_bucket: FindBucket) -> Option<T> {
         let FindBucket {
             hash_index,
             bucket_index,
-        } = self.find_bucket(entity)?;
+        } = find_bucket;
 
         {
             let hash_index = self
@@ -864,96 +1023,583 @@ where
     }
 }
 
-impl<T, E, S, const N: usize> Storage for HashArrayStorage<T, S, N, E>
+impl<T, E, S, const N: usize> HashArrayStorage<T, S, N, E>
 where
     T: Component<Storage = Self>,
     E: Entity + PartialEq,
     E::Index: Hash + PartialEq,
     E::Generation: PartialOrd,
-    S: BuildHasher + 'static,
+    S: BuildHasher,
 {
-    type Item = T;
-    type Entity = E;
-
-    fn attach(&mut self, entity: Self::Entity, component: Self::Item) -> Option<Self::Item> {
-        HashArrayStorage::attach(self, entity, component)
-    }
-
-    fn is_attached(&self, entity: Self::Entity) -> bool {
-        HashArrayStorage::is_attached(self, entity)
-    }
-
-    fn get(&self, entity: Self::Entity) -> Option<&Self::Item> {
-        HashArrayStorage::get(self, entity)
-    }
-
-    fn get_mut(&mut self, entity: Self::Entity) -> Option<&mut Self::Item> {
-        HashArrayStorage::get_mut(self, entity)
-    }
-
-    fn remove(&mut self, entity: Self::Entity) -> Option<Self::Item> {
-        HashArrayStorage::remove(self, entity)
-    }
-
-    fn clear(&mut self) {
-        HashArrayStorage::clear(self)
-    }
-
-    fn len(&self) -> usize {
-        HashArrayStorage::len(self)
+    /// Retrieves a reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// let error = storage.get_checked(DefaultEntity::new(9, 0)).unwrap_err();
+    /// assert_eq!(error.stored_generation(), 1);
+    /// assert_eq!(storage.get_checked(DefaultEntity::new(9, 2)).unwrap(), None);
+    /// ```
+    pub fn get_checked(&self, entity: E) -> Result<Option<&T>, StaleEntityError<E>> {
+        let Some(FindBucket { bucket_index, .. }) = self.find_checked_bucket(entity)? else {
+            return Ok(None);
+        };
+        let Bucket { value, .. } = self
+            .buckets
+            .get(bucket_index)
+            .expect("index should point to the valid bucket");
+        Ok(Some(value))
     }
 
-    fn is_empty(&self) -> bool {
-        HashArrayStorage::is_empty(self)
+    /// Retrieves a mutable reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.get_mut_checked(DefaultEntity::new(9, 0)).is_err());
+    /// ```
+    pub fn get_mut_checked(&mut self, entity: E) -> Result<Option<&mut T>, StaleEntityError<E>> {
+        let Some(FindBucket { bucket_index, .. }) = self.find_checked_bucket(entity)? else {
+            return Ok(None);
+        };
+        let Bucket { value, .. } = self
+            .buckets
+            .get_mut(bucket_index)
+            .expect("index should point to the valid bucket");
+        Ok(Some(value))
     }
 
-    type Iter<'me>
-        = Iter<'me, Self::Item, S, N, Self::Entity>
-    where
-        Self: 'me;
-
-    fn iter(&self) -> Self::Iter<'_> {
-        HashArrayStorage::iter(self)
+    /// Removes component from provided entity.
+    /// Returns previous component data, or [`None`] if there was no component attached to the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale,
+    /// i.e. the storage has a component attached to the newer entity with the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(9, 1), Position { x: 1.0, y: -1.0 });
+    ///
+    /// assert!(storage.remove_checked(DefaultEntity::new(9, 0)).is_err());
+    /// assert!(storage.is_attached(DefaultEntity::new(9, 1)));
+    /// ```
+    pub fn remove_checked(&mut self, entity: E) -> Result<Option<T>, StaleEntityError<E>> {
+        let Some(find_bucket) = self.find_checked_bucket(entity)? else {
+            return Ok(None);
+        };
+        Ok(self.remove_bucket(find_bucket))
     }
 
-    type IterMut<'me>
-        = IterMut<'me, Self::Item, S, N, Self::Entity>
-    where
-        Self: 'me;
-
-    fn iter_mut(&mut self) -> Self::IterMut<'_> {
-        HashArrayStorage::iter_mut(self)
+    fn find_checked_bucket(&self, entity: E) -> Result<Option<FindBucket>, StaleEntityError<E>> {
+        let entity_hash = HashValue::new(&self.build_hasher, entity.index());
+        let predicate =
+            |key: E| key.index() == entity.index() && key.generation() >= entity.generation();
+        let Some(find_bucket) = self.probe_bucket(entity_hash, predicate) else {
+            return Ok(None);
+        };
+        let &Bucket { key, .. } = self
+            .buckets
+            .get(find_bucket.bucket_index)
+            .expect("index should point to the valid bucket");
+        if key != entity {
+            return Err(StaleEntityError::new(entity, key));
+        }
+        Ok(Some(find_bucket))
     }
 }
 
-impl<T, E, S, const N: usize> TryStorage for HashArrayStorage<T, S, N, E>
+impl<T, E, S, const N: usize> HashArrayStorage<T, S, N, E>
 where
     T: Component<Storage = Self>,
     E: Entity + PartialEq,
     E::Index: Hash + PartialEq,
     E::Generation: PartialOrd,
-    S: BuildHasher + 'static,
-{
-    type Err = ArrayStorageError;
-
-    fn try_attach(
-        &mut self,
-        entity: Self::Entity,
-        component: Self::Item,
-    ) -> Result<Option<Self::Item>, Self::Err> {
-        HashArrayStorage::try_attach(self, entity, component)
-    }
-}
-
-impl<'me, T, E, S, const N: usize> IntoIterator for &'me HashArrayStorage<T, S, N, E>
-where
-    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
-    E: Entity,
+    S: BuildHasher,
 {
-    type Item = (E, &'me T);
-
-    type IntoIter = Iter<'me, T, S, N, E>;
-
+    /// Retains only the components specified by the predicate.
+    ///
+    /// In other words, removes all components for which `f(entity, &mut component)` returns `false`.
+    /// This method visits each component exactly once, but the order of visiting is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// storage.attach(DefaultEntity::new(1, 0), Position { x: 0.0, y: -10.0 });
+    /// storage.attach(DefaultEntity::new(7, 15), Position { x: 10.0, y: 0.0 });
+    /// storage.attach(DefaultEntity::new(9, 10), Position { x: 1.0, y: 23.0 });
+    ///
+    /// storage.retain(|_, position| position.x > 0.0);
+    /// assert_eq!(storage.len(), 2);
+    /// assert!(!storage.is_attached(DefaultEntity::new(1, 0)));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(E, &mut T) -> bool,
+    {
+        self.mark_changed();
+        let mut bucket_index = 0;
+        while let Some(bucket) = self.buckets.get_mut(bucket_index) {
+            let &mut Bucket {
+                key, ref mut value, ..
+            } = bucket;
+            if f(key, value) {
+                bucket_index += 1;
+                continue;
+            }
+            let _ = self.remove(key);
+        }
+    }
+
+    /// Exchanges components attached to provided entities.
+    ///
+    /// If only one of provided entities has a component attached,
+    /// the component is moved to another entity.
+    /// Does nothing if neither of entities has a component attached.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the component should be moved to another entity,
+    /// but the storage is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// let first = DefaultEntity::new(1, 0);
+    /// let second = DefaultEntity::new(7, 15);
+    /// storage.attach(first, Position { x: 0.0, y: -10.0 });
+    /// storage.attach(second, Position { x: 10.0, y: 0.0 });
+    ///
+    /// storage.swap(first, second);
+    /// assert_eq!(storage.get(first), Some(&Position { x: 10.0, y: 0.0 }));
+    /// assert_eq!(storage.get(second), Some(&Position { x: 0.0, y: -10.0 }));
+    /// ```
+    #[track_caller]
+    pub fn swap(&mut self, a: E, b: E) {
+        self.mark_changed();
+        if let (Some(a_bucket), Some(b_bucket)) = (self.find_bucket(a), self.find_bucket(b)) {
+            let FindBucket {
+                bucket_index: a_index,
+                ..
+            } = a_bucket;
+            let FindBucket {
+                bucket_index: b_index,
+                ..
+            } = b_bucket;
+            if a_index == b_index {
+                return;
+            }
+            let Ok([a_bucket, b_bucket]) = self.buckets.get_disjoint_mut([a_index, b_index]) else {
+                unreachable!("bucket indices should be valid and distinct");
+            };
+            mem::swap(&mut a_bucket.value, &mut b_bucket.value);
+            return;
+        }
+
+        let a_component = self.remove(a);
+        let b_component = self.remove(b);
+        if let Some(component) = a_component {
+            self.attach(b, component);
+        }
+        if let Some(component) = b_component {
+            self.attach(a, component);
+        }
+    }
+}
+
+impl<T, E, S, const N: usize> HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher + 'static,
+{
+    /// Retrieves an entry of provided entity in the hash array storage for in-place manipulation.
+    ///
+    /// Hash of the entity is computed only once,
+    /// so inserting into the vacant entry does not hash the entity again.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if provided entity has no component attached to it,
+    /// but the count of components attached to some entities is the same as the capacity of the storage.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_entry`][Self::try_entry()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// let entity = DefaultEntity::new(127, 0);
+    ///
+    /// storage.entry(entity).or_insert(Position { x: 0.0, y: 0.0 }).x += 1.0;
+    /// storage
+    ///     .entry(entity)
+    ///     .and_modify(|position| position.y -= 1.0)
+    ///     .or_insert(Position { x: 10.0, y: 10.0 });
+    /// assert_eq!(storage.get(entity), Some(&Position { x: 1.0, y: -1.0 }));
+    /// ```
+    #[track_caller]
+    pub fn entry(&mut self, entity: E) -> StorageEntry<'_, Self> {
+        match self.try_entry(entity) {
+            Ok(entry) => entry,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Tries to retrieve an entry of provided entity in the hash array storage for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity has no component attached to it,
+    /// but the count of components attached to some entities is the same as the capacity of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::hash_map::RandomState;
+    /// # use stream_ecs::component::Component;
+    /// use stream_ecs::{component::storage::array::HashArrayStorage, entity::DefaultEntity};
+    /// # #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    /// # #[component(storage = HashArrayStorage<Self, RandomState, 10>)]
+    /// # #[component(crate = stream_ecs)]
+    /// # struct Position {
+    /// #     x: f32,
+    /// #     y: f32,
+    /// # }
+    ///
+    /// let mut storage = HashArrayStorage::new();
+    /// for i in 0..10 {
+    ///     let entity = DefaultEntity::new(i + 10, 0);
+    ///     storage.attach(entity, Position { x: 10.0, y: 10.0 });
+    /// }
+    ///
+    /// let entity = DefaultEntity::new(36, 0);
+    /// let result = storage.try_entry(entity);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// This is the fallible version of [`entry`][Self::entry()] method.
+    pub fn try_entry(&mut self, entity: E) -> Result<StorageEntry<'_, Self>, ArrayStorageError> {
+        self.mark_changed();
+        let entity_hash = HashValue::new(&self.build_hasher, entity.index());
+        let entry = match self.find_hashed_bucket(entity, entity_hash) {
+            Some(find_bucket) => {
+                let storage = self;
+                StorageEntry::Occupied(OccupiedEntry {
+                    entity,
+                    find_bucket,
+                    storage,
+                })
+            }
+            None if self.buckets.is_full() => return Err(ArrayStorageError),
+            None => {
+                let storage = self;
+                StorageEntry::Vacant(VacantEntry {
+                    entity,
+                    entity_hash,
+                    storage,
+                })
+            }
+        };
+        Ok(entry)
+    }
+}
+
+impl<T, E, S, const N: usize> Storage for HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher + 'static,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn attach(&mut self, entity: Self::Entity, component: Self::Item) -> Option<Self::Item> {
+        HashArrayStorage::attach(self, entity, component)
+    }
+
+    fn is_attached(&self, entity: Self::Entity) -> bool {
+        HashArrayStorage::is_attached(self, entity)
+    }
+
+    fn get(&self, entity: Self::Entity) -> Option<&Self::Item> {
+        HashArrayStorage::get(self, entity)
+    }
+
+    fn get_mut(&mut self, entity: Self::Entity) -> Option<&mut Self::Item> {
+        HashArrayStorage::get_mut(self, entity)
+    }
+
+    fn remove(&mut self, entity: Self::Entity) -> Option<Self::Item> {
+        HashArrayStorage::remove(self, entity)
+    }
+
+    fn clear(&mut self) {
+        HashArrayStorage::clear(self)
+    }
+
+    fn len(&self) -> usize {
+        HashArrayStorage::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        HashArrayStorage::is_empty(self)
+    }
+
+    fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        HashArrayStorage::can_attach_all(self, entities)
+    }
+
+    type Iter<'me>
+        = Iter<'me, Self::Item, S, N, Self::Entity>
+    where
+        Self: 'me;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        HashArrayStorage::iter(self)
+    }
+
+    type IterMut<'me>
+        = IterMut<'me, Self::Item, S, N, Self::Entity>
+    where
+        Self: 'me;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        HashArrayStorage::iter_mut(self)
+    }
+
+    fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(Self::Entity, &mut Self::Item) -> bool,
+    {
+        HashArrayStorage::retain(self, f)
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = (Self::Entity, Self::Item)> {
+        HashArrayStorage::drain(self)
+    }
+
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Self::Entity, Self::Item)>,
+    {
+        Extend::extend(self, iter)
+    }
+
+    fn swap(&mut self, a: Self::Entity, b: Self::Entity) {
+        HashArrayStorage::swap(self, a, b)
+    }
+
+    type OccupiedEntry<'me>
+        = OccupiedEntry<'me, Self::Item, S, N, Self::Entity>
+    where
+        Self: 'me;
+
+    type VacantEntry<'me>
+        = VacantEntry<'me, Self::Item, S, N, Self::Entity>
+    where
+        Self: 'me;
+
+    #[track_caller]
+    fn entry(&mut self, entity: Self::Entity) -> StorageEntry<'_, Self> {
+        HashArrayStorage::entry(self, entity)
+    }
+}
+
+impl<T, E, S, const N: usize> TryStorage for HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher + 'static,
+{
+    type Err = ArrayStorageError;
+
+    fn try_attach(
+        &mut self,
+        entity: Self::Entity,
+        component: Self::Item,
+    ) -> Result<Option<Self::Item>, Self::Err> {
+        HashArrayStorage::try_attach(self, entity, component)
+    }
+
+    fn try_entry(&mut self, entity: Self::Entity) -> Result<StorageEntry<'_, Self>, Self::Err> {
+        HashArrayStorage::try_entry(self, entity)
+    }
+}
+
+impl<T, E, S, const N: usize> CheckedStorage for HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher + 'static,
+{
+    fn get_checked(
+        &self,
+        entity: Self::Entity,
+    ) -> Result<Option<&Self::Item>, StaleEntityError<Self::Entity>> {
+        HashArrayStorage::get_checked(self, entity)
+    }
+
+    fn get_mut_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<&mut Self::Item>, StaleEntityError<Self::Entity>> {
+        HashArrayStorage::get_mut_checked(self, entity)
+    }
+
+    fn remove_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<Self::Item>, StaleEntityError<Self::Entity>> {
+        HashArrayStorage::remove_checked(self, entity)
+    }
+}
+
+impl<T, E, S, const N: usize> TrackedStorage for HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher + 'static,
+{
+    fn change_tick(&self) -> ChangeTick {
+        HashArrayStorage::change_tick(self)
+    }
+
+    fn set_change_tick(&mut self, tick: ChangeTick) {
+        self.change_tick = tick;
+    }
+}
+
+impl<T, E, S, const N: usize> Extend<(E, T)> for HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = Self>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher,
+{
+    #[track_caller]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (E, T)>,
+    {
+        for (entity, component) in iter {
+            let _ = self.attach(entity, component);
+        }
+    }
+}
+
+impl<'me, T, E, S, const N: usize> IntoIterator for &'me HashArrayStorage<T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    type Item = (E, &'me T);
+
+    type IntoIter = Iter<'me, T, S, N, E>;
+
     fn into_iter(self) -> Self::IntoIter {
         let iter = self.buckets.iter();
         Iter { iter }
@@ -970,6 +1616,7 @@ where
     type IntoIter = IterMut<'me, T, S, N, E>;
 
     fn into_iter(self) -> Self::IntoIter {
+        self.mark_changed();
         let iter = self.buckets.iter_mut();
         IterMut { iter }
     }
@@ -1161,12 +1808,199 @@ where
 {
 }
 
+/// Draining iterator of entities with components attached to them in the hash array storage.
+///
+/// This struct is created by [`drain`][HashArrayStorage::drain()] method of the hash array storage.
+pub struct Drain<'data, T, S, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    iter: arrayvec::Drain<'data, Bucket<E, T>, N>,
+}
+
+impl<T, E, S, const N: usize> Iterator for Drain<'_, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    type Item = (E, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Bucket { key, value, .. } = self.iter.next()?;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, E, S, const N: usize> DoubleEndedIterator for Drain<'_, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Bucket { key, value, .. } = self.iter.next_back()?;
+        Some((key, value))
+    }
+}
+
+impl<T, E, S, const N: usize> ExactSizeIterator for Drain<'_, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<T, E, S, const N: usize> FusedIterator for Drain<'_, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+}
+
+/// View into an occupied entry of the hash array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][HashArrayStorage::entry()] method of the hash array storage.
+pub struct OccupiedEntry<'data, T, S, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    find_bucket: FindBucket,
+    storage: &'data mut HashArrayStorage<T, S, N, E>,
+}
+
+impl<'data, T, E, S, const N: usize> OccupiedStorageEntry<'data>
+    for OccupiedEntry<'data, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn get(&self) -> &Self::Item {
+        let FindBucket { bucket_index, .. } = self.find_bucket;
+        let Bucket { value, .. } = self
+            .storage
+            .buckets
+            .get(bucket_index)
+            .expect("index should point to the valid bucket");
+        value
+    }
+
+    fn get_mut(&mut self) -> &mut Self::Item {
+        let FindBucket { bucket_index, .. } = self.find_bucket;
+        let Bucket { value, .. } = self
+            .storage
+            .buckets
+            .get_mut(bucket_index)
+            .expect("index should point to the valid bucket");
+        value
+    }
+
+    fn into_mut(self) -> &'data mut Self::Item {
+        let FindBucket { bucket_index, .. } = self.find_bucket;
+        let Bucket { value, .. } = self
+            .storage
+            .buckets
+            .get_mut(bucket_index)
+            .expect("index should point to the valid bucket");
+        value
+    }
+
+    fn insert(&mut self, component: Self::Item) -> Self::Item {
+        mem::replace(self.get_mut(), component)
+    }
+
+    fn remove(self) -> Self::Item {
+        let Self {
+            find_bucket,
+            storage,
+            ..
+        } = self;
+        storage
+            .remove_bucket(find_bucket)
+            .expect("bucket of occupied entry should be removed")
+    }
+}
+
+/// View into a vacant entry of the hash array storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// This struct is created by [`entry`][HashArrayStorage::entry()] method of the hash array storage.
+pub struct VacantEntry<'data, T, S, const N: usize, E = DefaultEntity>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity,
+{
+    entity: E,
+    entity_hash: HashValue,
+    storage: &'data mut HashArrayStorage<T, S, N, E>,
+}
+
+impl<'data, T, E, S, const N: usize> VacantStorageEntry<'data> for VacantEntry<'data, T, S, N, E>
+where
+    T: Component<Storage = HashArrayStorage<T, S, N, E>>,
+    E: Entity + PartialEq,
+    E::Index: Hash + PartialEq,
+    E::Generation: PartialOrd,
+    S: BuildHasher,
+{
+    type Item = T;
+    type Entity = E;
+
+    fn entity(&self) -> Self::Entity {
+        self.entity
+    }
+
+    fn insert(self, component: Self::Item) -> &'data mut Self::Item {
+        let Self {
+            entity,
+            entity_hash,
+            storage,
+        } = self;
+        let _ = storage
+            .try_attach_hashed(entity, entity_hash, component)
+            .expect("vacant entry should have free space in the storage");
+        let FindBucket { bucket_index, .. } = storage
+            .find_hashed_bucket(entity, entity_hash)
+            .expect("component should be attached to the entity");
+        let Bucket { value, .. } = storage
+            .buckets
+            .get_mut(bucket_index)
+            .expect("index should point to the valid bucket");
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::hash::BuildHasherDefault;
+    use core::hash::{BuildHasherDefault, Hasher};
     use std::collections::hash_map::DefaultHasher;
 
-    use crate::{component::Component, entity::DefaultEntity as Entity};
+    use crate::{
+        component::{
+            Component,
+            storage::{OccupiedEntry, StorageEntry, VacantEntry},
+        },
+        entity::DefaultEntity as Entity,
+    };
 
     type HashArrayStorage<T, const N: usize> =
         super::HashArrayStorage<T, BuildHasherDefault<DefaultHasher>, N>;
@@ -1232,6 +2066,52 @@ mod tests {
         assert!(storage.is_attached(new_entity));
     }
 
+    #[derive(Default)]
+    struct IdentityHasher(u64);
+
+    impl Hasher for IdentityHasher {
+        fn finish(&self) -> u64 {
+            let Self(hash) = *self;
+            hash
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            let Self(hash) = self;
+            for &byte in bytes.iter().rev() {
+                *hash = (*hash << 8) | u64::from(byte);
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Index(u32);
+
+    impl Component for Index {
+        type Storage = super::HashArrayStorage<Self, BuildHasherDefault<IdentityHasher>, 4>;
+    }
+
+    #[test]
+    fn collisions() {
+        let mut storage = super::HashArrayStorage::new();
+        // indices 3, 7 and 11 share the last slot, so probing wraps around,
+        // and attaching 7 displaces 0 from the first slot
+        let indices = [3, 0, 7, 11];
+        for index in indices {
+            let _ = storage.attach(Entity::new(index, 0), Index(index));
+        }
+        for index in indices {
+            assert_eq!(storage.get(Entity::new(index, 0)), Some(&Index(index)));
+        }
+
+        let _ = storage.remove(Entity::new(3, 0));
+        let _ = storage.remove(Entity::new(7, 0));
+        assert_eq!(storage.len(), 2);
+        assert!(!storage.is_attached(Entity::new(3, 0)));
+        assert!(!storage.is_attached(Entity::new(7, 0)));
+        assert_eq!(storage.get(Entity::new(0, 0)), Some(&Index(0)));
+        assert_eq!(storage.get(Entity::new(11, 0)), Some(&Index(11)));
+    }
+
     #[test]
     #[should_panic]
     fn too_many() {
@@ -1275,4 +2155,97 @@ mod tests {
         let entity = iter.find(|(entity, _)| entity.index() == 2);
         assert!(entity.is_none());
     }
+
+    #[test]
+    fn retain() {
+        let mut storage = HashArrayStorage::new();
+        for index in 0..5 {
+            let _ = storage.attach(Entity::new(index, 0), Marker);
+        }
+
+        let mut calls = 0;
+        crate::component::storage::Storage::retain(&mut storage, |entity, _| {
+            calls += 1;
+            entity.index() % 2 == 0
+        });
+        assert_eq!(calls, 5);
+        assert_eq!(storage.len(), 3);
+        assert!(!storage.is_attached(Entity::new(1, 0)));
+        assert!(storage.is_attached(Entity::new(2, 0)));
+        assert!(storage.is_attached(Entity::new(4, 0)));
+    }
+
+    #[test]
+    fn drain() {
+        let mut storage = HashArrayStorage::new();
+        storage.extend((0..5).map(|index| (Entity::new(index, 0), Marker)));
+
+        let mut drain = storage.drain();
+        assert_eq!(drain.len(), 5);
+        let _ = drain.next();
+        drop(drain);
+        assert!(storage.is_empty());
+        assert!(!storage.is_attached(Entity::new(4, 0)));
+    }
+
+    #[test]
+    fn swap() {
+        let mut storage = HashArrayStorage::new();
+        let first = Entity::new(1, 0);
+        let second = Entity::new(3, 2);
+
+        let _ = storage.attach(first, Marker);
+        storage.swap(first, second);
+        assert!(!storage.is_attached(first));
+        assert!(storage.is_attached(second));
+
+        storage.swap(first, second);
+        assert!(storage.is_attached(first));
+        assert!(!storage.is_attached(second));
+    }
+
+    #[test]
+    fn entry() {
+        let mut storage = HashArrayStorage::new();
+        let entity = Entity::new(1, 0);
+        let _ = storage.attach(Entity::new(0, 0), Marker);
+
+        let _ = storage.entry(entity).or_insert(Marker);
+        assert!(storage.is_attached(entity));
+        assert_eq!(storage.len(), 2);
+
+        let StorageEntry::Occupied(entry) = storage.entry(entity) else {
+            panic!("entry should be occupied");
+        };
+        let _ = entry.remove();
+        assert!(!storage.is_attached(entity));
+        assert_eq!(storage.len(), 1);
+
+        let _ = storage.attach(entity, Marker);
+        let newer = Entity::new(1, 1);
+        let StorageEntry::Vacant(entry) = storage.entry(newer) else {
+            panic!("entry should be vacant");
+        };
+        let _ = entry.insert(Marker);
+        assert!(storage.is_attached(newer));
+        assert!(!storage.is_attached(entity));
+        assert_eq!(storage.len(), 2);
+    }
+
+    #[test]
+    fn checked() {
+        let mut storage = HashArrayStorage::new();
+        let _ = storage.attach(Entity::new(1, 1), Marker);
+
+        let error = storage.get_checked(Entity::new(1, 0)).unwrap_err();
+        assert_eq!(error.requested_generation(), 0);
+        assert_eq!(error.stored_generation(), 1);
+        assert!(storage.get_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 2)).unwrap().is_none());
+        assert!(storage.get_checked(Entity::new(2, 0)).unwrap().is_none());
+
+        assert!(storage.remove_checked(Entity::new(1, 0)).is_err());
+        assert!(storage.remove_checked(Entity::new(1, 1)).unwrap().is_some());
+        assert!(storage.get_checked(Entity::new(1, 0)).unwrap().is_none());
+    }
 }
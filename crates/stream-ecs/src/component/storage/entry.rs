@@ -0,0 +1,222 @@
+//! Entry API of the component storage.
+
+use crate::{component::Component, entity::Entity};
+
+use super::Storage;
+
+/// View into a single slot of the storage, which may either be occupied or vacant.
+///
+/// This enum is constructed from the [`entry`][Storage::entry()] method of the storage.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub enum StorageEntry<'me, S>
+where
+    S: Storage + ?Sized,
+{
+    /// Provided entity has a component attached to it.
+    Occupied(S::OccupiedEntry<'me>),
+    /// Provided entity has no component attached to it.
+    Vacant(S::VacantEntry<'me>),
+}
+
+impl<'me, S> StorageEntry<'me, S>
+where
+    S: Storage + ?Sized,
+{
+    /// Returns the entity of this entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn entity(&self) -> S::Entity {
+        match self {
+            Self::Occupied(entry) => entry.entity(),
+            Self::Vacant(entry) => entry.entity(),
+        }
+    }
+
+    /// Ensures a component is attached to the entity by inserting provided component if vacant,
+    /// and returns a mutable reference to the component in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn or_insert(self, default: S::Item) -> &'me mut S::Item {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a component is attached to the entity by inserting the result of provided function if vacant,
+    /// and returns a mutable reference to the component in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn or_insert_with<F>(self, default: F) -> &'me mut S::Item
+    where
+        F: FnOnce() -> S::Item,
+    {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts into the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut S::Item),
+    {
+        match self {
+            Self::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Self::Occupied(entry)
+            }
+            Self::Vacant(entry) => Self::Vacant(entry),
+        }
+    }
+
+    /// Sets the component of the entry, and returns a mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn insert(self, component: S::Item) -> &'me mut S::Item {
+        match self {
+            Self::Occupied(mut entry) => {
+                let _ = entry.insert(component);
+                entry.into_mut()
+            }
+            Self::Vacant(entry) => entry.insert(component),
+        }
+    }
+}
+
+/// View into an occupied entry of the storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait OccupiedEntry<'me>: Sized {
+    /// Type of component which is stored in the storage.
+    type Item: Component;
+
+    /// Type of entity which is used to track stored components.
+    type Entity: Entity;
+
+    /// Returns the entity of this entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn entity(&self) -> Self::Entity;
+
+    /// Retrieves a reference to the component in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn get(&self) -> &Self::Item;
+
+    /// Retrieves a mutable reference to the component in the entry.
+    ///
+    /// If you need a reference which may outlive the destruction of the entry,
+    /// see [`into_mut`][OccupiedEntry::into_mut()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn get_mut(&mut self) -> &mut Self::Item;
+
+    /// Converts the entry into a mutable reference to the component in the entry
+    /// with a lifetime bound to the storage itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn into_mut(self) -> &'me mut Self::Item;
+
+    /// Sets the component of the entry, and returns the old component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn insert(&mut self, component: Self::Item) -> Self::Item;
+
+    /// Removes the component from the storage, and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn remove(self) -> Self::Item;
+}
+
+/// View into a vacant entry of the storage.
+/// It is a part of the [`StorageEntry`] enum.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait VacantEntry<'me>: Sized {
+    /// Type of component which is stored in the storage.
+    type Item: Component;
+
+    /// Type of entity which is used to track stored components.
+    type Entity: Entity;
+
+    /// Returns the entity of this entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn entity(&self) -> Self::Entity;
+
+    /// Attaches provided component to the entity of the entry,
+    /// and returns a mutable reference to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn insert(self, component: Self::Item) -> &'me mut Self::Item;
+}
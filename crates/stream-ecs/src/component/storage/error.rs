@@ -1,8 +1,17 @@
-use core::any::{Any, TypeId, type_name};
+use core::{
+    any::{Any, TypeId, type_name},
+    fmt::Display,
+};
 
 use derive_more::{Display, From};
 
-use crate::{component::Component, entity::Entity, utils::type_name::TypeName};
+use crate::{
+    component::Component,
+    entity::{DefaultEntity, Entity},
+    utils::type_name::TypeName,
+};
+
+use super::ErasedStorage;
 
 /// The error type which is returned when type of component or entity was mismatched
 /// when trying to attach component to the entity with erased storage.
@@ -178,6 +187,146 @@ impl EntityMismatchError {
     }
 }
 
+/// The error type which is returned when storage of some component type
+/// does not have enough capacity to attach components to all the entities.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(r#"storage of component type "{type_name}" does not have enough capacity"#)]
+pub struct StorageCapacityError {
+    type_name: &'static str,
+    type_id: TypeId,
+}
+
+impl StorageCapacityError {
+    /// Creates new error when storage of provided component type does not have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new<T>() -> Self
+    where
+        T: Component,
+    {
+        Self {
+            type_name: type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// Creates new error when provided erased storage does not have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn from_erased(storage: &dyn ErasedStorage) -> Self {
+        Self {
+            type_name: storage.component_type_name(),
+            type_id: storage.component_type_id(),
+        }
+    }
+
+    /// Returns [`TypeId`] of component which storage does not have enough capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_id(self) -> TypeId {
+        self.type_id
+    }
+}
+
+/// The error type which is returned when provided entity is stale,
+/// i.e. the storage has a component attached to the newer entity with the same index.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(bound(E: Display))]
+#[display(
+    "entity {entity} is stale: storage has a component attached to the newer entity {stored}"
+)]
+pub struct StaleEntityError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    entity: E,
+    stored: E,
+}
+
+impl<E> StaleEntityError<E>
+where
+    E: Entity,
+{
+    /// Creates new error when provided entity is older than the entity stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(entity: E, stored: E) -> Self {
+        Self { entity, stored }
+    }
+
+    /// Returns the stale entity which was requested from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn entity(self) -> E {
+        self.entity
+    }
+
+    /// Returns the entity which component is actually stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn stored(self) -> E {
+        self.stored
+    }
+
+    /// Returns the generation of the stale entity which was requested from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn requested_generation(self) -> E::Generation {
+        self.entity.generation()
+    }
+
+    /// Returns the generation of the entity which component is actually stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn stored_generation(self) -> E::Generation {
+        self.stored.generation()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct TypeMismatchError {
     provided_type_name: &'static str,
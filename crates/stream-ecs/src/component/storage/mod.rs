@@ -1,18 +1,27 @@
 //! Utilities for storages of components in ECS.
 
-use core::any::Any;
-
-pub use self::error::{AttachError, ComponentMismatchError, EntityMismatchError};
+use core::any::{Any, TypeId, type_name};
+
+pub use self::{
+    entry::{OccupiedEntry, StorageEntry, VacantEntry},
+    error::{
+        AttachError, ComponentMismatchError, EntityMismatchError, StaleEntityError,
+        StorageCapacityError,
+    },
+    tick::ChangeTick,
+};
 
 use crate::{
     component::{Component, ErasedComponent},
-    entity::{Entity, ErasedEntity},
+    entity::{Entity, ErasedEntity, map::EntityMapper},
 };
 
 pub mod array;
 pub mod bundle;
 
+mod entry;
 mod error;
+mod tick;
 
 /// Storage of some component type in ECS.
 ///
@@ -113,6 +122,24 @@ pub trait Storage: 'static {
         self.len() == 0
     }
 
+    /// Checks if the storage has enough capacity to attach components to all provided entities.
+    ///
+    /// Provided entities are expected to be distinct.
+    /// Storages are not bounded by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn can_attach_all<I>(&self, entities: I) -> bool
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        let _ = entities;
+        true
+    }
+
     /// Iterator which returns entity keys
     /// with references of components attached to them.
     type Iter<'me>: Iterator<Item = (Self::Entity, &'me Self::Item)>
@@ -144,6 +171,163 @@ pub trait Storage: 'static {
     /// todo!()
     /// ```
     fn iter_mut(&mut self) -> Self::IterMut<'_>;
+
+    /// Occupied entry of the storage which is returned by [`entry`][Storage::entry()] method.
+    type OccupiedEntry<'me>: OccupiedEntry<'me, Item = Self::Item, Entity = Self::Entity>
+    where
+        Self: 'me;
+
+    /// Vacant entry of the storage which is returned by [`entry`][Storage::entry()] method.
+    type VacantEntry<'me>: VacantEntry<'me, Item = Self::Item, Entity = Self::Entity>
+    where
+        Self: 'me;
+
+    /// Retrieves an entry of provided entity in the storage for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn entry(&mut self, entity: Self::Entity) -> StorageEntry<'_, Self>;
+
+    /// Retains only the components specified by the predicate.
+    ///
+    /// In other words, removes all components for which `f(entity, &mut component)` returns `false`.
+    ///
+    /// Default implementation searches for the next component to remove from the start of the storage,
+    /// so the predicate can be called more than once for retained components.
+    /// Implementations are encouraged to override this method with more efficient one,
+    /// as [array storages](mod@array) do by calling the predicate exactly once for each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Self::Entity, &mut Self::Item) -> bool,
+    {
+        loop {
+            let entity = self
+                .iter_mut()
+                .find_map(|(entity, item)| (!f(entity, item)).then_some(entity));
+            let Some(entity) = entity else {
+                break;
+            };
+            let _ = self.remove(entity);
+        }
+    }
+
+    /// Removes all components from the storage, returning them with their entity keys as an iterator.
+    ///
+    /// If the returned iterator is dropped before being fully consumed,
+    /// it removes the remaining components from the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn drain(&mut self) -> impl Iterator<Item = (Self::Entity, Self::Item)> {
+        Drain { storage: self }
+    }
+
+    /// Attaches all the components from the iterator to their entities.
+    ///
+    /// Previous component data of the entities, if any, is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (Self::Entity, Self::Item)>,
+    {
+        for (entity, component) in iter {
+            let _ = self.attach(entity, component);
+        }
+    }
+
+    /// Exchanges components attached to provided entities.
+    ///
+    /// If only one of provided entities has a component attached,
+    /// the component is moved to another entity.
+    /// Does nothing if neither of entities has a component attached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn swap(&mut self, a: Self::Entity, b: Self::Entity) {
+        let a_component = self.remove(a);
+        let b_component = self.remove(b);
+        if let Some(component) = a_component {
+            let _ = self.attach(b, component);
+        }
+        if let Some(component) = b_component {
+            let _ = self.attach(a, component);
+        }
+    }
+
+    /// Copies component attached to the `from` entity to the `to` entity.
+    /// Returns `false` if the `from` entity has no component attached,
+    /// leaving the `to` entity untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn clone_component(&mut self, from: Self::Entity, to: Self::Entity) -> bool {
+        let Some(&component) = self.get(from) else {
+            return false;
+        };
+        let _ = self.attach(to, component);
+        true
+    }
+}
+
+/// Default draining iterator of the storage which removes components one by one.
+struct Drain<'me, S>
+where
+    S: Storage + ?Sized,
+{
+    storage: &'me mut S,
+}
+
+impl<S> Iterator for Drain<'_, S>
+where
+    S: Storage + ?Sized,
+{
+    type Item = (S::Entity, S::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { storage } = self;
+        let (entity, _) = storage.iter().next()?;
+        let component = storage.remove(entity)?;
+        Some((entity, component))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { storage } = self;
+        let len = storage.len();
+        (len, Some(len))
+    }
+}
+
+impl<S> Drop for Drain<'_, S>
+where
+    S: Storage + ?Sized,
+{
+    fn drop(&mut self) {
+        let Self { storage } = self;
+        storage.clear();
+    }
 }
 
 /// Extension of storage which allows to implement fallible operations for the storage.
@@ -177,6 +361,117 @@ pub trait TryStorage: Storage {
         entity: Self::Entity,
         component: Self::Item,
     ) -> Result<Option<Self::Item>, Self::Err>;
+
+    /// Tries to retrieve an entry of provided entity in the storage for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the storage will fail to attach a component
+    /// to the entity through the vacant entry.
+    /// Conditions of failure are provided by implementation of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`entry`][Storage::entry()] method.
+    fn try_entry(&mut self, entity: Self::Entity) -> Result<StorageEntry<'_, Self>, Self::Err>;
+}
+
+/// Extension of storage which allows to distinguish stale entities from entities without a component.
+///
+/// Methods of [`Storage`] trait return [`None`] both when provided entity has no component attached
+/// and when it is stale, i.e. the storage has a component attached to the newer entity with the same index.
+/// Methods of this trait report the latter case as an error, which helps to find use-after-destroy bugs.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait CheckedStorage: Storage {
+    /// Retrieves a reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn get_checked(
+        &self,
+        entity: Self::Entity,
+    ) -> Result<Option<&Self::Item>, StaleEntityError<Self::Entity>>;
+
+    /// Retrieves a mutable reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn get_mut_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<&mut Self::Item>, StaleEntityError<Self::Entity>>;
+
+    /// Removes component from provided entity.
+    /// Returns previous component data, or [`None`] if there was no component attached to the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn remove_checked(
+        &mut self,
+        entity: Self::Entity,
+    ) -> Result<Option<Self::Item>, StaleEntityError<Self::Entity>>;
+}
+
+/// Extension of storage which tracks changes of its components with [change ticks](ChangeTick).
+///
+/// Change tick of the storage is advanced each time the storage is accessed mutably,
+/// even if no component was actually changed.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait TrackedStorage: Storage {
+    /// Returns current change tick of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn change_tick(&self) -> ChangeTick;
+
+    /// Replaces current change tick of the storage with provided one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn set_change_tick(&mut self, tick: ChangeTick);
 }
 
 /// Erased variant of storage of some component type in ECS.
@@ -262,6 +557,105 @@ pub trait ErasedStorage: Any {
     // FIXME: replace return type with `Option<impl Component>` when stabilized
     fn remove(&mut self, entity: &dyn ErasedEntity) -> Result<(), EntityMismatchError>;
 
+    /// Retains only the components specified by the predicate.
+    ///
+    /// In other words, removes all components for which `f(entity, component)` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn ErasedEntity, &mut dyn ErasedComponent) -> bool);
+
+    /// Exchanges components attached to provided entities.
+    ///
+    /// If only one of provided entities has a component attached,
+    /// the component is moved to another entity.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if type of any provided entity
+    /// does not match the type of entity tracked by the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn swap(
+        &mut self,
+        a: &dyn ErasedEntity,
+        b: &dyn ErasedEntity,
+    ) -> Result<(), EntityMismatchError>;
+
+    /// Copies component attached to the `from` entity to the `to` entity.
+    /// Returns `false` if the `from` entity has no component attached,
+    /// leaving the `to` entity untouched.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if type of any provided entity
+    /// does not match the type of entity tracked by the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn clone_component(
+        &mut self,
+        from: &dyn ErasedEntity,
+        to: &dyn ErasedEntity,
+    ) -> Result<bool, EntityMismatchError>;
+
+    /// Returns [`TypeId`] of components which are stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn component_type_id(&self) -> TypeId;
+
+    /// Returns type name of components which are stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn component_type_name(&self) -> &'static str;
+
+    /// Checks if components which are stored in the storage take part in the checksum of the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn has_checksum(&self) -> bool;
+
+    /// Rewrites entity keys stored in all the components of the storage with provided mapper.
+    /// Returns count of remapped components, which is zero
+    /// if components of the storage do not [opt in](Component::MAP_ENTITIES) to remapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) -> usize;
+
+    /// Calls provided function for each component of the storage and the entity it is attached to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn for_each(&self, f: &mut dyn FnMut(&dyn ErasedEntity, &dyn ErasedComponent));
+
     /// Clears this storage, destroying all components in it.
     ///
     /// # Examples
@@ -290,6 +684,29 @@ pub trait ErasedStorage: Any {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Checks if the storage has enough capacity to attach components to all provided entities.
+    ///
+    /// Provided entities are expected to be distinct.
+    /// Storages are not bounded by default.
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if type of any provided entity
+    /// does not match the type of entity tracked by the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn can_attach_all(
+        &self,
+        entities: &mut dyn Iterator<Item = &dyn ErasedEntity>,
+    ) -> Result<bool, EntityMismatchError> {
+        let _ = entities;
+        Ok(true)
+    }
 }
 
 impl<T> ErasedStorage for T
@@ -367,6 +784,80 @@ where
         Ok(())
     }
 
+    fn retain(&mut self, f: &mut dyn FnMut(&dyn ErasedEntity, &mut dyn ErasedComponent) -> bool) {
+        Storage::retain(self, |entity, item| f(&entity, item))
+    }
+
+    fn swap(
+        &mut self,
+        a: &dyn ErasedEntity,
+        b: &dyn ErasedEntity,
+    ) -> Result<(), EntityMismatchError> {
+        let a = a as &dyn Any;
+        let Some(a) = a.downcast_ref().copied() else {
+            let error = EntityMismatchError::new::<_, T::Entity>(a);
+            return Err(error);
+        };
+        let b = b as &dyn Any;
+        let Some(b) = b.downcast_ref().copied() else {
+            let error = EntityMismatchError::new::<_, T::Entity>(b);
+            return Err(error);
+        };
+
+        Storage::swap(self, a, b);
+        Ok(())
+    }
+
+    fn clone_component(
+        &mut self,
+        from: &dyn ErasedEntity,
+        to: &dyn ErasedEntity,
+    ) -> Result<bool, EntityMismatchError> {
+        let from = from as &dyn Any;
+        let Some(from) = from.downcast_ref().copied() else {
+            let error = EntityMismatchError::new::<_, T::Entity>(from);
+            return Err(error);
+        };
+        let to = to as &dyn Any;
+        let Some(to) = to.downcast_ref().copied() else {
+            let error = EntityMismatchError::new::<_, T::Entity>(to);
+            return Err(error);
+        };
+
+        let cloned = Storage::clone_component(self, from, to);
+        Ok(cloned)
+    }
+
+    fn component_type_id(&self) -> TypeId {
+        TypeId::of::<T::Item>()
+    }
+
+    fn component_type_name(&self) -> &'static str {
+        type_name::<T::Item>()
+    }
+
+    fn has_checksum(&self) -> bool {
+        T::Item::CHECKSUM.is_some()
+    }
+
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) -> usize {
+        let Some(map_entities) = T::Item::MAP_ENTITIES else {
+            return 0;
+        };
+        let mut count = 0;
+        for (_, component) in Storage::iter_mut(self) {
+            map_entities(component, mapper);
+            count += 1;
+        }
+        count
+    }
+
+    fn for_each(&self, f: &mut dyn FnMut(&dyn ErasedEntity, &dyn ErasedComponent)) {
+        for (entity, component) in Storage::iter(self) {
+            f(&entity, component)
+        }
+    }
+
     fn clear(&mut self) {
         Storage::clear(self)
     }
@@ -378,4 +869,24 @@ where
     fn is_empty(&self) -> bool {
         Storage::is_empty(self)
     }
+    fn can_attach_all(
+        &self,
+        entities: &mut dyn Iterator<Item = &dyn ErasedEntity>,
+    ) -> Result<bool, EntityMismatchError> {
+        let mut mismatch = None;
+        let entities = entities.map_while(|entity| {
+            let entity = entity as &dyn Any;
+            let Some(entity) = entity.downcast_ref().copied() else {
+                let error = EntityMismatchError::new::<_, T::Entity>(entity);
+                mismatch = Some(error);
+                return None;
+            };
+            Some(entity)
+        });
+        let can_attach = Storage::can_attach_all(self, entities);
+        match mismatch {
+            Some(error) => Err(error),
+            None => Ok(can_attach),
+        }
+    }
 }
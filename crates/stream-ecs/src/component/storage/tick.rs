@@ -0,0 +1,49 @@
+use derive_more::Display;
+
+/// Tick of the storage which is advanced each time the storage is accessed mutably.
+///
+/// Ticks only grow over time, so if the tick of the storage is the same
+/// as it was earlier, then the storage was not changed since then.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ChangeTick(u64);
+
+impl ChangeTick {
+    /// Creates new change tick from provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(tick: u64) -> Self {
+        Self(tick)
+    }
+
+    /// Returns the value of this change tick.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Returns the change tick which follows this change tick.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn next(self) -> Self {
+        Self(self.0.wrapping_add(1))
+    }
+}
@@ -0,0 +1,269 @@
+use core::{cmp::Ordering, fmt, num::NonZeroU64};
+
+use super::Entity;
+
+/// Entity key which packs its index and generation into one [non-zero](NonZeroU64) integer.
+///
+/// Both index and generation of the key are [`u32`].
+/// Zero is never a valid key, so [`Option<CompactEntity>`] has the same size as the key itself,
+/// which makes this key useful for dense relationship storages and components storing entity references.
+///
+/// Similarly to the [default entity](super::DefaultEntity), the key is [null](CompactEntity::null())
+/// if its index is [`u32::MAX`]. Null keys do not preserve their generation,
+/// so all the null keys are equal to each other.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::size_of;
+/// use stream_ecs::entity::CompactEntity;
+///
+/// assert_eq!(size_of::<Option<CompactEntity>>(), size_of::<u64>());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactEntity {
+    bits: NonZeroU64,
+}
+
+impl CompactEntity {
+    /// Creates new entity key with provided index and its generation.
+    ///
+    /// If provided index is [`u32::MAX`], the key is [null](CompactEntity::null())
+    /// and provided generation is discarded, so generation of the resulting key is always zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(0, 0);
+    ///
+    /// let entity = CompactEntity::new(u32::MAX, 42);
+    /// assert_eq!(entity.generation(), 0);
+    /// assert_eq!(entity, CompactEntity::null());
+    /// ```
+    pub const fn new(index: u32, generation: u32) -> Self {
+        // generation of null keys is discarded, so packed bits are never zero
+        let generation = if index == u32::MAX { 0 } else { generation };
+        let packed = ((generation as u64) << u32::BITS) | index as u64;
+        let Some(bits) = NonZeroU64::new(!packed) else {
+            unreachable!();
+        };
+        Self { bits }
+    }
+
+    /// Returns a unique index of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(42, 127);
+    /// assert_eq!(entity.index(), 42);
+    /// ```
+    pub const fn index(self) -> u32 {
+        let Self { bits } = self;
+        !bits.get() as u32
+    }
+
+    /// Returns the generation of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(42, 127);
+    /// assert_eq!(entity.generation(), 127);
+    /// ```
+    pub const fn generation(self) -> u32 {
+        let Self { bits } = self;
+        (!bits.get() >> u32::BITS) as u32
+    }
+
+    /// Creates the key which doesn't belong to any entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::null();
+    /// assert!(entity.is_null());
+    /// ```
+    pub const fn null() -> Self {
+        Self::new(u32::MAX, 0)
+    }
+
+    /// Checks if the entity key is null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(0, 0);
+    /// assert!(!entity.is_null());
+    ///
+    /// let entity = CompactEntity::null();
+    /// assert!(entity.is_null());
+    /// ```
+    pub const fn is_null(self) -> bool {
+        self.index() == u32::MAX
+    }
+
+    /// Returns packed representation of the entity key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(42, 127);
+    /// assert_eq!(CompactEntity::from_bits(entity.to_bits()), Some(entity));
+    /// ```
+    pub const fn to_bits(self) -> NonZeroU64 {
+        let Self { bits } = self;
+        bits
+    }
+
+    /// Creates the entity key from its packed representation.
+    /// Returns [`None`] if provided bits are not a valid packed key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::CompactEntity;
+    ///
+    /// let entity = CompactEntity::new(42, 127);
+    /// assert_eq!(CompactEntity::from_bits(entity.to_bits()), Some(entity));
+    /// ```
+    pub const fn from_bits(bits: NonZeroU64) -> Option<Self> {
+        let entity = Self { bits };
+        if entity.is_null() && entity.generation() != 0 {
+            return None;
+        }
+        Some(entity)
+    }
+}
+
+impl Default for CompactEntity {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
+impl PartialOrd for CompactEntity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CompactEntity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let key = |entity: &Self| (entity.index(), entity.generation());
+        key(self).cmp(&key(other))
+    }
+}
+
+impl fmt::Debug for CompactEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompactEntity")
+            .field("index", &self.index())
+            .field("generation", &self.generation())
+            .finish()
+    }
+}
+
+impl fmt::Display for CompactEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index(), self.generation())
+    }
+}
+
+impl Entity for CompactEntity {
+    type Index = u32;
+    type Generation = u32;
+
+    fn with(index: Self::Index, generation: Self::Generation) -> Self {
+        CompactEntity::new(index, generation)
+    }
+
+    fn index(self) -> Self::Index {
+        CompactEntity::index(self)
+    }
+
+    fn generation(self) -> Self::Generation {
+        CompactEntity::generation(self)
+    }
+
+    fn null() -> Self {
+        CompactEntity::null()
+    }
+
+    fn is_null(self) -> bool {
+        CompactEntity::is_null(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::size_of;
+
+    use hlist::hlist;
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::{ArrayRegistry, DenseArrayRegistry},
+        world::World,
+    };
+
+    use super::CompactEntity;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Target(Option<CompactEntity>);
+
+    impl Component for Target {
+        type Storage = ArrayStorage<Self, 10, CompactEntity>;
+    }
+
+    #[test]
+    fn pack() {
+        assert_eq!(
+            size_of::<Option<CompactEntity>>(),
+            size_of::<CompactEntity>()
+        );
+
+        let entity = CompactEntity::new(u32::MAX - 1, u32::MAX);
+        assert_eq!(entity.index(), u32::MAX - 1);
+        assert_eq!(entity.generation(), u32::MAX);
+        assert!(!entity.is_null());
+
+        let null = CompactEntity::new(u32::MAX, u32::MAX);
+        assert!(null.is_null());
+        assert_eq!(null, CompactEntity::null());
+        assert!(CompactEntity::new(1, 0) < CompactEntity::new(1, 1));
+        assert!(CompactEntity::new(1, 1) < CompactEntity::new(2, 0));
+    }
+
+    #[test]
+    fn world() {
+        let components = hlist![ArrayStorage::<Target, 10, CompactEntity>::new()];
+        let mut world = World::with(ArrayRegistry::<10, CompactEntity>::new(), components, ());
+        let first = world.create();
+        let second = world.create();
+        let _ = world.attach(first, Target(Some(second))).unwrap();
+        world.destroy(second).unwrap();
+        let second = world.create();
+        assert_eq!(second.generation(), 1);
+        assert_eq!(
+            world.get::<Target>(first).unwrap(),
+            Some(&Target(Some(CompactEntity::new(1, 0)))),
+        );
+
+        let mut registry = DenseArrayRegistry::<10, CompactEntity>::new();
+        let entity = registry.create();
+        assert!(registry.contains(entity));
+    }
+}
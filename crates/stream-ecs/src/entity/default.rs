@@ -1,7 +1,7 @@
 use core::fmt::Display;
 
 use derive_more::Display;
-use num_traits::{Unsigned, Zero, bounds::UpperBounded};
+use num_traits::{CheckedAdd, Unsigned, Zero, bounds::UpperBounded};
 
 use super::Entity;
 
@@ -117,7 +117,7 @@ where
 impl<Index, Generation> Entity for DefaultEntity<Index, Generation>
 where
     Index: Copy + UpperBounded + Unsigned + PartialEq + 'static,
-    Generation: Copy + Zero + Unsigned + 'static,
+    Generation: Copy + Zero + Unsigned + CheckedAdd + 'static,
 {
     type Index = Index;
     type Generation = Generation;
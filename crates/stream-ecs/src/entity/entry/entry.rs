@@ -1,7 +1,10 @@
 #![allow(clippy::module_inception)]
 
+use core::any::TypeId;
+
 use crate::{
     component::{
+        ErasedComponent,
         bundle::{Bundle, GetBundle, NotRegisteredError},
         registry::Registry as Components,
         storage::bundle::Bundle as StorageBundle,
@@ -189,4 +192,31 @@ where
         } = self;
         B::get(components, entity)
     }
+
+    /// Iterates over all the components attached to the underlying entity.
+    ///
+    /// Each item of the iterator contains type identifier, type name
+    /// and a reference to the component of some component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter_components(
+        &self,
+    ) -> impl Iterator<Item = (TypeId, &'static str, &'state dyn ErasedComponent)> + use<'state, E, C>
+    {
+        let &Self {
+            entity, components, ..
+        } = self;
+        components.iter().filter_map(move |storage| {
+            let Ok(Some(component)) = storage.get(&entity) else {
+                return None;
+            };
+            let type_id = storage.component_type_id();
+            let type_name = storage.component_type_name();
+            Some((type_id, type_name, component))
+        })
+    }
 }
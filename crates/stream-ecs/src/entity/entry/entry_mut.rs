@@ -1,8 +1,11 @@
+use core::any::TypeId;
+
 use crate::{
     component::{
+        Component, ErasedComponent,
         bundle::{Bundle, GetBundle, GetBundleMut, NotRegisteredError, TryBundle, TryBundleError},
         registry::Registry as Components,
-        storage::bundle::Bundle as StorageBundle,
+        storage::{Storage, StorageEntry, bundle::Bundle as StorageBundle},
     },
     entity::registry::{Registry as Entities, TryRegistry as TryEntities},
 };
@@ -307,4 +310,172 @@ where
         let components = &mut *self.components;
         B::get_mut(components, entity)
     }
+
+    /// Retrieves an entry of the component attached to the underlying entity
+    /// for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the component
+    /// was not registered in the component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn component_entry<T>(&mut self) -> Result<StorageEntry<'_, T::Storage>, NotRegisteredError>
+    where
+        T: Component,
+        T::Storage: Storage<Entity = E::Entity>,
+    {
+        let entity = self.entity;
+        let Some(storage) = self.components.get_mut::<T>() else {
+            return Err(NotRegisteredError::new::<T>());
+        };
+        let entry = storage.entry(entity);
+        Ok(entry)
+    }
+
+    /// Iterates over all the components attached to the underlying entity.
+    ///
+    /// Each item of the iterator contains type identifier, type name
+    /// and a reference to the component of some component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter_components(
+        &self,
+    ) -> impl Iterator<Item = (TypeId, &'static str, &dyn ErasedComponent)> {
+        let entity = self.entity;
+        let components = &*self.components;
+        components.iter().filter_map(move |storage| {
+            let Ok(Some(component)) = storage.get(&entity) else {
+                return None;
+            };
+            let type_id = storage.component_type_id();
+            let type_name = storage.component_type_name();
+            Some((type_id, type_name, component))
+        })
+    }
+
+    /// Iterates over all the components attached to the underlying entity.
+    ///
+    /// Each item of the iterator contains type identifier, type name
+    /// and a mutable reference to the component of some component type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter_components_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (TypeId, &'static str, &mut dyn ErasedComponent)> {
+        let entity = self.entity;
+        let components = &mut *self.components;
+        components.iter_mut().filter_map(move |storage| {
+            let type_id = storage.component_type_id();
+            let type_name = storage.component_type_name();
+            let Ok(Some(component)) = storage.get_mut(&entity) else {
+                return None;
+            };
+            Some((type_id, type_name, component))
+        })
+    }
+
+    /// Removes all the components attached to the underlying entity.
+    ///
+    /// Returns count of components which were removed from the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove_all(&mut self) -> usize {
+        let entity = self.entity;
+        let components = &mut *self.components;
+        components
+            .iter_mut()
+            .filter(|storage| matches!(storage.is_attached(&entity), Ok(true)))
+            .filter_map(|storage| storage.remove(&entity).ok())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::{Any, TypeId};
+
+    use hlist::hlist;
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        world::World,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Velocity(i32);
+
+    impl Component for Velocity {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[test]
+    fn iter_components() {
+        let components = hlist![
+            ArrayStorage::<Position, 10>::new(),
+            ArrayStorage::<Velocity, 10>::new(),
+        ];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+        let entity = world.create();
+        let _ = world.attach(entity, Velocity(2)).unwrap();
+
+        let mut entry = world.entry_mut(entity).unwrap();
+        let types: std::vec::Vec<_> = entry
+            .iter_components()
+            .map(|(type_id, type_name, _)| (type_id, type_name))
+            .collect();
+        assert_eq!(
+            types,
+            [(TypeId::of::<Velocity>(), core::any::type_name::<Velocity>())],
+        );
+
+        for (_, _, component) in entry.iter_components_mut() {
+            let component: &mut dyn Any = component;
+            if let Some(velocity) = component.downcast_mut::<Velocity>() {
+                velocity.0 += 1;
+            }
+        }
+        assert_eq!(entry.get::<Velocity>().unwrap(), Some(&Velocity(3)));
+    }
+
+    #[test]
+    fn remove_all() {
+        let components = hlist![
+            ArrayStorage::<Position, 10>::new(),
+            ArrayStorage::<Velocity, 10>::new(),
+        ];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+        let entity = world.create();
+        let _ = world.attach(entity, Position(1)).unwrap();
+        let _ = world.attach(entity, Velocity(2)).unwrap();
+
+        let mut entry = world.entry_mut(entity).unwrap();
+        assert_eq!(entry.remove_all(), 2);
+        assert_eq!(entry.iter_components().count(), 0);
+        assert_eq!(entry.remove_all(), 0);
+    }
 }
@@ -0,0 +1,300 @@
+//! Entity map implementation backed by an array.
+//!
+//! Such implementation does not use heap allocation at all, so it could be used in `no_std` environment.
+
+use core::{any::Any, iter::FusedIterator, slice};
+
+use arrayvec::ArrayVec;
+use derive_more::Display;
+
+use crate::entity::{DefaultEntity, Entity, ErasedEntity};
+
+use super::EntityMapper;
+
+/// The error type which is returned when array entity map capacity was exceeded.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("array entity map capacity exceeded")]
+pub struct ArrayEntityMapError;
+
+/// Map of old entity keys to new ones backed by an array.
+///
+/// Pairs of entity keys are sorted by old keys, so lookup of the new key takes logarithmic time.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArrayEntityMap<const N: usize, E = DefaultEntity> {
+    entities: ArrayVec<(E, E), N>,
+}
+
+impl<E, const N: usize> ArrayEntityMap<N, E> {
+    /// Creates new empty array entity map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let entities = ArrayVec::new_const();
+        Self { entities }
+    }
+
+    /// Returns count of entities mapped by this map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn len(&self) -> usize {
+        let Self { entities } = self;
+        entities.len()
+    }
+
+    /// Checks if the map is empty, or maps no entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        let Self { entities } = self;
+        entities.is_empty()
+    }
+
+    /// Returns count of entities which can be mapped by this map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns an iterator over pairs of old and new entity keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> Iter<'_, E> {
+        let Self { entities } = self;
+        let iter = entities.iter();
+        Iter { iter }
+    }
+
+    /// Removes all the mapped entities from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn clear(&mut self) {
+        let Self { entities } = self;
+        entities.clear()
+    }
+}
+
+impl<E, const N: usize> ArrayEntityMap<N, E>
+where
+    E: Entity + Ord,
+{
+    /// Maps old entity key to the new one.
+    /// Returns previous new key of the old entity, or [`None`] if the entity was not mapped.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the map is full and the entity was not mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn insert(&mut self, old: E, new: E) -> Option<E> {
+        let Ok(previous) = self.try_insert(old, new) else {
+            panic!("array entity map capacity exceeded");
+        };
+        previous
+    }
+
+    /// Tries to map old entity key to the new one.
+    /// Returns previous new key of the old entity, or [`None`] if the entity was not mapped.
+    ///
+    /// This is the fallible version of [`insert`][ArrayEntityMap::insert()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the map is full and the entity was not mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn try_insert(&mut self, old: E, new: E) -> Result<Option<E>, ArrayEntityMapError> {
+        let Self { entities } = self;
+        match entities.binary_search_by_key(&old, |&(old, _)| old) {
+            Ok(index) => {
+                let (_, previous) = &mut entities[index];
+                let previous = core::mem::replace(previous, new);
+                Ok(Some(previous))
+            }
+            Err(index) => {
+                entities
+                    .try_insert(index, (old, new))
+                    .map_err(|_| ArrayEntityMapError)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns new key of provided old entity, or [`None`] if the entity was not mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self, old: E) -> Option<E> {
+        let Self { entities } = self;
+        let index = entities.binary_search_by_key(&old, |&(old, _)| old).ok()?;
+        let (_, new) = entities[index];
+        Some(new)
+    }
+
+    /// Checks if provided old entity was mapped to the new one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn contains(&self, old: E) -> bool {
+        self.get(old).is_some()
+    }
+}
+
+impl<E, const N: usize> EntityMapper for ArrayEntityMap<N, E>
+where
+    E: Entity + Ord,
+{
+    fn map_erased(&self, entity: &mut dyn ErasedEntity) -> bool {
+        let entity = entity as &mut dyn Any;
+        let Some(entity) = entity.downcast_mut::<E>() else {
+            return false;
+        };
+        let Some(new) = self.get(*entity) else {
+            return false;
+        };
+        *entity = new;
+        true
+    }
+}
+
+impl<'me, E, const N: usize> IntoIterator for &'me ArrayEntityMap<N, E>
+where
+    E: Copy,
+{
+    type Item = (E, E);
+
+    type IntoIter = Iter<'me, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over pairs of old and new entity keys of the [array entity map](ArrayEntityMap).
+///
+/// This struct is created by [`iter`](ArrayEntityMap::iter()) method of the map.
+pub struct Iter<'me, E> {
+    iter: slice::Iter<'me, (E, E)>,
+}
+
+impl<E> Iterator for Iter<'_, E>
+where
+    E: Copy,
+{
+    type Item = (E, E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        iter.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for Iter<'_, E>
+where
+    E: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        iter.next_back().copied()
+    }
+}
+
+impl<E> ExactSizeIterator for Iter<'_, E>
+where
+    E: Copy,
+{
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl<E> FusedIterator for Iter<'_, E> where E: Copy {}
+
+#[cfg(test)]
+mod tests {
+    use crate::entity::{DefaultEntity, map::EntityMapper};
+
+    use super::ArrayEntityMap;
+
+    #[test]
+    fn insert() {
+        let mut map = ArrayEntityMap::<2>::new();
+        let (a, b, c) = (
+            DefaultEntity::new(2, 0),
+            DefaultEntity::new(0, 1),
+            DefaultEntity::new(5, 0),
+        );
+        assert_eq!(map.insert(a, c), None);
+        assert_eq!(map.insert(b, a), None);
+        assert_eq!(map.insert(a, b), Some(c));
+        assert!(map.try_insert(c, a).is_err());
+        assert_eq!(map.len(), 2);
+
+        let items: std::vec::Vec<_> = map.iter().collect();
+        assert_eq!(items, [(b, a), (a, b)]);
+
+        let mapper: &dyn EntityMapper = &map;
+        let mut entity = a;
+        assert!(mapper.map(&mut entity));
+        assert_eq!(entity, b);
+        let mut entity = c;
+        assert!(!mapper.map(&mut entity));
+        assert_eq!(entity, c);
+    }
+}
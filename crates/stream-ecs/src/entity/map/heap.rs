@@ -0,0 +1,191 @@
+//! Entity map implementation backed by the heap.
+
+use alloc::collections::btree_map::{self, BTreeMap};
+use core::{any::Any, iter::FusedIterator};
+
+use crate::entity::{DefaultEntity, Entity, ErasedEntity};
+
+use super::EntityMapper;
+
+/// Map of old entity keys to new ones.
+///
+/// This map is created when entities are moved from one world to another,
+/// so components which store keys of these entities can be remapped.
+/// Map is allocated on the heap, so it is only available with `alloc` feature enabled.
+/// See [array variant](super::array::ArrayEntityMap) of the map which does not use heap allocation at all.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone)]
+pub struct EntityMap<E = DefaultEntity> {
+    entities: BTreeMap<E, E>,
+}
+
+impl<E> EntityMap<E> {
+    /// Creates new empty entity map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let entities = BTreeMap::new();
+        Self { entities }
+    }
+
+    /// Returns count of entities mapped by this map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn len(&self) -> usize {
+        let Self { entities } = self;
+        entities.len()
+    }
+
+    /// Checks if the map is empty, or maps no entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let Self { entities } = self;
+        entities.is_empty()
+    }
+
+    /// Returns an iterator over pairs of old and new entity keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> Iter<'_, E> {
+        let Self { entities } = self;
+        let iter = entities.iter();
+        Iter { iter }
+    }
+}
+
+impl<E> EntityMap<E>
+where
+    E: Entity + Ord,
+{
+    /// Maps old entity key to the new one.
+    /// Returns previous new key of the old entity, or [`None`] if the entity was not mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn insert(&mut self, old: E, new: E) -> Option<E> {
+        let Self { entities } = self;
+        entities.insert(old, new)
+    }
+
+    /// Returns new key of provided old entity, or [`None`] if the entity was not mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self, old: E) -> Option<E> {
+        let Self { entities } = self;
+        entities.get(&old).copied()
+    }
+
+    /// Checks if provided old entity was mapped to the new one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn contains(&self, old: E) -> bool {
+        let Self { entities } = self;
+        entities.contains_key(&old)
+    }
+}
+
+impl<E> Default for EntityMap<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> EntityMapper for EntityMap<E>
+where
+    E: Entity + Ord,
+{
+    fn map_erased(&self, entity: &mut dyn ErasedEntity) -> bool {
+        let entity = entity as &mut dyn Any;
+        let Some(entity) = entity.downcast_mut::<E>() else {
+            return false;
+        };
+        let Some(new) = self.get(*entity) else {
+            return false;
+        };
+        *entity = new;
+        true
+    }
+}
+
+impl<'me, E> IntoIterator for &'me EntityMap<E>
+where
+    E: Copy,
+{
+    type Item = (E, E);
+
+    type IntoIter = Iter<'me, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over pairs of old and new entity keys of the [entity map](EntityMap).
+///
+/// This struct is created by [`iter`](EntityMap::iter()) method of the map.
+pub struct Iter<'me, E> {
+    iter: btree_map::Iter<'me, E, E>,
+}
+
+impl<E> Iterator for Iter<'_, E>
+where
+    E: Copy,
+{
+    type Item = (E, E);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let (&old, &new) = iter.next()?;
+        Some((old, new))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl<E> ExactSizeIterator for Iter<'_, E>
+where
+    E: Copy,
+{
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl<E> FusedIterator for Iter<'_, E> where E: Copy {}
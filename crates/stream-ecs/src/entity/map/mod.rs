@@ -0,0 +1,368 @@
+//! Provides utilities for remapping entity references in ECS.
+//!
+//! Components can store keys of other entities (for example, targets or owners).
+//! When entities are moved from one world to another, their keys change,
+//! so such components should rewrite stored keys with the map of old keys to new ones.
+//! Such map can be backed by an [array](array::ArrayEntityMap) or by the heap (with `alloc` feature enabled).
+
+#[cfg(feature = "derive")]
+pub use stream_ecs_macros::MapEntities;
+
+#[cfg(feature = "alloc")]
+pub use self::heap::{EntityMap, Iter};
+
+use super::{CompactEntity, DefaultEntity, Entity, ErasedEntity};
+
+pub mod array;
+#[cfg(feature = "alloc")]
+mod heap;
+
+/// Mapper of entity keys which is used to rewrite entity references.
+///
+/// This trait is guaranteed to be object safe, so it can be used as trait object.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait EntityMapper {
+    /// Rewrites provided entity key in place if it was mapped to another key.
+    /// Returns `false` if provided entity was not mapped or its type does not match
+    /// the type of entities of the mapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn map_erased(&self, entity: &mut dyn ErasedEntity) -> bool;
+}
+
+impl dyn EntityMapper + '_ {
+    /// Rewrites provided entity key in place if it was mapped to another key.
+    /// Returns `false` if provided entity was not mapped or its type does not match
+    /// the type of entities of the mapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn map<E>(&self, entity: &mut E) -> bool
+    where
+        E: Entity,
+    {
+        self.map_erased(entity)
+    }
+}
+
+/// Trait for types which store entity keys and can rewrite them with an [entity mapper](EntityMapper).
+///
+/// Components can opt in to remapping of entity references
+/// by [`MAP_ENTITIES`](crate::component::Component::MAP_ENTITIES) constant of the component trait.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait MapEntities {
+    /// Rewrites all entity keys stored in this value with provided mapper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn map_entities(&mut self, mapper: &dyn EntityMapper);
+}
+
+impl<Index, Generation> MapEntities for DefaultEntity<Index, Generation>
+where
+    Self: Entity,
+{
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) {
+        let _ = mapper.map(self);
+    }
+}
+
+impl MapEntities for CompactEntity {
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) {
+        let _ = mapper.map(self);
+    }
+}
+
+impl<T> MapEntities for Option<T>
+where
+    T: MapEntities,
+{
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) {
+        if let Some(value) = self {
+            value.map_entities(mapper);
+        }
+    }
+}
+
+impl<T, const N: usize> MapEntities for [T; N]
+where
+    T: MapEntities,
+{
+    fn map_entities(&mut self, mapper: &dyn EntityMapper) {
+        for value in self {
+            value.map_entities(mapper);
+        }
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl MapEntities for $ty {
+                fn map_entities(&mut self, _: &dyn EntityMapper) {}
+            }
+        )*
+    };
+}
+
+impl_leaf!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str,
+);
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use core::any::TypeId;
+    use std::hash::RandomState;
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{
+            Component,
+            storage::array::{ArrayStorage, HashArrayStorage},
+        },
+        entity::{
+            DefaultEntity,
+            registry::{
+                Registry as _,
+                array::{ArrayRegistry, ArrayRegistryError},
+                tests::NoHintRegistry,
+            },
+        },
+        world::{MergeError, World},
+    };
+
+    use super::{MapEntities, array::ArrayEntityMap};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Component, MapEntities)]
+    #[component(storage = ArrayStorage<Self, 10>, map_entities)]
+    enum Owner {
+        Entity(DefaultEntity),
+        Tagged {
+            entity: DefaultEntity,
+            #[map_entities(skip)]
+            tag: DefaultEntity,
+        },
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Component, MapEntities)]
+    #[component(storage = ArrayStorage<Self, 10>, map_entities)]
+    struct Target(Option<DefaultEntity>);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    #[component(storage = ArrayStorage<Self, 2>)]
+    struct Small;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Component)]
+    #[component(storage = HashArrayStorage<Self, RandomState, 2>)]
+    struct Score(u32);
+
+    type Components = HList![ArrayStorage<Owner, 10>, ArrayStorage<Target, 10>];
+
+    #[test]
+    fn merge_from() {
+        let components: Components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+        let existing = world.create();
+        let _ = world.attach(existing, Target(Some(existing))).unwrap();
+
+        let components = hlist![ArrayStorage::<Target, 10>::new()];
+        let mut staging = World::with(ArrayRegistry::<10>::new(), components, ());
+        let first = staging.create();
+        let second = staging.create();
+        let _ = staging.attach(first, Target(Some(second))).unwrap();
+        let _ = staging.attach(second, Target(None)).unwrap();
+
+        let map = world.merge_from(&mut staging);
+        assert_eq!(map.len(), 2);
+        assert!(staging.entities().is_empty());
+        assert!(staging.get::<Target>(first).is_err());
+
+        let (first, second) = (map.get(first).unwrap(), map.get(second).unwrap());
+        assert_eq!(world.entities().len(), 3);
+        assert_eq!(
+            world.get::<Target>(first).unwrap(),
+            Some(&Target(Some(second)))
+        );
+        assert_eq!(world.get::<Target>(second).unwrap(), Some(&Target(None)));
+        assert_eq!(
+            world.get::<Target>(existing).unwrap(),
+            Some(&Target(Some(existing))),
+        );
+    }
+
+    #[test]
+    fn merge_from_unregistered() {
+        let components = hlist![ArrayStorage::<Target, 10>::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+
+        let components: Components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        let mut staging = World::with(ArrayRegistry::<10>::new(), components, ());
+        let first = staging.create();
+        let second = staging.create();
+        let _ = staging.attach(first, Owner::Entity(second)).unwrap();
+        let _ = staging.attach(first, Target(None)).unwrap();
+        let _ = staging.attach(second, Target(Some(first))).unwrap();
+
+        let map = world.merge_from(&mut staging);
+        assert_eq!(map.len(), 2);
+        assert_eq!(world.entities().len(), 2);
+        let new_first = map.get(first).unwrap();
+        assert_eq!(world.get::<Target>(new_first).unwrap(), Some(&Target(None)));
+
+        assert_eq!(staging.entities().len(), 1);
+        assert!(staging.entities().contains(first));
+        assert!(!staging.entities().contains(second));
+        assert_eq!(staging.get::<Target>(first).unwrap(), None);
+        assert_eq!(
+            staging.get::<Owner>(first).unwrap(),
+            Some(&Owner::Entity(second)),
+        );
+    }
+
+    #[test]
+    fn try_merge_from() {
+        let components = hlist![ArrayStorage::<Target, 10>::new()];
+        let mut world = World::with(ArrayRegistry::<2>::new(), components, ());
+        let components = hlist![ArrayStorage::<Target, 10>::new()];
+        let mut staging = World::with(ArrayRegistry::<10>::new(), components, ());
+        for _ in 0..3 {
+            let entity = staging.create();
+            let _ = staging.attach(entity, Target(None)).unwrap();
+        }
+        let Err(error) = world.try_merge_from(&mut staging) else {
+            panic!("registry capacity should be exceeded");
+        };
+        assert!(matches!(
+            error,
+            MergeError::Entities(ArrayRegistryError::Capacity),
+        ));
+        assert!(world.entities().is_empty());
+        assert_eq!(staging.entities().len(), 3);
+
+        let components = hlist![ArrayStorage::<Small, 2>::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+        let components = hlist![ArrayStorage::<Small, 2>::new()];
+        let mut staging = World::with(ArrayRegistry::<10>::new(), components, ());
+        let _ = staging.create();
+        let entity = staging.create();
+        let _ = staging.attach(entity, Small).unwrap();
+        let existing = world.create();
+        let _ = world.attach(existing, Small).unwrap();
+        let Err(error) = world.try_merge_from(&mut staging) else {
+            panic!("storage capacity should be exceeded");
+        };
+        assert!(matches!(error, MergeError::Storage(_)));
+        assert_eq!(world.entities().len(), 1);
+        assert_eq!(staging.entities().len(), 2);
+        assert_eq!(staging.get::<Small>(entity).unwrap(), Some(&Small));
+
+        let _ = world.destroy(existing);
+        let map = world.try_merge_from(&mut staging).unwrap();
+        assert_eq!(map.len(), 2);
+        assert!(staging.entities().is_empty());
+        let entity = map.get(entity).unwrap();
+        assert_eq!(world.get::<Small>(entity).unwrap(), Some(&Small));
+    }
+
+    #[test]
+    fn try_merge_from_without_hint() {
+        type ScoreComponents =
+            HList![ArrayStorage<Target, 10>, HashArrayStorage<Score, RandomState, 2>];
+
+        let components =
+            || -> ScoreComponents { hlist![ArrayStorage::new(), HashArrayStorage::new()] };
+        let mut world = World::with(NoHintRegistry::<10>::default(), components(), ());
+        let mut staging = World::with(ArrayRegistry::<10>::new(), components(), ());
+        let first = staging.create();
+        let _ = staging.attach(first, Target(None)).unwrap();
+        let Err(MergeError::Storage(error)) = world.try_merge_from(&mut staging) else {
+            panic!("array storage capacity cannot be checked without a hint");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Target>());
+        assert!(world.entities().is_empty());
+        assert_eq!(staging.get::<Target>(first).unwrap(), Some(&Target(None)));
+
+        let _ = staging.remove::<Target>(first).unwrap();
+        for (score, entity) in (0..).zip([first, staging.create()]) {
+            let _ = staging.attach(entity, Score(score)).unwrap();
+        }
+        let existing = world.create();
+        let _ = world.attach(existing, Score(10)).unwrap();
+        let Err(MergeError::Storage(error)) = world.try_merge_from(&mut staging) else {
+            panic!("hash storage capacity should be exceeded");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Score>());
+        assert_eq!(world.entities().len(), 1);
+        assert_eq!(staging.entities().len(), 2);
+
+        let _ = world.remove::<Score>(existing).unwrap();
+        let map = world.try_merge_from(&mut staging).unwrap();
+        assert_eq!(map.len(), 2);
+        assert!(staging.entities().is_empty());
+        let mut scores: std::vec::Vec<_> = world
+            .entities()
+            .iter()
+            .filter_map(|entity| world.get::<Score>(entity).unwrap().copied())
+            .collect();
+        scores.sort_by_key(|score| score.0);
+        assert_eq!(scores, [Score(0), Score(1)]);
+    }
+
+    #[test]
+    fn map_entities() {
+        let components: Components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+        let (a, b) = (world.create(), world.create());
+        let _ = world.attach(a, Owner::Entity(b)).unwrap();
+        let owner = Owner::Tagged { entity: a, tag: a };
+        let _ = world.attach(b, owner).unwrap();
+        let _ = world.attach(b, Target(Some(b))).unwrap();
+
+        let mut map = ArrayEntityMap::<2>::new();
+        let _ = map.insert(a, b);
+        let _ = map.insert(b, a);
+        assert_eq!(world.map_entities(&map), 3);
+        assert_eq!(world.get::<Owner>(a).unwrap(), Some(&Owner::Entity(a)));
+        let owner = Owner::Tagged { entity: b, tag: a };
+        assert_eq!(world.get::<Owner>(b).unwrap(), Some(&owner));
+        assert_eq!(world.get::<Target>(b).unwrap(), Some(&Target(Some(a))));
+    }
+}
@@ -2,12 +2,16 @@
 
 use core::any::Any;
 
-pub use self::default::DefaultEntity;
+use num_traits::{CheckedAdd, One, Zero};
+
+pub use self::{compact::CompactEntity, default::DefaultEntity};
 
 pub mod builder;
 pub mod entry;
+pub mod map;
 pub mod registry;
 
+mod compact;
 mod default;
 
 /// Unique key of the entity in ECS.
@@ -25,13 +29,22 @@ pub trait Entity: Copy + 'static {
     type Index: Copy + 'static;
 
     /// Type of generation of the entity.
-    type Generation: Copy + 'static;
+    ///
+    /// Generation starts from [zero](Zero) and is increased by [one](One) with [checked addition](CheckedAdd)
+    /// each time the entity with the same index is destroyed.
+    /// Generation never wraps around: once it cannot be increased anymore,
+    /// registries retire the index permanently, so stale keys of destroyed entities can never be resurrected.
+    type Generation: Copy + PartialEq + Zero + One + CheckedAdd + 'static;
 
     /// Creates new entity key with provided index and its generation.
     ///
     /// More detained information about index and generation can be found
     /// in the documentation of [`index`][index] and [`generation`][generation] methods.
     ///
+    /// Implementations may discard provided generation if the key is [null](Entity::is_null()),
+    /// so generation of the resulting key may differ from provided one
+    /// (see [`CompactEntity::new`](CompactEntity::new()) for an example).
+    ///
     /// [index]: Entity::index()
     /// [generation]: Entity::generation()
     ///
@@ -2,22 +2,26 @@
 
 use core::{
     iter::{Enumerate, FusedIterator},
-    ops::Add,
     slice,
 };
 
 use arrayvec::ArrayVec;
+use num_traits::{CheckedAdd, One, Zero};
 
 use crate::entity::{
     DefaultEntity, Entity,
-    registry::{NotPresentError, Registry, TryRegistry},
+    registry::{CreateMany, NotPresentError, Registry, TryRegistry},
 };
 
-use super::ArrayRegistryError;
+use super::{
+    ArrayRegistryError, RecyclePolicy,
+    free::{FreeList, FreeSlot},
+};
 
 #[derive(Debug, Clone)]
 enum SlotEntry<T> {
     Free { next_free: usize },
+    Retired,
     Occupied { value: T },
 }
 
@@ -27,6 +31,22 @@ struct Slot<T, G> {
     generation: G,
 }
 
+impl<T, G> FreeSlot for Slot<T, G> {
+    fn next_free(&mut self) -> &mut usize {
+        let SlotEntry::Free { next_free } = &mut self.entry else {
+            unreachable!("free list must not point to the occupied entry")
+        };
+        next_free
+    }
+
+    fn next_free_index(&self) -> usize {
+        let SlotEntry::Free { next_free } = self.entry else {
+            unreachable!("free list must not point to the occupied entry")
+        };
+        next_free
+    }
+}
+
 /// Default implementation of the entity registry backed by an array.
 ///
 /// # Examples
@@ -40,8 +60,10 @@ where
     E: Entity,
 {
     slots: ArrayVec<Slot<(), E::Generation>, N>,
-    free_head: usize,
+    free: FreeList,
+    retired: usize,
     len: usize,
+    policy: RecyclePolicy,
 }
 
 impl<E, const N: usize> ArrayRegistry<N, E>
@@ -66,13 +88,82 @@ where
     /// const REGISTRY: ArrayRegistry<1024> = ArrayRegistry::new();
     /// ```
     pub const fn new() -> Self {
+        Self::with_policy(RecyclePolicy::Lifo)
+    }
+
+    /// Creates new empty array entity registry which reuses indices of destroyed entities
+    /// with provided [policy](RecyclePolicy).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::{ArrayRegistry, RecyclePolicy};
+    ///
+    /// let registry = ArrayRegistry::<10>::with_policy(RecyclePolicy::Fifo);
+    /// assert_eq!(registry.policy(), RecyclePolicy::Fifo);
+    /// ```
+    pub const fn with_policy(policy: RecyclePolicy) -> Self {
         Self {
             slots: ArrayVec::new_const(),
-            free_head: 0,
+            free: FreeList::new(),
+            retired: 0,
             len: 0,
+            policy,
         }
     }
 
+    /// Returns the [policy](RecyclePolicy) of reusing indices of destroyed entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::{ArrayRegistry, RecyclePolicy};
+    ///
+    /// let registry = ArrayRegistry::<10>::new();
+    /// assert_eq!(registry.policy(), RecyclePolicy::Lifo);
+    /// ```
+    pub const fn policy(&self) -> RecyclePolicy {
+        self.policy
+    }
+
+    /// Returns count of indices which were retired by the array registry.
+    ///
+    /// Index is retired permanently when the [generation](Entity::Generation) of the destroyed entity
+    /// cannot be increased anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::{DefaultEntity, registry::array::ArrayRegistry};
+    ///
+    /// let mut registry = ArrayRegistry::<10, DefaultEntity<u32, u8>>::new();
+    /// for _ in 0..=u8::MAX {
+    ///     let entity = registry.create();
+    ///     registry.destroy(entity).unwrap();
+    /// }
+    /// assert_eq!(registry.retired_count(), 1);
+    /// ```
+    pub const fn retired_count(&self) -> usize {
+        self.retired
+    }
+
+    /// Changes the [policy](RecyclePolicy) of reusing indices of destroyed entities.
+    ///
+    /// Indices which were freed earlier are reused in the order they were freed with the previous policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::{ArrayRegistry, RecyclePolicy};
+    ///
+    /// let mut registry = ArrayRegistry::<10>::new();
+    /// registry.set_policy(RecyclePolicy::Delayed(4));
+    /// assert_eq!(registry.policy(), RecyclePolicy::Delayed(4));
+    /// ```
+    pub fn set_policy(&mut self, policy: RecyclePolicy) {
+        self.policy = policy;
+    }
+
     /// Returns count of currently alive entities of the array registry.
     ///
     /// # Examples
@@ -140,16 +231,21 @@ where
     /// ```
     pub fn clear(&mut self) {
         self.slots.clear();
-        self.free_head = 0;
+        self.free.clear();
+        self.retired = 0;
         self.len = 0;
     }
+
+    fn next_free(&self) -> Option<usize> {
+        let can_grow = !self.slots.is_full();
+        self.free.peek(self.policy, can_grow)
+    }
 }
 
 impl<E, const N: usize> ArrayRegistry<N, E>
 where
     E: Entity,
     E::Index: TryFrom<usize>,
-    E::Generation: TryFrom<usize>,
 {
     /// Creates new entity in the array registry.
     ///
@@ -186,7 +282,7 @@ where
     /// # Errors
     ///
     /// This function will return an error if the count of already created entities
-    /// is the same as the capacity of the registry.
+    /// is the same as the capacity of the registry, or if all of its indices were retired.
     ///
     /// # Examples
     ///
@@ -202,39 +298,199 @@ where
     ///
     /// This is the fallible version of [`create`][Self::create()] method.
     pub fn try_create(&mut self) -> Result<E, ArrayRegistryError> {
-        let entity = if let Some(slot) = self.slots.get_mut(self.free_head) {
-            if let SlotEntry::Free { next_free } = slot.entry {
-                let index = self.free_head.try_into().map_err(|_| ArrayRegistryError)?;
-                let entity = E::with(index, slot.generation);
-                self.free_head = next_free;
-                slot.entry = SlotEntry::Occupied { value: () };
-                entity
-            } else {
-                unreachable!("free head must not point to the occupied entry")
-            }
+        let entity = if let Some(index) = self.next_free() {
+            let entity_index = index.try_into().map_err(|_| ArrayRegistryError::Capacity)?;
+            let _ = self.free.pop(&mut self.slots);
+            let slot = &mut self.slots[index];
+            slot.entry = SlotEntry::Occupied { value: () };
+            E::with(entity_index, slot.generation)
         } else {
-            let index = self.len.try_into().map_err(|_| ArrayRegistryError)?;
-            let generation = 0.try_into().map_err(|_| ArrayRegistryError)?;
-            let entity = E::with(index, generation);
+            let index = self
+                .slots
+                .len()
+                .try_into()
+                .map_err(|_| ArrayRegistryError::Capacity)?;
+            let generation = E::Generation::zero();
             let slot = Slot {
                 entry: SlotEntry::Occupied { value: () },
                 generation,
             };
             if self.slots.try_push(slot).is_err() {
-                return Err(ArrayRegistryError);
+                if self.retired == N {
+                    return Err(ArrayRegistryError::Retired);
+                }
+                return Err(ArrayRegistryError::Capacity);
             }
-            self.free_head = self.len + 1;
-            entity
+            E::with(index, generation)
         };
         self.len += 1;
         Ok(entity)
     }
+
+    /// Returns index of the entity which will be created next by the array registry,
+    /// or [`None`] if the registry is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let mut registry = ArrayRegistry::<2>::new();
+    /// let index = registry.next_index_hint();
+    /// let entity = registry.create();
+    /// assert_eq!(index, Some(entity.index()));
+    ///
+    /// let _ = registry.create();
+    /// assert_eq!(registry.next_index_hint(), None);
+    /// ```
+    pub fn next_index_hint(&self) -> Option<E::Index> {
+        let index = match self.next_free() {
+            Some(index) => index,
+            None if self.slots.is_full() => return None,
+            None => self.slots.len(),
+        };
+        index.try_into().ok()
+    }
+
+    /// Returns an iterator over provided count of entities which will be created next by the array registry,
+    /// or [`None`] if the registry cannot create so many entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let mut registry = ArrayRegistry::<3>::new();
+    /// let hint: Vec<_> = registry.next_entities_hint(2).unwrap().collect();
+    /// let entities: Vec<_> = registry.create_many(2).collect();
+    /// assert_eq!(hint, entities);
+    ///
+    /// assert!(registry.next_entities_hint(2).is_none());
+    /// ```
+    pub fn next_entities_hint(&self, count: usize) -> Option<impl Iterator<Item = E> + Clone + '_> {
+        self.check_capacity(count).ok()?;
+        let Self {
+            slots,
+            free,
+            policy,
+            ..
+        } = self;
+        let upcoming = free.upcoming(slots, *policy, N).take(count);
+        let entities = upcoming.map_while(|index| {
+            let generation = slots
+                .get(index)
+                .map_or_else(E::Generation::zero, |slot| slot.generation);
+            let index = index.try_into().ok()?;
+            Some(E::with(index, generation))
+        });
+        Some(entities)
+    }
+
+    /// Checks if the array registry is able to create provided count of new entities
+    /// without creating any of them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the array registry cannot create provided count of entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let registry = ArrayRegistry::<2>::new();
+    /// assert!(registry.check_capacity(2).is_ok());
+    /// assert!(registry.check_capacity(3).is_err());
+    /// ```
+    pub fn check_capacity(&self, count: usize) -> Result<(), ArrayRegistryError> {
+        let spare = self.slots.remaining_capacity();
+        let Some(grow) = self.free.grow_count(self.policy, count, spare) else {
+            if self.retired == N {
+                return Err(ArrayRegistryError::Retired);
+            }
+            return Err(ArrayRegistryError::Capacity);
+        };
+        if let Some(last) = (self.slots.len() + grow).checked_sub(1) {
+            E::Index::try_from(last).map_err(|_| ArrayRegistryError::Capacity)?;
+        }
+        Ok(())
+    }
+
+    /// Creates provided count of new entities in the array registry.
+    /// Returns an iterator over newly created entities.
+    ///
+    /// All the entities are created even if returned iterator was not consumed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the array registry cannot create provided count of entities.
+    /// No entities are created in this case.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_create_many`][Self::try_create_many()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let mut registry = ArrayRegistry::<10>::new();
+    /// let entities = registry.create_many(3);
+    /// assert_eq!(entities.len(), 3);
+    ///
+    /// drop(entities);
+    /// assert_eq!(registry.len(), 3);
+    /// ```
+    #[track_caller]
+    pub fn create_many(&mut self, count: usize) -> CreateMany<'_, Self>
+    where
+        usize: TryFrom<E::Index>,
+    {
+        match self.try_create_many(count) {
+            Ok(entities) => entities,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Tries to create provided count of new entities in the array registry.
+    /// Returns an iterator over newly created entities.
+    ///
+    /// All the entities are created even if returned iterator was not consumed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the array registry cannot create provided count of entities.
+    /// No entities are created in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let mut registry = ArrayRegistry::<2>::new();
+    /// assert!(registry.try_create_many(3).is_err());
+    /// assert!(registry.is_empty());
+    ///
+    /// let entities: Vec<_> = registry.try_create_many(2).unwrap().collect();
+    /// assert_eq!(entities.len(), 2);
+    /// ```
+    ///
+    /// This is the fallible version of [`create_many`][Self::create_many()] method.
+    pub fn try_create_many(
+        &mut self,
+        count: usize,
+    ) -> Result<CreateMany<'_, Self>, ArrayRegistryError>
+    where
+        usize: TryFrom<E::Index>,
+    {
+        self.check_capacity(count)?;
+        Ok(CreateMany::new(self, count))
+    }
 }
 
 impl<E, const N: usize> ArrayRegistry<N, E>
 where
     E: Entity,
-    E::Generation: PartialEq,
     usize: TryFrom<E::Index>,
 {
     /// Checks if the array registry contains provided entity.
@@ -262,9 +518,9 @@ where
             ref entry,
             generation,
         } = slot;
-        if let SlotEntry::Free { .. } = entry {
+        let SlotEntry::Occupied { .. } = entry else {
             return false;
-        }
+        };
         generation == entity.generation()
     }
 }
@@ -273,7 +529,6 @@ impl<E, const N: usize> ArrayRegistry<N, E>
 where
     E: Entity,
     E::Index: TryFrom<usize>,
-    E::Generation: TryFrom<usize> + PartialEq + Add<Output = E::Generation>,
     usize: TryFrom<E::Index>,
 {
     /// Destroys provided entity which was previously created in the array registry.
@@ -298,6 +553,48 @@ where
     /// assert!(result.is_err());
     /// ```
     pub fn destroy(&mut self, entity: E) -> Result<(), NotPresentError<E>> {
+        let mut free = FreeList::new();
+        self.release(entity, &mut free)?;
+        self.free.append(&mut self.slots, free, self.policy);
+        Ok(())
+    }
+
+    /// Destroys all provided entities which were previously created in the array registry.
+    /// Returns count of destroyed entities.
+    ///
+    /// Entities which were destroyed earlier or were not created in the registry are skipped.
+    /// Indices of destroyed entities are linked together first
+    /// and then are spliced into the list of free indices at once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use stream_ecs::entity::registry::array::ArrayRegistry;
+    ///
+    /// let mut registry = ArrayRegistry::<10>::new();
+    /// let first = registry.create();
+    /// let second = registry.create();
+    ///
+    /// let count = registry.destroy_many([first, second, first]);
+    /// assert_eq!(count, 2);
+    /// assert!(registry.is_empty());
+    /// ```
+    pub fn destroy_many<I>(&mut self, entities: I) -> usize
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let mut free = FreeList::new();
+        let count = entities
+            .into_iter()
+            .filter(|&entity| self.release(entity, &mut free).is_ok())
+            .count();
+        self.free.append(&mut self.slots, free, self.policy);
+        count
+    }
+
+    /// Removes provided entity from the registry, linking its index into provided free list
+    /// or retiring the index if the generation cannot be increased anymore.
+    fn release(&mut self, entity: E, free: &mut FreeList) -> Result<(), NotPresentError<E>> {
         let Ok(index) = usize::try_from(entity.index()) else {
             return Err(NotPresentError::new(entity));
         };
@@ -310,16 +607,17 @@ where
         if slot.generation != entity.generation() {
             return Err(NotPresentError::new(entity));
         }
-        slot.generation = {
-            let Ok(one) = 1.try_into() else {
-                return Err(NotPresentError::new(entity));
-            };
-            slot.generation + one
-        };
-        slot.entry = SlotEntry::Free {
-            next_free: self.free_head,
-        };
-        self.free_head = index;
+        match slot.generation.checked_add(&E::Generation::one()) {
+            Some(generation) => {
+                slot.generation = generation;
+                slot.entry = SlotEntry::Free { next_free: 0 };
+                free.push(&mut self.slots, index, self.policy);
+            }
+            None => {
+                slot.entry = SlotEntry::Retired;
+                self.retired += 1;
+            }
+        }
         self.len -= 1;
         Ok(value)
     }
@@ -354,7 +652,6 @@ impl<E, const N: usize> Registry for ArrayRegistry<N, E>
 where
     E: Entity,
     E::Index: TryFrom<usize>,
-    E::Generation: TryFrom<usize> + PartialEq + Add<Output = E::Generation>,
     usize: TryFrom<E::Index>,
 {
     type Entity = E;
@@ -363,6 +660,10 @@ where
         ArrayRegistry::create(self)
     }
 
+    fn create_many(&mut self, count: usize) -> impl ExactSizeIterator<Item = Self::Entity> {
+        ArrayRegistry::create_many(self, count)
+    }
+
     fn contains(&self, entity: Self::Entity) -> bool {
         ArrayRegistry::contains(self, entity)
     }
@@ -371,6 +672,13 @@ where
         ArrayRegistry::destroy(self, entity)
     }
 
+    fn destroy_many<I>(&mut self, entities: I) -> usize
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        ArrayRegistry::destroy_many(self, entities)
+    }
+
     fn len(&self) -> usize {
         ArrayRegistry::len(self)
     }
@@ -383,6 +691,21 @@ where
         ArrayRegistry::clear(self)
     }
 
+    fn next_index_hint(&self) -> Option<E::Index> {
+        ArrayRegistry::next_index_hint(self)
+    }
+
+    fn next_entities_hint(
+        &self,
+        count: usize,
+    ) -> Option<impl Iterator<Item = Self::Entity> + Clone> {
+        ArrayRegistry::next_entities_hint(self, count)
+    }
+
+    fn retired_count(&self) -> usize {
+        ArrayRegistry::retired_count(self)
+    }
+
     type Iter<'me>
         = Iter<'me, Self::Entity>
     where
@@ -397,7 +720,6 @@ impl<E, const N: usize> TryRegistry for ArrayRegistry<N, E>
 where
     E: Entity,
     E::Index: TryFrom<usize>,
-    E::Generation: TryFrom<usize> + PartialEq + Add<Output = E::Generation>,
     usize: TryFrom<E::Index>,
 {
     type Err = ArrayRegistryError;
@@ -405,6 +727,17 @@ where
     fn try_create(&mut self) -> Result<Self::Entity, Self::Err> {
         ArrayRegistry::try_create(self)
     }
+
+    fn check_capacity(&self, count: usize) -> Result<(), Self::Err> {
+        ArrayRegistry::check_capacity(self, count)
+    }
+
+    fn try_create_many(
+        &mut self,
+        count: usize,
+    ) -> Result<impl ExactSizeIterator<Item = Self::Entity>, Self::Err> {
+        ArrayRegistry::try_create_many(self, count)
+    }
 }
 
 impl<'me, E, const N: usize> IntoIterator for &'me ArrayRegistry<N, E>
@@ -464,9 +797,9 @@ where
                 ref entry,
                 generation,
             } = slot;
-            if let SlotEntry::Free { .. } = entry {
+            let SlotEntry::Occupied { .. } = entry else {
                 continue;
-            }
+            };
             self.num_left -= 1;
             break E::with(index, generation);
         };
@@ -492,9 +825,9 @@ where
                 ref entry,
                 generation,
             } = slot;
-            if let SlotEntry::Free { .. } = entry {
+            let SlotEntry::Occupied { .. } = entry else {
                 continue;
-            }
+            };
             self.num_left -= 1;
             break E::with(index, generation);
         };
@@ -541,9 +874,9 @@ where
             let (index, slot) = self.iter.next()?;
             let index = index.try_into().ok()?;
             let Slot { entry, generation } = slot;
-            if let SlotEntry::Free { .. } = entry {
+            let SlotEntry::Occupied { .. } = entry else {
                 continue;
-            }
+            };
             self.num_left -= 1;
             break E::with(index, generation);
         };
@@ -566,9 +899,9 @@ where
             let (index, slot) = self.iter.next_back()?;
             let index = index.try_into().ok()?;
             let Slot { entry, generation } = slot;
-            if let SlotEntry::Free { .. } = entry {
+            let SlotEntry::Occupied { .. } = entry else {
                 continue;
-            }
+            };
             self.num_left -= 1;
             break E::with(index, generation);
         };
@@ -595,6 +928,14 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::entity::{
+        DefaultEntity,
+        registry::{
+            Registry, TryRegistry,
+            array::{ArrayRegistryError, RecyclePolicy},
+        },
+    };
+
     use super::ArrayRegistry;
 
     #[test]
@@ -674,4 +1015,138 @@ mod tests {
         let entity = iter.find(|entity| entity.index() == 2);
         assert!(entity.is_none());
     }
+
+    #[test]
+    fn policy() {
+        let mut registry = ArrayRegistry::<4>::with_policy(RecyclePolicy::Fifo);
+        let first = registry.create();
+        let second = registry.create();
+        registry.destroy(first).unwrap();
+        registry.destroy(second).unwrap();
+        assert_eq!(Registry::next_index_hint(&registry), Some(first.index()));
+        assert_eq!(registry.create().index(), first.index());
+
+        registry.set_policy(RecyclePolicy::Lifo);
+        let third = registry.create();
+        assert_eq!(third.index(), second.index());
+        registry.destroy(third).unwrap();
+        let entity = registry.create();
+        assert_eq!(entity.index(), third.index());
+        assert_eq!(entity.generation(), third.generation() + 1);
+    }
+
+    #[test]
+    fn delayed() {
+        let mut registry = ArrayRegistry::<3>::with_policy(RecyclePolicy::Delayed(1));
+        let first = registry.create();
+        registry.destroy(first).unwrap();
+        assert_eq!(registry.next_index_hint(), Some(1));
+        let second = registry.create();
+        assert_eq!(second.index(), 1);
+
+        registry.destroy(second).unwrap();
+        assert_eq!(registry.next_index_hint(), Some(first.index()));
+        let _ = registry.create();
+        let _ = registry.create();
+        // registry is full, so the delayed index is reused earlier
+        assert_eq!(registry.next_index_hint(), Some(second.index()));
+        let _ = registry.create();
+        assert_eq!(registry.next_index_hint(), None);
+        assert!(registry.try_create().is_err());
+    }
+
+    #[test]
+    fn retire() {
+        let mut registry = ArrayRegistry::<2, DefaultEntity<u32, u8>>::new();
+        let first = registry.create();
+        for _ in 0..=u8::MAX {
+            let entity = registry.create();
+            registry.destroy(entity).unwrap();
+        }
+        assert_eq!(registry.retired_count(), 1);
+        assert!(registry.contains(first));
+        assert_eq!(registry.try_create(), Err(ArrayRegistryError::Capacity));
+        registry.destroy(first).unwrap();
+
+        let entity = registry.try_create().unwrap();
+        assert_eq!(entity.index(), first.index());
+        assert_eq!(registry.try_create(), Err(ArrayRegistryError::Capacity));
+        registry.clear();
+        assert_eq!(registry.retired_count(), 0);
+
+        let mut registry = ArrayRegistry::<1, DefaultEntity<u32, u8>>::new();
+        for _ in 0..=u8::MAX {
+            let entity = registry.create();
+            registry.destroy(entity).unwrap();
+        }
+        assert_eq!(Registry::retired_count(&registry), 1);
+        assert_eq!(registry.next_index_hint(), None);
+        assert_eq!(
+            TryRegistry::try_create(&mut registry).err(),
+            Some(ArrayRegistryError::Retired),
+        );
+    }
+
+    #[test]
+    fn batch() {
+        let mut registry = ArrayRegistry::<4>::with_policy(RecyclePolicy::Fifo);
+        let entities: std::vec::Vec<_> = registry.create_many(3).collect();
+        assert_eq!(registry.len(), 3);
+        assert_eq!(
+            registry.destroy_many([entities[2], entities[0], entities[2]]),
+            2,
+        );
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.next_index_hint(), Some(entities[2].index()));
+
+        assert_eq!(
+            registry.try_create_many(4).err(),
+            Some(ArrayRegistryError::Capacity),
+        );
+        assert_eq!(registry.len(), 1);
+        let _ = Registry::create_many(&mut registry, 3);
+        assert_eq!(registry.len(), 4);
+        assert!(!registry.contains(entities[0]));
+        assert!(registry.contains(entities[1]));
+
+        let mut registry = ArrayRegistry::<4>::new();
+        let entities: std::vec::Vec<_> = registry.create_many(4).collect();
+        registry.destroy(entities[3]).unwrap();
+        assert_eq!(registry.destroy_many([entities[0], entities[1]]), 2);
+        let indices: std::vec::Vec<_> = registry.create_many(3).map(|e| e.index()).collect();
+        assert_eq!(indices, [1, 0, 3]);
+
+        let mut registry = ArrayRegistry::<300, DefaultEntity<u8, u32>>::new();
+        assert_eq!(
+            registry.try_create_many(257).err(),
+            Some(ArrayRegistryError::Capacity),
+        );
+        assert!(registry.is_empty());
+        assert_eq!(registry.create_many(256).len(), 256);
+    }
+    #[test]
+    fn entities_hint() {
+        let policies = [
+            RecyclePolicy::Lifo,
+            RecyclePolicy::Fifo,
+            RecyclePolicy::Delayed(2),
+        ];
+        for policy in policies {
+            let mut registry = ArrayRegistry::<6>::with_policy(policy);
+            let entities: std::vec::Vec<_> = registry.create_many(5).collect();
+            assert_eq!(
+                registry.destroy_many([entities[3], entities[1], entities[0]]),
+                3
+            );
+
+            assert!(registry.next_entities_hint(5).is_none());
+            let hint: std::vec::Vec<_> = registry.next_entities_hint(4).unwrap().collect();
+            let created: std::vec::Vec<_> = registry.create_many(4).collect();
+            assert_eq!(hint, created);
+            assert_eq!(
+                Registry::next_entities_hint(&registry, 0).unwrap().count(),
+                0
+            );
+        }
+    }
 }
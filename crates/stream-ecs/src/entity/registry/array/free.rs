@@ -0,0 +1,245 @@
+//! Intrusive list of free slots shared by array registries.
+
+use core::iter::FusedIterator;
+
+use super::RecyclePolicy;
+
+/// Slot of the array registry which can be linked into the free list.
+pub trait FreeSlot {
+    /// Returns a mutable reference to the index of the next free slot.
+    ///
+    /// Free list must only link free slots, so this method panics for occupied slots.
+    fn next_free(&mut self) -> &mut usize;
+
+    /// Returns the index of the next free slot.
+    ///
+    /// Free list must only link free slots, so this method panics for occupied slots.
+    fn next_free_index(&self) -> usize;
+}
+
+/// List of free slots linked through the slots themselves.
+///
+/// Slots are always taken from the front of the list,
+/// but freed slots are pushed to the front or to the back of the list depending on the policy.
+#[derive(Debug, Clone, Copy)]
+pub struct FreeList {
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl FreeList {
+    const NONE: usize = usize::MAX;
+
+    pub const fn new() -> Self {
+        Self {
+            head: Self::NONE,
+            tail: Self::NONE,
+            len: 0,
+        }
+    }
+
+    /// Returns index of the slot which will be taken next with provided policy,
+    /// or [`None`] if new slot should be allocated instead.
+    ///
+    /// Delayed slots are reused earlier than required by the policy
+    /// only if new slot cannot be allocated.
+    pub fn peek(&self, policy: RecyclePolicy, can_grow: bool) -> Option<usize> {
+        let &Self { head, len, .. } = self;
+        if len == 0 {
+            return None;
+        }
+        match policy {
+            RecyclePolicy::Delayed(after) if len <= after && can_grow => None,
+            _ => Some(head),
+        }
+    }
+
+    /// Returns count of new slots which will be allocated
+    /// if provided count of slots is taken one by one with provided policy,
+    /// or [`None`] if there is not enough free and new slots.
+    pub fn grow_count(&self, policy: RecyclePolicy, count: usize, spare: usize) -> Option<usize> {
+        let &Self { len, .. } = self;
+        if count > len + spare {
+            return None;
+        }
+        let reused = match policy {
+            RecyclePolicy::Delayed(after) => len.saturating_sub(after),
+            RecyclePolicy::Lifo | RecyclePolicy::Fifo => len,
+        };
+        let grow = count.saturating_sub(reused).min(spare);
+        Some(grow)
+    }
+
+    /// Returns an iterator over indices of slots which will be taken one by one with provided policy,
+    /// allocating new slots after all the existing ones until provided capacity is reached.
+    pub fn upcoming<'slots, S>(
+        &self,
+        slots: &'slots [S],
+        policy: RecyclePolicy,
+        capacity: usize,
+    ) -> Upcoming<'slots, S>
+    where
+        S: FreeSlot,
+    {
+        Upcoming {
+            slots,
+            free: *self,
+            policy,
+            capacity,
+            grown: 0,
+        }
+    }
+
+    /// Takes the slot from the front of the list.
+    pub fn pop<S>(&mut self, slots: &mut [S]) -> Option<usize>
+    where
+        S: FreeSlot,
+    {
+        let Self { head, tail, len } = self;
+        if *len == 0 {
+            return None;
+        }
+        let index = *head;
+        *head = *slots[index].next_free();
+        *len -= 1;
+        if *len == 0 {
+            *tail = Self::NONE;
+        }
+        Some(index)
+    }
+
+    /// Links provided free slot into the list with provided policy.
+    pub fn push<S>(&mut self, slots: &mut [S], index: usize, policy: RecyclePolicy)
+    where
+        S: FreeSlot,
+    {
+        let Self { head, tail, len } = self;
+        match policy {
+            RecyclePolicy::Lifo => {
+                *slots[index].next_free() = *head;
+                *head = index;
+                if *len == 0 {
+                    *tail = index;
+                }
+            }
+            RecyclePolicy::Fifo | RecyclePolicy::Delayed(_) => {
+                *slots[index].next_free() = Self::NONE;
+                if *len == 0 {
+                    *head = index;
+                } else {
+                    *slots[*tail].next_free() = index;
+                }
+                *tail = index;
+            }
+        }
+        *len += 1;
+    }
+
+    /// Links all the slots of other list into this list with provided policy at once.
+    ///
+    /// Other list must be built with the same policy,
+    /// so the order of its slots is the same as if they were pushed one by one.
+    pub fn append<S>(&mut self, slots: &mut [S], other: Self, policy: RecyclePolicy)
+    where
+        S: FreeSlot,
+    {
+        let Self {
+            head: other_head,
+            tail: other_tail,
+            len: other_len,
+        } = other;
+        if other_len == 0 {
+            return;
+        }
+        if self.len == 0 {
+            *self = other;
+            return;
+        }
+        let Self { head, tail, len } = self;
+        match policy {
+            RecyclePolicy::Lifo => {
+                *slots[other_tail].next_free() = *head;
+                *head = other_head;
+            }
+            RecyclePolicy::Fifo | RecyclePolicy::Delayed(_) => {
+                *slots[*tail].next_free() = other_head;
+                *tail = other_tail;
+            }
+        }
+        *len += other_len;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for FreeList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator over indices of slots which will be taken next from the free list or allocated,
+/// without modifying the list itself.
+#[derive(Debug)]
+pub struct Upcoming<'slots, S> {
+    slots: &'slots [S],
+    free: FreeList,
+    policy: RecyclePolicy,
+    capacity: usize,
+    grown: usize,
+}
+
+impl<S> Clone for Upcoming<'_, S> {
+    fn clone(&self) -> Self {
+        let &Self {
+            slots,
+            free,
+            policy,
+            capacity,
+            grown,
+        } = self;
+        Self {
+            slots,
+            free,
+            policy,
+            capacity,
+            grown,
+        }
+    }
+}
+
+impl<S> Iterator for Upcoming<'_, S>
+where
+    S: FreeSlot,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            slots,
+            free,
+            policy,
+            capacity,
+            grown,
+        } = self;
+        let next = slots.len() + *grown;
+        let can_grow = next < *capacity;
+        match free.peek(*policy, can_grow) {
+            Some(index) => {
+                free.head = slots[index].next_free_index();
+                free.len -= 1;
+                Some(index)
+            }
+            None if can_grow => {
+                *grown += 1;
+                Some(next)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<S> FusedIterator for Upcoming<'_, S> where S: FreeSlot {}
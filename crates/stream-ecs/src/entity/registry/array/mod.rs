@@ -10,13 +10,45 @@ pub use self::dense::DenseArrayRegistry;
 pub mod basic;
 pub mod dense;
 
-/// The error type which is returned when array registry capacity was exceeded.
+mod free;
+
+/// The error type which is returned when array registry fails to create new entity.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayRegistryError {
+    /// Capacity of the array registry was exceeded.
+    #[display("array registry capacity exceeded")]
+    Capacity,
+    /// All the indices of the array registry were retired.
+    #[display("all indices of array registry were retired")]
+    Retired,
+}
+
+/// Policy of reusing indices of destroyed entities by array registries.
+///
+/// Each time an index is reused, new entity gets the next generation of the index,
+/// so policies which delay reusing of indices reduce both the chance of stale keys aliasing alive entities
+/// and the rate at which generations grow.
 ///
 /// # Examples
 ///
 /// ```
 /// todo!()
 /// ```
-#[derive(Debug, Display, Clone, Copy)]
-#[display("array registry capacity exceeded")]
-pub struct ArrayRegistryError;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RecyclePolicy {
+    /// The most recently freed index is reused first.
+    #[default]
+    Lifo,
+    /// The least recently freed index is reused first.
+    Fifo,
+    /// The least recently freed index is reused only after provided count of other indices were freed.
+    ///
+    /// Indices are reused earlier only if registry has no space left for new indices.
+    Delayed(usize),
+}
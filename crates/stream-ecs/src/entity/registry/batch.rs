@@ -0,0 +1,88 @@
+use core::iter::FusedIterator;
+
+use super::Registry;
+
+/// Iterator over entities created by the entity registry in a batch.
+///
+/// Entities are created while iterating, but all the remaining entities
+/// are created when the iterator is dropped, so the count of created entities
+/// is the same as provided to the [`create_many`](Registry::create_many()) method.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug)]
+pub struct CreateMany<'me, R>
+where
+    R: Registry + ?Sized,
+{
+    registry: &'me mut R,
+    num_left: usize,
+}
+
+impl<'me, R> CreateMany<'me, R>
+where
+    R: Registry + ?Sized,
+{
+    /// Creates new iterator which creates provided count of entities in the registry
+    /// one by one with [`create`](Registry::create()) method.
+    ///
+    /// This function does not check if the registry is able to create provided count of entities,
+    /// so the caller should check it before creating the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(registry: &'me mut R, count: usize) -> Self {
+        Self {
+            registry,
+            num_left: count,
+        }
+    }
+}
+
+impl<R> Iterator for CreateMany<'_, R>
+where
+    R: Registry + ?Sized,
+{
+    type Item = R::Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { registry, num_left } = self;
+        if *num_left == 0 {
+            return None;
+        }
+        *num_left -= 1;
+        let entity = registry.create();
+        Some(entity)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.num_left;
+        (len, Some(len))
+    }
+}
+
+impl<R> ExactSizeIterator for CreateMany<'_, R>
+where
+    R: Registry + ?Sized,
+{
+    fn len(&self) -> usize {
+        self.num_left
+    }
+}
+
+impl<R> FusedIterator for CreateMany<'_, R> where R: Registry + ?Sized {}
+
+impl<R> Drop for CreateMany<'_, R>
+where
+    R: Registry + ?Sized,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
@@ -1,11 +1,14 @@
 //! Utilities for entity management.
 
-pub use self::error::NotPresentError;
+use core::iter;
+
+pub use self::{batch::CreateMany, error::NotPresentError};
 
 use super::Entity;
 
 pub mod array;
 
+mod batch;
 mod error;
 
 /// Entity registry of the world.
@@ -35,6 +38,21 @@ pub trait Registry {
     /// ```
     fn create(&mut self) -> Self::Entity;
 
+    /// Creates provided count of new entities which are registered in the registry.
+    /// Returns an iterator over newly created entities.
+    ///
+    /// All the entities are created even if returned iterator was not consumed.
+    /// Registries create entities [one by one](CreateMany) by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn create_many(&mut self, count: usize) -> impl ExactSizeIterator<Item = Self::Entity> {
+        CreateMany::new(self, count)
+    }
+
     /// Checks if the registry contains provided entity.
     ///
     /// # Examples
@@ -60,6 +78,26 @@ pub trait Registry {
     /// Note that provided entity will be removed from the registry.
     fn destroy(&mut self, entity: Self::Entity) -> Result<(), NotPresentError<Self::Entity>>;
 
+    /// Destroys all provided entities which were previously created in the registry.
+    /// Returns count of destroyed entities.
+    ///
+    /// Entities which were destroyed earlier or were not created in the registry are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn destroy_many<I>(&mut self, entities: I) -> usize
+    where
+        I: IntoIterator<Item = Self::Entity>,
+    {
+        entities
+            .into_iter()
+            .filter(|&entity| self.destroy(entity).is_ok())
+            .count()
+    }
+
     /// Returns count of currently alive entities.
     ///
     /// # Examples
@@ -89,6 +127,57 @@ pub trait Registry {
     /// ```
     fn clear(&mut self);
 
+    /// Returns index of the entity which will be created next by the registry,
+    /// or [`None`] if the registry cannot predict it (for example, if the registry is full).
+    ///
+    /// This method is only a hint: registry may not create an entity with returned index
+    /// if the registry is modified in between.
+    /// Registries do not predict the index of the next entity by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn next_index_hint(&self) -> Option<<Self::Entity as Entity>::Index> {
+        None
+    }
+
+    /// Returns an iterator over provided count of entities which will be created next by the registry,
+    /// or [`None`] if the registry cannot predict them (for example, if the registry cannot create so many entities).
+    ///
+    /// This method is only a hint: registry may not create returned entities
+    /// if the registry is modified in between.
+    /// Registries do not predict entities which will be created next by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn next_entities_hint(
+        &self,
+        count: usize,
+    ) -> Option<impl Iterator<Item = Self::Entity> + Clone> {
+        let _ = count;
+        None::<iter::Empty<Self::Entity>>
+    }
+
+    /// Returns count of indices which were retired by the registry.
+    ///
+    /// Index is retired permanently when the [generation](Entity::Generation) of the destroyed entity
+    /// cannot be increased anymore, so entities with such index will never be created again.
+    /// Registries do not retire indices by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn retired_count(&self) -> usize {
+        0
+    }
+
     /// Type of iterator of alive entities created by the registry.
     type Iter<'me>: Iterator<Item = Self::Entity>
     where
@@ -123,7 +212,8 @@ pub trait TryRegistry: Registry {
     /// # Errors
     ///
     /// This function will return an error if the registry will fail to create new entity.
-    /// Conditions of failure are provided by implementation of the registry.
+    /// Conditions of failure are provided by implementation of the registry,
+    /// but registry should fail if all of its indices were [retired](Registry::retired_count()).
     ///
     /// # Examples
     ///
@@ -133,4 +223,127 @@ pub trait TryRegistry: Registry {
     ///
     /// This is the fallible version of [`create`][Registry::create()] method.
     fn try_create(&mut self) -> Result<Self::Entity, Self::Err>;
+
+    /// Checks if the registry is able to create provided count of new entities
+    /// without creating any of them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the registry will fail to create all the entities.
+    /// Registries are not bounded by default, so this method never fails by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn check_capacity(&self, count: usize) -> Result<(), Self::Err> {
+        let _ = count;
+        Ok(())
+    }
+
+    /// Tries to create provided count of new entities which are registered in the registry.
+    /// Returns an iterator over newly created entities.
+    ///
+    /// All the entities are created even if returned iterator was not consumed.
+    /// By default, [capacity](TryRegistry::check_capacity()) of the registry is checked first,
+    /// and then entities are created one by one with [`create`](Registry::create()) method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the registry will fail to create all the entities.
+    /// No entities are created in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`create_many`][Registry::create_many()] method.
+    fn try_create_many(
+        &mut self,
+        count: usize,
+    ) -> Result<impl ExactSizeIterator<Item = Self::Entity>, Self::Err> {
+        self.check_capacity(count)?;
+        let entities = CreateMany::new(self, count);
+        Ok(entities)
+    }
+}
+
+/// Returns an iterator over provided count of entities which will be created next by the registry.
+///
+/// If the registry cannot [predict](Registry::next_entities_hint()) them,
+/// the iterator returns null keys instead, which stand for the worst case:
+/// null key has no component attached and, for the [default entity](super::DefaultEntity), the largest index,
+/// so storages which place components by the index of the entity reject it,
+/// and other storages require a free slot for each of them.
+pub(crate) fn next_entities_or_null<R>(
+    registry: &R,
+    count: usize,
+) -> impl Iterator<Item = R::Entity> + Clone + '_
+where
+    R: Registry + ?Sized,
+{
+    let hint = registry.next_entities_hint(count);
+    let null_count = if hint.is_some() { 0 } else { count };
+    let nulls = iter::repeat_n(R::Entity::null(), null_count);
+    hint.into_iter().flatten().chain(nulls)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::entity::{
+        DefaultEntity,
+        registry::{
+            NotPresentError, Registry, TryRegistry,
+            array::{ArrayRegistry, ArrayRegistryError, basic},
+        },
+    };
+
+    /// Array registry which cannot predict entities which will be created next.
+    #[derive(Debug, Default)]
+    pub(crate) struct NoHintRegistry<const N: usize>(ArrayRegistry<N>);
+
+    impl<const N: usize> Registry for NoHintRegistry<N> {
+        type Entity = DefaultEntity;
+
+        fn create(&mut self) -> Self::Entity {
+            self.0.create()
+        }
+
+        fn contains(&self, entity: Self::Entity) -> bool {
+            self.0.contains(entity)
+        }
+
+        fn destroy(&mut self, entity: Self::Entity) -> Result<(), NotPresentError<Self::Entity>> {
+            self.0.destroy(entity)
+        }
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn clear(&mut self) {
+            self.0.clear()
+        }
+
+        type Iter<'me> = basic::Iter<'me, Self::Entity>;
+
+        fn iter(&self) -> Self::Iter<'_> {
+            self.0.iter()
+        }
+    }
+
+    impl<const N: usize> TryRegistry for NoHintRegistry<N> {
+        type Err = ArrayRegistryError;
+
+        fn try_create(&mut self) -> Result<Self::Entity, Self::Err> {
+            self.0.try_create()
+        }
+
+        fn check_capacity(&self, count: usize) -> Result<(), Self::Err> {
+            self.0.check_capacity(count)
+        }
+    }
 }
@@ -0,0 +1,125 @@
+use crate::{
+    component::{Component, storage::array::ArrayStorage},
+    entity::{DefaultEntity, Entity},
+};
+
+/// Type of storage of the [`Parent`] component.
+pub type ParentStorage<const N: usize, E = DefaultEntity> = ArrayStorage<Parent<N, E>, N, E>;
+
+/// Type of storage of the [`Children`] component.
+pub type ChildrenStorage<const N: usize, E = DefaultEntity> = ArrayStorage<Children<N, E>, N, E>;
+
+/// Component which is attached to the child entity and points to its parent entity.
+///
+/// It can be attached to exactly `N` entities of the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parent<const N: usize, E = DefaultEntity>
+where
+    E: Entity,
+{
+    pub(super) parent: E,
+    pub(super) prev_sibling: Option<E>,
+    pub(super) next_sibling: Option<E>,
+}
+
+impl<E, const N: usize> Parent<N, E>
+where
+    E: Entity,
+{
+    /// Returns the parent entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self) -> E {
+        self.parent
+    }
+}
+
+impl<E, const N: usize> Component for Parent<N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Storage = ParentStorage<N, E>;
+}
+
+/// Component which is attached to the parent entity and points to its children.
+///
+/// It can be attached to exactly `N` entities of the world.
+/// Parent entity always has at least one child, so this component is removed
+/// when the last child of the entity is removed.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Children<const N: usize, E = DefaultEntity>
+where
+    E: Entity,
+{
+    pub(super) first: E,
+    pub(super) last: E,
+    pub(super) len: usize,
+}
+
+impl<E, const N: usize> Children<N, E>
+where
+    E: Entity,
+{
+    /// Returns the first child of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn first(&self) -> E {
+        self.first
+    }
+
+    /// Returns the last child of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn last(&self) -> E {
+        self.last
+    }
+
+    /// Returns count of children of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<E, const N: usize> Component for Children<N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Storage = ChildrenStorage<N, E>;
+}
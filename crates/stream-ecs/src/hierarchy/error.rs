@@ -0,0 +1,101 @@
+use core::fmt::Display;
+
+use derive_more::{Display, From};
+
+use crate::{
+    component::{bundle::NotRegisteredError, storage::array::ArrayStorageError},
+    entity::{DefaultEntity, Entity, registry::NotPresentError},
+    world::EntityError,
+};
+
+/// The error type which is returned when setting a parent of the entity
+/// would create a cycle in the hierarchy.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(bound(E: Display))]
+#[display("entity {parent} cannot be a parent of entity {child} because it would create a cycle")]
+pub struct CycleError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    child: E,
+    parent: E,
+}
+
+impl<E> CycleError<E>
+where
+    E: Entity,
+{
+    /// Creates new error when setting a parent of the entity would create a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(child: E, parent: E) -> Self {
+        Self { child, parent }
+    }
+
+    /// Returns the entity which parent was tried to be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn child(self) -> E {
+        self.child
+    }
+
+    /// Returns the entity which was tried to be set as a parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn parent(self) -> E {
+        self.parent
+    }
+}
+
+/// The error type which is returned when changing the hierarchy of the ECS world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(E: Display))]
+pub enum HierarchyError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    /// Hierarchy components were not registered in the world.
+    NotRegistered(NotRegisteredError),
+    /// Entity was not present in the world.
+    NotPresent(NotPresentError<E>),
+    /// Hierarchy would contain a cycle.
+    Cycle(CycleError<E>),
+    /// Hierarchy cannot store any more entities.
+    Capacity(ArrayStorageError),
+}
+
+impl<E> From<EntityError<E>> for HierarchyError<E>
+where
+    E: Entity,
+{
+    fn from(error: EntityError<E>) -> Self {
+        match error {
+            EntityError::NotRegistered(error) => Self::NotRegistered(error),
+            EntityError::NotPresent(error) => Self::NotPresent(error),
+        }
+    }
+}
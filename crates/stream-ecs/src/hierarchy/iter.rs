@@ -0,0 +1,264 @@
+use core::iter::FusedIterator;
+
+use arrayvec::ArrayVec;
+
+use crate::entity::Entity;
+
+use super::component::{Children, ChildrenStorage, Parent, ParentStorage};
+
+/// Iterator over children of the entity.
+///
+/// This struct is created by [`children`](crate::world::World::children()) method of the world.
+pub struct ChildrenIter<'data, const N: usize, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    parents: &'data ParentStorage<N, E>,
+    next: Option<E>,
+    len: usize,
+}
+
+impl<'data, E, const N: usize> ChildrenIter<'data, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    pub(crate) fn new(
+        parents: &'data ParentStorage<N, E>,
+        children: &'data ChildrenStorage<N, E>,
+        entity: E,
+    ) -> Self {
+        let (next, len) = match children.get(entity) {
+            Some(&Children { first, len, .. }) => (Some(first), len),
+            None => (None, 0),
+        };
+        Self { parents, next, len }
+    }
+}
+
+impl<E, const N: usize> Iterator for ChildrenIter<'_, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let &Parent { next_sibling, .. } = self
+            .parents
+            .get(current)
+            .expect("child should have a parent");
+        self.next = next_sibling;
+        self.len -= 1;
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<E, const N: usize> ExactSizeIterator for ChildrenIter<'_, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<E, const N: usize> FusedIterator for ChildrenIter<'_, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+}
+
+/// Iterator over descendants of the entity in depth-first order.
+///
+/// This struct is created by [`descendants_depth_first`](crate::world::World::descendants_depth_first())
+/// method of the world.
+pub struct DepthFirstIter<'data, const N: usize, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    parents: &'data ParentStorage<N, E>,
+    children: &'data ChildrenStorage<N, E>,
+    root: E,
+    next: Option<E>,
+}
+
+impl<'data, E, const N: usize> DepthFirstIter<'data, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    pub(crate) fn new(
+        parents: &'data ParentStorage<N, E>,
+        children: &'data ChildrenStorage<N, E>,
+        root: E,
+    ) -> Self {
+        let next = children.get(root).map(Children::first);
+        Self {
+            parents,
+            children,
+            root,
+            next,
+        }
+    }
+}
+
+impl<E, const N: usize> Iterator for DepthFirstIter<'_, N, E>
+where
+    E: Entity + PartialEq,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            parents,
+            children,
+            root,
+            ref mut next,
+        } = *self;
+
+        let current = (*next)?;
+        if let Some(&Children { first, .. }) = children.get(current) {
+            *next = Some(first);
+            return Some(current);
+        }
+
+        let mut node = current;
+        *next = loop {
+            let &Parent {
+                parent,
+                next_sibling,
+                ..
+            } = parents.get(node).expect("descendant should have a parent");
+            if next_sibling.is_some() {
+                break next_sibling;
+            }
+            if parent == root {
+                break None;
+            }
+            node = parent;
+        };
+        Some(current)
+    }
+}
+
+impl<E, const N: usize> FusedIterator for DepthFirstIter<'_, N, E>
+where
+    E: Entity + PartialEq,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+}
+
+/// Iterator over descendants of the entity in breadth-first order.
+///
+/// This struct is created by [`descendants_breadth_first`](crate::world::World::descendants_breadth_first())
+/// method of the world.
+pub struct BreadthFirstIter<'data, const N: usize, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    parents: &'data ParentStorage<N, E>,
+    children: &'data ChildrenStorage<N, E>,
+    queue: ArrayVec<E, N>,
+    head: usize,
+}
+
+impl<'data, E, const N: usize> BreadthFirstIter<'data, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    pub(crate) fn new(
+        parents: &'data ParentStorage<N, E>,
+        children: &'data ChildrenStorage<N, E>,
+        root: E,
+    ) -> Self {
+        let mut iter = Self {
+            parents,
+            children,
+            queue: ArrayVec::new(),
+            head: 0,
+        };
+        iter.enqueue_children(root);
+        iter
+    }
+
+    fn enqueue_children(&mut self, entity: E) {
+        let Self {
+            parents,
+            children,
+            ref mut queue,
+            ..
+        } = *self;
+
+        // each descendant has a parent component attached,
+        // so the queue cannot contain more than `N` entities
+        let iter = ChildrenIter::new(parents, children, entity);
+        queue.extend(iter);
+    }
+}
+
+impl<E, const N: usize> Iterator for BreadthFirstIter<'_, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = *self.queue.get(self.head)?;
+        self.head += 1;
+        self.enqueue_children(current);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.queue.len() - self.head;
+        (len, Some(N - self.head))
+    }
+}
+
+impl<E, const N: usize> FusedIterator for BreadthFirstIter<'_, N, E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+}
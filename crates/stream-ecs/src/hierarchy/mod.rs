@@ -0,0 +1,131 @@
+//! Provides utilities for hierarchies of entities in ECS.
+//!
+//! Hierarchy is represented by [`Parent`] and [`Children`] components:
+//! each child entity has a [`Parent`] component attached,
+//! and each parent entity has a [`Children`] component attached.
+//! Children of the same parent are linked with each other,
+//! so hierarchy does not use heap allocation at all.
+//!
+//! Hierarchy of the [world](crate::world::World) can be changed by
+//! [`set_parent`](crate::world::World::set_parent()) and
+//! [`remove_parent`](crate::world::World::remove_parent()) methods of the world.
+
+pub use self::{
+    component::{Children, ChildrenStorage, Parent, ParentStorage},
+    error::{CycleError, HierarchyError},
+    iter::{BreadthFirstIter, ChildrenIter, DepthFirstIter},
+};
+
+pub(crate) use self::tree::{remove_parent, storages, storages_mut, try_set_parent};
+
+mod component;
+mod error;
+mod iter;
+mod tree;
+
+#[cfg(test)]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        entity::{DefaultEntity, registry::array::ArrayRegistry},
+        world::World,
+    };
+
+    use super::{ChildrenStorage, HierarchyError, ParentStorage};
+
+    type Components = HList![ParentStorage<5>, ChildrenStorage<5>];
+
+    fn world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![ParentStorage::new(), ChildrenStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    fn children(
+        world: &World<ArrayRegistry<10>, Components, ()>,
+        entity: DefaultEntity,
+    ) -> Vec<DefaultEntity> {
+        world.children::<5>(entity).unwrap().collect()
+    }
+
+    #[test]
+    fn set_parent() {
+        let mut world = world();
+        let [first_root, second_root, a, b, c] = [(); 5].map(|()| world.create());
+        for child in [a, b, c] {
+            assert_eq!(world.set_parent::<5>(child, first_root).unwrap(), None);
+        }
+        assert_eq!(children(&world, first_root), [a, b, c]);
+
+        assert_eq!(
+            world.set_parent::<5>(b, second_root).unwrap(),
+            Some(first_root)
+        );
+        assert_eq!(children(&world, first_root), [a, c]);
+        assert_eq!(children(&world, second_root), [b]);
+        assert_eq!(world.parent::<5>(b).unwrap(), Some(second_root));
+
+        assert_eq!(
+            world.set_parent::<5>(a, second_root).unwrap(),
+            Some(first_root)
+        );
+        assert_eq!(
+            world.set_parent::<5>(c, second_root).unwrap(),
+            Some(first_root)
+        );
+        assert!(children(&world, first_root).is_empty());
+        assert_eq!(children(&world, second_root), [b, a, c]);
+
+        assert!(matches!(
+            world.set_parent::<5>(second_root, a),
+            Err(HierarchyError::Cycle(_)),
+        ));
+        assert_eq!(world.parent::<5>(second_root).unwrap(), None);
+    }
+
+    #[test]
+    fn try_set_parent_capacity() {
+        let mut world = world();
+        let entities = [(); 6].map(|()| world.create());
+        let (root, last) = (entities[0], entities[5]);
+
+        assert!(matches!(
+            world.try_set_parent::<5>(last, root),
+            Err(HierarchyError::Capacity(_)),
+        ));
+        assert!(matches!(
+            world.try_set_parent::<5>(root, last),
+            Err(HierarchyError::Capacity(_)),
+        ));
+        assert!(children(&world, root).is_empty());
+        assert_eq!(world.parent::<5>(last).unwrap(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_parent_capacity() {
+        let mut world = world();
+        let entities = [(); 6].map(|()| world.create());
+        let _ = world.set_parent::<5>(entities[5], entities[0]);
+    }
+
+    #[test]
+    fn destroy_recursive() {
+        let mut world = world();
+        let [top, root, sibling, child, grandchild] = [(); 5].map(|()| world.create());
+        let _ = world.set_parent::<5>(root, top).unwrap();
+        let _ = world.set_parent::<5>(sibling, top).unwrap();
+        let _ = world.set_parent::<5>(child, root).unwrap();
+        let _ = world.set_parent::<5>(grandchild, child).unwrap();
+
+        world.destroy_recursive::<5>(root).unwrap();
+        for entity in [root, child, grandchild] {
+            assert!(!world.entities().contains(entity));
+        }
+        assert!(world.entities().contains(top));
+        assert!(world.entities().contains(sibling));
+        assert_eq!(children(&world, top), [sibling]);
+        assert_eq!(world.parent::<5>(sibling).unwrap(), Some(top));
+        assert_eq!(world.entities().len(), 2);
+    }
+}
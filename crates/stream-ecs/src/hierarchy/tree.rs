@@ -0,0 +1,311 @@
+use core::any::Any;
+
+use crate::{
+    component::{
+        bundle::NotRegisteredError, registry::Registry as Components,
+        storage::array::ArrayStorageError,
+    },
+    entity::Entity,
+};
+
+use super::{
+    component::{Children, ChildrenStorage, Parent, ParentStorage},
+    error::{CycleError, HierarchyError},
+};
+
+/// Retrieves references to the storages of hierarchy components from the component registry.
+pub fn storages<C, E, const N: usize>(
+    components: &C,
+) -> Result<(&ParentStorage<N, E>, &ChildrenStorage<N, E>), NotRegisteredError>
+where
+    C: Components,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    let Some(parents) = components.get::<Parent<N, E>>() else {
+        return Err(NotRegisteredError::new::<Parent<N, E>>());
+    };
+    let Some(children) = components.get::<Children<N, E>>() else {
+        return Err(NotRegisteredError::new::<Children<N, E>>());
+    };
+    Ok((parents, children))
+}
+
+/// Retrieves mutable references to the storages of hierarchy components from the component registry.
+pub fn storages_mut<C, E, const N: usize>(
+    components: &mut C,
+) -> Result<(&mut ParentStorage<N, E>, &mut ChildrenStorage<N, E>), NotRegisteredError>
+where
+    C: Components,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    let mut parents = None;
+    let mut children = None;
+    for storage in components.iter_mut() {
+        let storage = storage as &mut dyn Any;
+        if storage.is::<ParentStorage<N, E>>() {
+            parents = storage.downcast_mut();
+        } else if storage.is::<ChildrenStorage<N, E>>() {
+            children = storage.downcast_mut();
+        }
+    }
+    let Some(parents) = parents else {
+        return Err(NotRegisteredError::new::<Parent<N, E>>());
+    };
+    let Some(children) = children else {
+        return Err(NotRegisteredError::new::<Children<N, E>>());
+    };
+    Ok((parents, children))
+}
+
+/// Sets the parent of the child entity, appending the child to the end of children list of the parent.
+/// Returns previous parent of the child entity, if any.
+pub fn set_parent<E, const N: usize>(
+    parents: &mut ParentStorage<N, E>,
+    children: &mut ChildrenStorage<N, E>,
+    child: E,
+    parent: E,
+) -> Result<Option<E>, CycleError<E>>
+where
+    E: Entity + PartialEq,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    if child == parent {
+        return Err(CycleError::new(child, parent));
+    }
+    let mut ancestor = parent;
+    while let Some(&Parent { parent: next, .. }) = parents.get(ancestor) {
+        if next == child {
+            return Err(CycleError::new(child, parent));
+        }
+        ancestor = next;
+    }
+
+    let previous = remove_parent(parents, children, child);
+    let prev_sibling = match children.get_mut(parent) {
+        Some(list) => {
+            let last = list.last;
+            list.last = child;
+            list.len += 1;
+            let last_parent = parents
+                .get_mut(last)
+                .expect("last child should have a parent");
+            last_parent.next_sibling = Some(child);
+            Some(last)
+        }
+        None => {
+            let list = Children {
+                first: child,
+                last: child,
+                len: 1,
+            };
+            let _ = children.attach(parent, list);
+            None
+        }
+    };
+    let component = Parent {
+        parent,
+        prev_sibling,
+        next_sibling: None,
+    };
+    let _ = parents.attach(child, component);
+    Ok(previous)
+}
+
+/// Tries to set the parent of the child entity, appending the child to the end of children list of the parent.
+/// Returns previous parent of the child entity, if any.
+///
+/// Capacity of the hierarchy storages is checked before the hierarchy is changed.
+pub fn try_set_parent<E, const N: usize>(
+    parents: &mut ParentStorage<N, E>,
+    children: &mut ChildrenStorage<N, E>,
+    child: E,
+    parent: E,
+) -> Result<Option<E>, HierarchyError<E>>
+where
+    E: Entity + PartialEq,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    for (entity, capacity) in [(child, parents.capacity()), (parent, children.capacity())] {
+        let fits = usize::try_from(entity.index()).is_ok_and(|index| index < capacity);
+        if !fits {
+            return Err(ArrayStorageError.into());
+        }
+    }
+    let previous = set_parent(parents, children, child, parent)?;
+    Ok(previous)
+}
+
+/// Removes the child entity from children list of its parent.
+/// Returns previous parent of the child entity, if any.
+pub fn remove_parent<E, const N: usize>(
+    parents: &mut ParentStorage<N, E>,
+    children: &mut ChildrenStorage<N, E>,
+    child: E,
+) -> Option<E>
+where
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    let Parent {
+        parent,
+        prev_sibling,
+        next_sibling,
+    } = parents.remove(child)?;
+
+    if let Some(prev) = prev_sibling {
+        let prev = parents.get_mut(prev).expect("sibling should have a parent");
+        prev.next_sibling = next_sibling;
+    }
+    if let Some(next) = next_sibling {
+        let next = parents.get_mut(next).expect("sibling should have a parent");
+        next.prev_sibling = prev_sibling;
+    }
+
+    let list = children
+        .get_mut(parent)
+        .expect("parent should have children");
+    if list.len == 1 {
+        let _ = children.remove(parent);
+        return Some(parent);
+    }
+    list.len -= 1;
+    match (prev_sibling, next_sibling) {
+        (None, Some(next)) => list.first = next,
+        (Some(prev), None) => list.last = prev,
+        _ => {}
+    }
+    Some(parent)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entity::DefaultEntity as Entity;
+
+    use super::{super::iter::*, *};
+
+    type Storages = (ParentStorage<10>, ChildrenStorage<10>);
+
+    fn storages() -> Storages {
+        (ParentStorage::new(), ChildrenStorage::new())
+    }
+
+    #[test]
+    fn set_parent() {
+        let (mut parents, mut children) = storages();
+        let root = Entity::new(0, 0);
+        let first = Entity::new(1, 0);
+        let second = Entity::new(2, 0);
+
+        assert_eq!(
+            super::set_parent(&mut parents, &mut children, first, root).unwrap(),
+            None
+        );
+        assert_eq!(
+            super::set_parent(&mut parents, &mut children, second, root).unwrap(),
+            None
+        );
+        assert_eq!(parents.get(first).map(Parent::get), Some(root));
+        assert_eq!(children.get(root).map(Children::len), Some(2));
+
+        let iter = ChildrenIter::new(&parents, &children, root);
+        assert!(iter.eq([first, second]));
+    }
+
+    #[test]
+    fn remove_parent() {
+        let (mut parents, mut children) = storages();
+        let root = Entity::new(0, 0);
+        let entities = [1, 2, 3].map(|index| Entity::new(index, 0));
+        for entity in entities {
+            let _ = super::set_parent(&mut parents, &mut children, entity, root);
+        }
+
+        assert_eq!(
+            super::remove_parent(&mut parents, &mut children, entities[1]),
+            Some(root)
+        );
+        let iter = ChildrenIter::new(&parents, &children, root);
+        assert!(iter.eq([entities[0], entities[2]]));
+
+        assert_eq!(
+            super::remove_parent(&mut parents, &mut children, entities[0]),
+            Some(root)
+        );
+        assert_eq!(
+            super::remove_parent(&mut parents, &mut children, entities[2]),
+            Some(root)
+        );
+        assert_eq!(
+            super::remove_parent(&mut parents, &mut children, entities[2]),
+            None
+        );
+        assert!(parents.is_empty());
+        assert!(children.is_empty());
+    }
+
+    #[test]
+    fn cycle() {
+        let (mut parents, mut children) = storages();
+        let root = Entity::new(0, 0);
+        let child = Entity::new(1, 0);
+        let grandchild = Entity::new(2, 0);
+
+        let _ = super::set_parent(&mut parents, &mut children, child, root);
+        let _ = super::set_parent(&mut parents, &mut children, grandchild, child);
+        assert!(super::set_parent(&mut parents, &mut children, root, grandchild).is_err());
+        assert!(super::set_parent(&mut parents, &mut children, root, root).is_err());
+        assert!(super::set_parent(&mut parents, &mut children, grandchild, root).is_ok());
+    }
+
+    #[test]
+    fn capacity() {
+        let (mut parents, mut children) = storages();
+        let root = Entity::new(0, 0);
+        let child = Entity::new(1, 0);
+        let outside = Entity::new(10, 0);
+
+        let result = super::try_set_parent(&mut parents, &mut children, outside, root);
+        assert!(matches!(result, Err(HierarchyError::Capacity(_))));
+        let result = super::try_set_parent(&mut parents, &mut children, child, outside);
+        assert!(matches!(result, Err(HierarchyError::Capacity(_))));
+        assert!(parents.is_empty());
+        assert!(children.is_empty());
+
+        let result = super::try_set_parent(&mut parents, &mut children, child, root);
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[test]
+    fn descendants() {
+        let (mut parents, mut children) = storages();
+        let [root, a, b, c, d, e] = [0, 1, 2, 3, 4, 5].map(|index| Entity::new(index, 0));
+        // root -> (a -> (c, d), b -> e)
+        for (child, parent) in [(a, root), (b, root), (c, a), (d, a), (e, b)] {
+            let _ = super::set_parent(&mut parents, &mut children, child, parent);
+        }
+
+        let iter = DepthFirstIter::new(&parents, &children, root);
+        assert!(iter.eq([a, c, d, b, e]));
+
+        let iter = BreadthFirstIter::new(&parents, &children, root);
+        assert!(iter.eq([a, b, c, d, e]));
+
+        let iter = DepthFirstIter::new(&parents, &children, a);
+        assert!(iter.eq([c, d]));
+
+        let mut iter = DepthFirstIter::new(&parents, &children, e);
+        assert_eq!(iter.next(), None);
+    }
+}
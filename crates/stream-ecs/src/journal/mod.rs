@@ -0,0 +1,13 @@
+//! Provides utilities for journaling mutations of ECS worlds.
+//!
+//! [Journaled world](JournaledWorld) wraps the [world](crate::world::World)
+//! and records an inverse of each mutation made through it,
+//! so these mutations can be undone and redone later (for example, in the level editor).
+//!
+//! Journal stores previous values of components and resources on the heap,
+//! so this module is only available with `alloc` feature enabled.
+
+pub use self::world::JournaledWorld;
+
+mod operation;
+mod world;
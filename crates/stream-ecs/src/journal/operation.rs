@@ -0,0 +1,165 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::any::Any;
+
+use crate::{
+    component::{
+        ErasedComponent, bundle::Bundle, registry::Registry as Components,
+        storage::bundle::Bundle as StorageBundle,
+    },
+    entity::registry::Registry as Entities,
+    resource::{bundle::Bundle as ResourceBundle, registry::RegistryMut as ResourcesMut},
+    world::World,
+};
+
+/// Mapping from entities recorded in the journal to the actual entities of the world.
+///
+/// Entity recreated by the journal gets new key from the entity registry,
+/// so all the operations recorded for the destroyed entity should be applied to the new one.
+pub struct Aliases<E> {
+    aliases: Vec<(E, E)>,
+}
+
+impl<E> Aliases<E>
+where
+    E: Copy + PartialEq,
+{
+    pub const fn new() -> Self {
+        let aliases = Vec::new();
+        Self { aliases }
+    }
+
+    pub fn resolve(&self, entity: E) -> E {
+        let Self { aliases } = self;
+        aliases
+            .iter()
+            .find(|&&(recorded, _)| recorded == entity)
+            .map_or(entity, |&(_, actual)| actual)
+    }
+
+    pub fn insert(&mut self, entity: E, actual: E) {
+        let Self { aliases } = self;
+        aliases.retain(|&(recorded, _)| recorded != entity);
+        if entity != actual {
+            aliases.push((entity, actual));
+        }
+    }
+
+    pub fn clear(&mut self) {
+        let Self { aliases } = self;
+        aliases.clear()
+    }
+}
+
+type Apply<E, C, R> = dyn FnOnce(
+    &mut World<E, C, R>,
+    &mut Aliases<<E as Entities>::Entity>,
+) -> Option<Operation<E, C, R>>;
+
+/// Operation recorded in the journal.
+///
+/// Applying the operation returns its inverse,
+/// or [`None`] if the operation cannot be applied to the current state of the world.
+pub struct Operation<E, C, R>
+where
+    E: Entities,
+{
+    apply: Box<Apply<E, C, R>>,
+}
+
+impl<E, C, R> Operation<E, C, R>
+where
+    E: Entities + 'static,
+    E::Entity: PartialEq,
+    C: Components + 'static,
+    R: 'static,
+{
+    fn new<F>(apply: F) -> Self
+    where
+        F: FnOnce(&mut World<E, C, R>, &mut Aliases<E::Entity>) -> Option<Self> + 'static,
+    {
+        let apply = Box::new(apply);
+        Self { apply }
+    }
+
+    pub fn apply(
+        self,
+        world: &mut World<E, C, R>,
+        aliases: &mut Aliases<E::Entity>,
+    ) -> Option<Self> {
+        let Self { apply } = self;
+        apply(world, aliases)
+    }
+
+    /// Destroys the entity, keeping copies of its components to attach them back later.
+    pub fn destroy(entity: E::Entity) -> Self {
+        Self::new(move |world, aliases| {
+            let actual = aliases.resolve(entity);
+            if !world.contains(actual) {
+                return None;
+            }
+
+            let mut components = Vec::new();
+            for storage in world.components_mut().iter_mut() {
+                let Ok(Some(component)) = storage.get(&actual) else {
+                    continue;
+                };
+                components.push(component.to_boxed());
+                let _ = storage.remove(&actual);
+            }
+            world.destroy(actual).ok()?;
+            Some(Self::recreate(entity, components))
+        })
+    }
+
+    /// Creates new entity in place of the destroyed one and attaches provided components to it.
+    pub fn recreate(entity: E::Entity, components: Vec<Box<dyn ErasedComponent>>) -> Self {
+        Self::new(move |world, aliases| {
+            let actual = world.create();
+            aliases.insert(entity, actual);
+
+            for component in components {
+                let type_id = (&*component as &dyn Any).type_id();
+                let storage = world
+                    .components_mut()
+                    .iter_mut()
+                    .find(|storage| storage.component_type_id() == type_id);
+                if let Some(storage) = storage {
+                    let _ = storage.attach(&actual, &*component);
+                }
+            }
+            Some(Self::destroy(entity))
+        })
+    }
+
+    /// Attaches provided bundle to the entity, or removes the bundle if [`None`] was provided.
+    pub fn replace<B>(entity: E::Entity, bundle: Option<B>) -> Self
+    where
+        B: Bundle,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        Self::new(move |world, aliases| {
+            let actual = aliases.resolve(entity);
+            let previous = match bundle {
+                Some(bundle) => world.attach(actual, bundle),
+                None => world.remove::<B>(actual),
+            };
+            let previous = previous.ok()?;
+            Some(Self::replace(entity, previous))
+        })
+    }
+
+    /// Inserts provided resource bundle, or removes the bundle if [`None`] was provided.
+    pub fn replace_res<B>(bundle: Option<B>) -> Self
+    where
+        R: ResourcesMut,
+        B: ResourceBundle,
+    {
+        Self::new(move |world, _| {
+            let previous = match bundle {
+                Some(bundle) => world.insert_res(bundle),
+                None => world.remove_res::<B>(),
+            };
+            Some(Self::replace_res(previous))
+        })
+    }
+}
@@ -0,0 +1,599 @@
+use alloc::{vec, vec::Vec};
+use core::{fmt, mem};
+
+use crate::{
+    component::{
+        bundle::Bundle, registry::Registry as Components, storage::bundle::Bundle as StorageBundle,
+    },
+    entity::registry::{NotPresentError, Registry as Entities},
+    resource::{bundle::Bundle as ResourceBundle, registry::RegistryMut as ResourcesMut},
+    world::{EntityError, World},
+};
+
+use super::operation::{Aliases, Operation};
+
+type Group<E, C, R> = Vec<Operation<E, C, R>>;
+
+/// ECS world which records an inverse of each mutation made through it,
+/// so these mutations can be [undone](JournaledWorld::undo()) and [redone](JournaledWorld::redo()) later.
+///
+/// Mutations made between [`begin_group`][JournaledWorld::begin_group()]
+/// and [`end_group`][JournaledWorld::end_group()] calls are undone and redone together.
+///
+/// Entity destroyed by the journal is created again with the new key when undoing,
+/// so the key of such entity can be [resolved](JournaledWorld::resolve()) into the actual key of the world.
+/// Methods of the journaled world resolve provided entities automatically.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub struct JournaledWorld<E, C, R>
+where
+    E: Entities,
+{
+    world: World<E, C, R>,
+    aliases: Aliases<E::Entity>,
+    undo: Vec<Group<E, C, R>>,
+    redo: Vec<Group<E, C, R>>,
+    group: Group<E, C, R>,
+    depth: usize,
+}
+
+impl<E, C, R> JournaledWorld<E, C, R>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+{
+    /// Creates new journaled world from provided world with empty history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(world: World<E, C, R>) -> Self {
+        Self {
+            world,
+            aliases: Aliases::new(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            group: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn world(&self) -> &World<E, C, R> {
+        let Self { world, .. } = self;
+        world
+    }
+
+    /// Returns a mutable reference to the underlying world.
+    ///
+    /// Note that mutations made through this reference are not recorded in the journal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn world_mut(&mut self) -> &mut World<E, C, R> {
+        let Self { world, .. } = self;
+        world
+    }
+
+    /// Returns the underlying world, dropping the history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn into_inner(self) -> World<E, C, R> {
+        let Self { world, .. } = self;
+        world
+    }
+
+    /// Resolves the key of the entity recorded in the journal into the actual key of the world.
+    ///
+    /// Key of the entity changes when the journal creates the destroyed entity again.
+    /// Returns provided entity if its key was not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn resolve(&self, entity: E::Entity) -> E::Entity {
+        let Self { aliases, .. } = self;
+        aliases.resolve(entity)
+    }
+
+    /// Checks if there are any mutations which can be undone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn can_undo(&self) -> bool {
+        let Self { undo, group, .. } = self;
+        !undo.is_empty() || !group.is_empty()
+    }
+
+    /// Checks if there are any mutations which can be redone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn can_redo(&self) -> bool {
+        let Self { redo, .. } = self;
+        !redo.is_empty()
+    }
+
+    /// Begins new group of mutations which will be undone and redone together.
+    ///
+    /// Groups can be nested: mutations are grouped until the outermost group ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn begin_group(&mut self) {
+        let Self { depth, .. } = self;
+        *depth += 1;
+    }
+
+    /// Ends the group of mutations which was begun earlier.
+    /// Returns `false` if there was no group to end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn end_group(&mut self) -> bool {
+        let Self {
+            undo, group, depth, ..
+        } = self;
+
+        if *depth == 0 {
+            return false;
+        }
+        *depth -= 1;
+        if *depth == 0 && !group.is_empty() {
+            let group = mem::take(group);
+            undo.push(group);
+        }
+        true
+    }
+
+    /// Clears the history of the journal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn clear_history(&mut self) {
+        let Self {
+            aliases,
+            undo,
+            redo,
+            group,
+            depth,
+            ..
+        } = self;
+
+        aliases.clear();
+        undo.clear();
+        redo.clear();
+        group.clear();
+        *depth = 0;
+    }
+
+    fn record(&mut self, operation: Operation<E, C, R>) {
+        let Self {
+            undo,
+            redo,
+            group,
+            depth,
+            ..
+        } = self;
+
+        redo.clear();
+        if *depth == 0 {
+            undo.push(vec![operation]);
+        } else {
+            group.push(operation);
+        }
+    }
+}
+
+impl<E, C, R> JournaledWorld<E, C, R>
+where
+    E: Entities + 'static,
+    E::Entity: PartialEq,
+    C: Components + 'static,
+    R: 'static,
+{
+    /// Undoes the last mutation (or the last group of mutations) recorded in the journal.
+    /// Returns `false` if there was nothing to undo.
+    ///
+    /// All the groups which were not ended yet are ended before undoing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        while self.end_group() {}
+
+        let Self {
+            world,
+            aliases,
+            undo,
+            redo,
+            ..
+        } = self;
+
+        let Some(group) = undo.pop() else {
+            return false;
+        };
+        let group = apply(world, aliases, group);
+        redo.push(group);
+        true
+    }
+
+    /// Redoes the last mutation (or the last group of mutations) undone earlier.
+    /// Returns `false` if there was nothing to redo.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn redo(&mut self) -> bool {
+        let Self {
+            world,
+            aliases,
+            undo,
+            redo,
+            ..
+        } = self;
+
+        let Some(group) = redo.pop() else {
+            return false;
+        };
+        let group = apply(world, aliases, group);
+        undo.push(group);
+        true
+    }
+
+    /// Creates new empty entity in the world, recording its destruction in the journal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn create(&mut self) -> E::Entity {
+        let Self { world, .. } = self;
+
+        let entity = world.create();
+        self.record(Operation::destroy(entity));
+        entity
+    }
+
+    /// Destroys entity in the world, recording its creation in the journal.
+    ///
+    /// Components attached to the entity are removed from the world and kept in the journal,
+    /// so they are attached back when the entity is created again.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn destroy(&mut self, entity: E::Entity) -> Result<(), NotPresentError<E::Entity>> {
+        let Self { world, aliases, .. } = self;
+
+        let Some(inverse) = Operation::destroy(entity).apply(world, aliases) else {
+            return Err(NotPresentError::new(entity));
+        };
+        self.record(inverse);
+        Ok(())
+    }
+
+    /// Attaches provided bundle to the entity in the world, recording previous bundle data in the journal.
+    ///
+    /// Returns previous bundle data attached to the entity earlier.
+    /// Returns [`None`] if there was no bundle attached to the entity or some of bundle components are missing.
+    /// In the latter case undoing removes the whole bundle from the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or one of bundle components was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn attach<B>(
+        &mut self,
+        entity: E::Entity,
+        bundle: B,
+    ) -> Result<Option<B>, EntityError<E::Entity>>
+    where
+        B: Bundle + Clone,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        let Self { world, aliases, .. } = self;
+
+        let previous = world.attach(aliases.resolve(entity), bundle)?;
+        self.record(Operation::replace(entity, previous.clone()));
+        Ok(previous)
+    }
+
+    /// Removes components of the bundle from the entity in the world,
+    /// recording removed bundle data in the journal.
+    ///
+    /// Returns previous bundle data attached to the entity earlier.
+    /// Returns [`None`] if there was no bundle attached to the entity or some of bundle components are missing.
+    /// In the latter case undoing does not attach anything back to the entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or one of bundle components was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove<B>(&mut self, entity: E::Entity) -> Result<Option<B>, EntityError<E::Entity>>
+    where
+        B: Bundle + Clone,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        let Self { world, aliases, .. } = self;
+
+        let previous = world.remove::<B>(aliases.resolve(entity))?;
+        self.record(Operation::replace(entity, previous.clone()));
+        Ok(previous)
+    }
+}
+
+impl<E, C, R> JournaledWorld<E, C, R>
+where
+    E: Entities + 'static,
+    E::Entity: PartialEq,
+    C: Components + 'static,
+    R: ResourcesMut + 'static,
+{
+    /// Inserts provided resource bundle into the world, recording previous bundle data in the journal.
+    ///
+    /// Returns previous bundle data inserted into the world earlier.
+    /// Returns [`None`] if there was no bundle inserted earlier or some of bundle resources are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn insert_res<B>(&mut self, bundle: B) -> Option<B>
+    where
+        B: ResourceBundle + Clone,
+    {
+        let Self { world, .. } = self;
+
+        let previous = world.insert_res(bundle);
+        self.record(Operation::replace_res(previous.clone()));
+        previous
+    }
+
+    /// Removes resource bundle from the world, recording removed bundle data in the journal.
+    ///
+    /// Returns previous bundle data inserted into the world earlier.
+    /// Returns [`None`] if there was no bundle inserted earlier or some of bundle resources are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove_res<B>(&mut self) -> Option<B>
+    where
+        B: ResourceBundle + Clone,
+    {
+        let Self { world, .. } = self;
+
+        let previous = world.remove_res::<B>();
+        self.record(Operation::replace_res(previous.clone()));
+        previous
+    }
+}
+
+impl<E, C, R> From<World<E, C, R>> for JournaledWorld<E, C, R>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+{
+    fn from(world: World<E, C, R>) -> Self {
+        Self::new(world)
+    }
+}
+
+impl<E, C, R> fmt::Debug for JournaledWorld<E, C, R>
+where
+    E: Entities + fmt::Debug,
+    C: fmt::Debug,
+    R: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            world, undo, redo, ..
+        } = self;
+
+        f.debug_struct("JournaledWorld")
+            .field("world", world)
+            .field("undo", &undo.len())
+            .field("redo", &redo.len())
+            .finish_non_exhaustive()
+    }
+}
+
+fn apply<E, C, R>(
+    world: &mut World<E, C, R>,
+    aliases: &mut Aliases<E::Entity>,
+    group: Group<E, C, R>,
+) -> Group<E, C, R>
+where
+    E: Entities + 'static,
+    E::Entity: PartialEq,
+    C: Components + 'static,
+    R: 'static,
+{
+    // operations of the group are applied in reverse order, so their inverses are recorded in reverse too
+    group
+        .into_iter()
+        .rev()
+        .filter_map(|operation| operation.apply(world, aliases))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        world::World,
+    };
+
+    use super::JournaledWorld;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Velocity(i32);
+
+    impl Component for Velocity {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    type Components = HList![ArrayStorage<Position, 10>, ArrayStorage<Velocity, 10>];
+
+    fn world() -> JournaledWorld<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, ()).into()
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut world = world();
+        let entity = world.create();
+        assert_eq!(world.attach(entity, Position(1)).unwrap(), None);
+        assert_eq!(
+            world.attach(entity, Position(2)).unwrap(),
+            Some(Position(1))
+        );
+
+        assert!(world.undo());
+        assert_eq!(
+            world.world().get::<Position>(entity).unwrap(),
+            Some(&Position(1))
+        );
+        assert!(world.undo());
+        assert_eq!(world.world().get::<Position>(entity).unwrap(), None);
+        assert!(world.undo());
+        assert!(!world.world().contains(entity));
+        assert!(!world.undo());
+
+        assert!(world.redo());
+        assert!(world.redo());
+        assert!(world.redo());
+        assert!(!world.redo());
+        let entity = world.resolve(entity);
+        assert_eq!(
+            world.world().get::<Position>(entity).unwrap(),
+            Some(&Position(2))
+        );
+
+        let _ = world.remove::<Position>(entity).unwrap();
+        assert!(!world.can_redo());
+    }
+
+    #[test]
+    fn destroy() {
+        let mut world = world();
+        let entity = world.create();
+        let _ = world.attach(entity, Position(1)).unwrap();
+        let _ = world.attach(entity, Velocity(2)).unwrap();
+        world.destroy(entity).unwrap();
+        assert!(world.destroy(entity).is_err());
+
+        assert!(world.undo());
+        let actual = world.resolve(entity);
+        assert_ne!(actual, entity);
+        let bundle = world.world().get::<HList![Position, Velocity]>(actual);
+        assert_eq!(bundle.unwrap(), Some(hlist![&Position(1), &Velocity(2)]));
+
+        let _ = world.attach(entity, Velocity(3)).unwrap();
+        assert_eq!(
+            world.world().get::<Velocity>(actual).unwrap(),
+            Some(&Velocity(3))
+        );
+    }
+
+    #[test]
+    fn group() {
+        let mut world = world();
+        world.begin_group();
+        let first = world.create();
+        world.begin_group();
+        let second = world.create();
+        let _ = world.attach(second, Velocity(1)).unwrap();
+        assert!(world.end_group());
+        assert!(world.end_group());
+        assert!(!world.end_group());
+
+        assert!(world.undo());
+        assert!(!world.world().contains(first));
+        assert!(!world.world().contains(second));
+        assert!(!world.can_undo());
+
+        assert!(world.redo());
+        let second = world.resolve(second);
+        assert!(world.world().contains(world.resolve(first)));
+        assert_eq!(
+            world.world().get::<Velocity>(second).unwrap(),
+            Some(&Velocity(1))
+        );
+    }
+}
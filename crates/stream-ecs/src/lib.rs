@@ -7,12 +7,25 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use ::{hlist, lending_iterator, ref_kind};
 
+pub mod binary;
 pub mod component;
 pub mod dependency;
 pub mod entity;
+pub mod hierarchy;
+#[cfg(feature = "alloc")]
+pub mod journal;
+pub mod prefab;
+pub mod reflect;
+pub mod relation;
 pub mod resource;
+#[cfg(feature = "alloc")]
+pub mod scene;
+pub mod snapshot;
 pub mod view;
 pub mod world;
 
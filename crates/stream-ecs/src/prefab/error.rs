@@ -0,0 +1,81 @@
+use derive_more::{Display, From};
+
+use crate::component::{
+    bundle::NotRegisteredError,
+    storage::{EntityMismatchError, StorageCapacityError},
+};
+
+/// The error type which is returned when prefab creates more entities
+/// than can be returned from the instantiation.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("prefab creates {required} entities, but only {capacity} entities can be returned")]
+pub struct CapacityError {
+    required: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    /// Creates new error when prefab creates more entities than can be returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(required: usize, capacity: usize) -> Self {
+        Self { required, capacity }
+    }
+
+    /// Returns count of entities which would be created by the prefab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn required(self) -> usize {
+        self.required
+    }
+
+    /// Returns count of entities which can be returned from the instantiation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn capacity(self) -> usize {
+        self.capacity
+    }
+}
+
+/// The error type which is returned when instantiating a prefab in the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(Err: Display))]
+pub enum InstantiateError<Err> {
+    /// Component of the prefab or hierarchy components were not registered in the world.
+    NotRegistered(NotRegisteredError),
+    /// Prefab creates too many entities.
+    Capacity(CapacityError),
+    /// Entity registry failed to create new entities.
+    #[from(ignore)]
+    #[display("entity registry failed to create new entities: {_0}")]
+    Entities(Err),
+    /// Storage of some component of the prefab or storage of hierarchy components
+    /// does not have enough capacity for new entities.
+    Storage(StorageCapacityError),
+    /// Storage of some component of the prefab tracks another type of entity.
+    EntityMismatch(EntityMismatchError),
+}
@@ -0,0 +1,21 @@
+//! Provides utilities for prefabs — templates of entities in ECS.
+//!
+//! Prefab records a set of [erased components](crate::component::ErasedComponent)
+//! which are copied to each entity instantiated from the prefab.
+//! Prefab can contain other prefabs as its children:
+//! entities instantiated from them become [children](crate::hierarchy::Children) of the entity
+//! instantiated from the parent prefab.
+//!
+//! Prefab only borrows its components and children, so it does not use heap allocation at all
+//! and can be defined as a constant.
+
+pub use self::{
+    error::{CapacityError, InstantiateError},
+    prefab::Prefab,
+};
+
+pub(crate) use self::spawn::instantiate;
+
+mod error;
+mod prefab;
+mod spawn;
@@ -0,0 +1,86 @@
+#![allow(clippy::module_inception)]
+
+use crate::component::ErasedComponent;
+
+/// Template of the entity which records a set of erased components.
+///
+/// Each component of the prefab will be copied to the entity instantiated from this prefab.
+/// Each child prefab will be instantiated as a child of such entity.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Clone, Copy)]
+pub struct Prefab<'data> {
+    components: &'data [&'data dyn ErasedComponent],
+    children: &'data [Prefab<'data>],
+}
+
+impl<'data> Prefab<'data> {
+    /// Creates new prefab from provided components without any children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(components: &'data [&'data dyn ErasedComponent]) -> Self {
+        Self {
+            components,
+            children: &[],
+        }
+    }
+
+    /// Replaces children of this prefab with provided prefabs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn with_children(self, children: &'data [Prefab<'data>]) -> Self {
+        let Self { components, .. } = self;
+        Self {
+            components,
+            children,
+        }
+    }
+
+    /// Returns components recorded by this prefab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn components(&self) -> &'data [&'data dyn ErasedComponent] {
+        self.components
+    }
+
+    /// Returns children of this prefab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn children(&self) -> &'data [Prefab<'data>] {
+        self.children
+    }
+
+    /// Returns count of entities which will be created when instantiating this prefab,
+    /// including all the entities of child prefabs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        let children = self.children.iter().map(Prefab::len).sum::<usize>();
+        1 + children
+    }
+}
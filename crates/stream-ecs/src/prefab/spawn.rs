@@ -0,0 +1,397 @@
+use core::any::Any;
+
+use arrayvec::ArrayVec;
+
+use crate::{
+    component::{
+        ErasedComponent, bundle::NotRegisteredError, registry::Registry as Components,
+        storage::StorageCapacityError,
+    },
+    entity::{
+        Entity, ErasedEntity,
+        registry::{self, TryRegistry as TryEntities},
+    },
+    hierarchy::{self, Children, Parent},
+};
+
+use super::{CapacityError, InstantiateError, Prefab};
+
+/// Instantiates provided prefab, returning all the created entities in depth-first order.
+///
+/// Overrides are attached to the root entity after components of the prefab.
+/// Nothing is created if any component was not registered,
+/// prefab creates more than `N` entities
+/// or the registry or some component storage does not have enough capacity for them.
+pub fn instantiate<E, C, const N: usize>(
+    entities: &mut E,
+    components: &mut C,
+    prefab: &Prefab<'_>,
+    overrides: &[&dyn ErasedComponent],
+) -> Result<ArrayVec<E::Entity, N>, InstantiateError<E::Err>>
+where
+    E: TryEntities,
+    E::Entity: PartialEq,
+    <E::Entity as Entity>::Index: TryFrom<usize> + PartialEq,
+    <E::Entity as Entity>::Generation: PartialOrd,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+    C: Components,
+{
+    let len = prefab.len();
+    if len > N {
+        let error = CapacityError::new(len, N);
+        return Err(error.into());
+    }
+    check_registered(&*components, prefab)?;
+    for &component in overrides {
+        check_component(&*components, component)?;
+    }
+    if len > 1 {
+        let _ = hierarchy::storages::<_, E::Entity, N>(&*components)?;
+    }
+    entities
+        .check_capacity(len)
+        .map_err(InstantiateError::Entities)?;
+
+    let mut prefabs = ArrayVec::<_, N>::new();
+    flatten(prefab, &mut prefabs);
+    let next_entities = registry::next_entities_or_null(&*entities, len);
+    let next_entities: ArrayVec<_, N> = next_entities.collect();
+    check_capacity::<_, _, _, N>(&*components, &prefabs, overrides, &next_entities)?;
+
+    let mut spawned = ArrayVec::new();
+    spawn::<_, _, N>(entities, components, prefab, None, &mut spawned);
+    let root = spawned[0];
+    for &component in overrides {
+        attach(components, root, component);
+    }
+    Ok(spawned)
+}
+
+fn check_registered<C>(components: &C, prefab: &Prefab<'_>) -> Result<(), NotRegisteredError>
+where
+    C: Components,
+{
+    for &component in prefab.components() {
+        check_component(components, component)?;
+    }
+    for child in prefab.children() {
+        check_registered(components, child)?;
+    }
+    Ok(())
+}
+
+fn check_component<C>(
+    components: &C,
+    component: &dyn ErasedComponent,
+) -> Result<(), NotRegisteredError>
+where
+    C: Components,
+{
+    let type_id = (component as &dyn Any).type_id();
+    let is_registered = components
+        .iter()
+        .any(|storage| storage.component_type_id() == type_id);
+    if !is_registered {
+        return Err(NotRegisteredError::from_erased(component));
+    }
+    Ok(())
+}
+
+/// Collects provided prefab and all of its descendants in depth-first order,
+/// which is the order of entities created by the prefab.
+fn flatten<'prefab, 'data, const N: usize>(
+    prefab: &'prefab Prefab<'data>,
+    prefabs: &mut ArrayVec<&'prefab Prefab<'data>, N>,
+) {
+    prefabs.push(prefab);
+    for child in prefab.children() {
+        flatten(child, prefabs);
+    }
+}
+
+/// Checks if all the storages have enough capacity for components of provided prefabs
+/// which will be attached to provided entities.
+fn check_capacity<C, E, Err, const N: usize>(
+    components: &C,
+    prefabs: &[&Prefab<'_>],
+    overrides: &[&dyn ErasedComponent],
+    entities: &[E],
+) -> Result<(), InstantiateError<Err>>
+where
+    C: Components,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    for storage in components.iter() {
+        let type_id = storage.component_type_id();
+        let has_component =
+            |component: &&dyn ErasedComponent| (*component as &dyn Any).type_id() == type_id;
+        let mut attached = prefabs
+            .iter()
+            .zip(entities)
+            .enumerate()
+            .filter(|(index, (prefab, _))| {
+                let is_override = *index == 0 && overrides.iter().any(has_component);
+                is_override || prefab.components().iter().any(has_component)
+            })
+            .map(|(_, (_, entity))| entity as &dyn ErasedEntity);
+        if !storage.can_attach_all(&mut attached)? {
+            let error = StorageCapacityError::from_erased(storage);
+            return Err(error.into());
+        }
+    }
+    if entities.len() > 1 {
+        let (parents, children) = hierarchy::storages::<_, E, N>(components)?;
+        let child_entities = entities.iter().skip(1).copied();
+        if !parents.can_attach_all(child_entities) {
+            let error = StorageCapacityError::new::<Parent<N, E>>();
+            return Err(error.into());
+        }
+        let parent_entities = prefabs
+            .iter()
+            .zip(entities)
+            .filter(|(prefab, _)| !prefab.children().is_empty())
+            .map(|(_, &entity)| entity);
+        if !children.can_attach_all(parent_entities) {
+            let error = StorageCapacityError::new::<Children<N, E>>();
+            return Err(error.into());
+        }
+    }
+    Ok(())
+}
+
+fn attach<C, E>(components: &mut C, entity: E, component: &dyn ErasedComponent)
+where
+    C: Components,
+    E: Entity,
+{
+    let type_id = (component as &dyn Any).type_id();
+    let storage = components
+        .iter_mut()
+        .find(|storage| storage.component_type_id() == type_id);
+    let Some(storage) = storage else {
+        unreachable!("component of the prefab should be registered");
+    };
+    let Ok(()) = storage.attach(&entity, component) else {
+        unreachable!("storage of the prefab component should track provided entity type");
+    };
+}
+
+fn spawn<E, C, const N: usize>(
+    entities: &mut E,
+    components: &mut C,
+    prefab: &Prefab<'_>,
+    parent: Option<E::Entity>,
+    spawned: &mut ArrayVec<E::Entity, N>,
+) where
+    E: TryEntities,
+    E::Entity: PartialEq,
+    <E::Entity as Entity>::Index: TryFrom<usize> + PartialEq,
+    <E::Entity as Entity>::Generation: PartialOrd,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+    C: Components,
+{
+    // capacity of the registry, storages and hierarchy was checked before creating any entity
+    let entity = entities.create();
+    spawned.push(entity);
+    for &component in prefab.components() {
+        attach(components, entity, component);
+    }
+    if let Some(parent) = parent {
+        let Ok((parents, children)) = hierarchy::storages_mut::<_, _, N>(components) else {
+            unreachable!("hierarchy components should be registered");
+        };
+        let Ok(_) = hierarchy::try_set_parent(parents, children, entity, parent) else {
+            unreachable!("newly created entity cannot create a cycle or exceed checked capacity");
+        };
+    }
+    for child in prefab.children() {
+        spawn(entities, components, child, Some(entity), spawned);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, registry::Registry as Components, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        hierarchy::{ChildrenStorage, Parent, ParentStorage},
+        prefab::{InstantiateError, Prefab},
+        world::World,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Health(u32);
+
+    impl Component for Health {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Speed(f32);
+
+    impl Component for Speed {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Armor(u32);
+
+    impl Component for Armor {
+        type Storage = ArrayStorage<Self, 2>;
+    }
+
+    const WEAPON: Prefab = Prefab::new(&[&Health(5)]);
+    const ENEMY: Prefab = Prefab::new(&[&Health(10), &Speed(2.0)]).with_children(&[WEAPON, WEAPON]);
+    const SQUAD: Prefab = Prefab::new(&[&Health(20)]).with_children(&[ENEMY, ENEMY]);
+    const ARMORED: Prefab = Prefab::new(&[&Health(1), &Armor(1)]);
+
+    type WorldComponents = HList![
+        ArrayStorage<Health, 10>,
+        ArrayStorage<Speed, 10>,
+        ArrayStorage<Armor, 2>,
+        ParentStorage<4>,
+        ChildrenStorage<4>,
+    ];
+
+    fn world() -> World<ArrayRegistry<8>, WorldComponents, ()> {
+        let components = hlist![
+            ArrayStorage::new(),
+            ArrayStorage::new(),
+            ArrayStorage::new(),
+            ParentStorage::new(),
+            ChildrenStorage::new(),
+        ];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn instantiate() {
+        let mut entities = ArrayRegistry::<10>::new();
+        let mut components = hlist![
+            ArrayStorage::<Health, 10>::new(),
+            ArrayStorage::<Speed, 10>::new(),
+            ParentStorage::<10>::new(),
+            ChildrenStorage::<10>::new(),
+        ];
+
+        let spawned =
+            super::instantiate::<_, _, 10>(&mut entities, &mut components, &ENEMY, &[&Speed(3.0)])
+                .unwrap();
+        assert_eq!(spawned.len(), 3);
+        assert_eq!(entities.len(), 3);
+
+        let [root, first, second] = spawned[..] else {
+            panic!("prefab should create exactly 3 entities");
+        };
+        let healths = components.get::<Health>().unwrap();
+        assert_eq!(healths.get(root), Some(&Health(10)));
+        assert_eq!(healths.get(first), Some(&Health(5)));
+        assert_eq!(healths.get(second), Some(&Health(5)));
+        let speeds = components.get::<Speed>().unwrap();
+        assert_eq!(speeds.get(root), Some(&Speed(3.0)));
+        assert_eq!(speeds.get(first), None);
+        let parents = components.get::<Parent<10>>().unwrap();
+        assert_eq!(parents.get(first).map(|parent| parent.get()), Some(root));
+        assert_eq!(parents.get(second).map(|parent| parent.get()), Some(root));
+    }
+
+    #[test]
+    fn instantiate_errors() {
+        let mut entities = ArrayRegistry::<10>::new();
+        let mut components = hlist![ArrayStorage::<Health, 10>::new()];
+
+        let result = super::instantiate::<_, _, 10>(&mut entities, &mut components, &ENEMY, &[]);
+        assert!(matches!(result, Err(InstantiateError::NotRegistered(_))));
+
+        let result = super::instantiate::<_, _, 2>(&mut entities, &mut components, &ENEMY, &[]);
+        assert!(matches!(result, Err(InstantiateError::Capacity(_))));
+
+        let result = super::instantiate::<_, _, 10>(&mut entities, &mut components, &WEAPON, &[]);
+        assert!(result.is_ok());
+        assert_eq!(entities.len(), 1);
+    }
+
+    #[test]
+    fn world_instantiate() {
+        let components = hlist![
+            ArrayStorage::<Health, 10>::new(),
+            ArrayStorage::<Speed, 10>::new(),
+            ParentStorage::<10>::new(),
+            ChildrenStorage::<10>::new(),
+        ];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, ());
+
+        let spawned = world.instantiate::<10>(&SQUAD).unwrap();
+        assert_eq!(spawned.len(), 7);
+        assert_eq!(world.entities().len(), 7);
+
+        let [squad, first, first_a, first_b, second, second_a, second_b] = spawned[..] else {
+            panic!("prefab should create exactly 7 entities");
+        };
+        assert_eq!(world.get::<Health>(squad).unwrap(), Some(&Health(20)));
+        assert_eq!(world.get::<Speed>(squad).unwrap(), None);
+        assert_eq!(world.get::<Health>(first).unwrap(), Some(&Health(10)));
+        assert_eq!(world.get::<Speed>(second).unwrap(), Some(&Speed(2.0)));
+        assert_eq!(world.get::<Health>(second_b).unwrap(), Some(&Health(5)));
+        assert_eq!(world.parent::<10>(squad).unwrap(), None);
+        assert!(world.children::<10>(squad).unwrap().eq([first, second]));
+        assert!(world.children::<10>(first).unwrap().eq([first_a, first_b]));
+        assert!(
+            world
+                .children::<10>(second)
+                .unwrap()
+                .eq([second_a, second_b])
+        );
+        assert_eq!(world.parent::<10>(second_a).unwrap(), Some(second));
+
+        let spawned = world
+            .instantiate_with::<10>(&WEAPON, &[&Health(7)])
+            .unwrap();
+        assert_eq!(world.get::<Health>(spawned[0]).unwrap(), Some(&Health(7)));
+    }
+
+    #[test]
+    fn world_instantiate_full() {
+        let mut world = world();
+        let _ = [(); 6].map(|()| world.create());
+
+        let result = world.instantiate::<4>(&ENEMY);
+        assert!(matches!(result, Err(InstantiateError::Entities(_))));
+        assert_eq!(world.entities().len(), 6);
+        let spawned = world.instantiate::<4>(&WEAPON).unwrap();
+        assert_eq!(spawned.len(), 1);
+    }
+
+    #[test]
+    fn world_instantiate_storage_capacity() {
+        let mut world = world();
+        let _ = [(); 2].map(|()| world.create());
+
+        let result = world.instantiate::<4>(&ARMORED);
+        let Err(InstantiateError::Storage(error)) = result else {
+            panic!("armor storage should not have enough capacity");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Armor>());
+        assert_eq!(world.entities().len(), 2);
+
+        let result = world.instantiate::<4>(&ENEMY);
+        let Err(InstantiateError::Storage(error)) = result else {
+            panic!("hierarchy should not have enough capacity");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Parent<4>>());
+        assert_eq!(world.entities().len(), 2);
+        assert_eq!(world.components().get::<Health>().unwrap().len(), 0);
+
+        let spawned = world.instantiate_with::<4>(&WEAPON, &[&Armor(2)]);
+        assert!(matches!(spawned, Err(InstantiateError::Storage(_))));
+        let spawned = world.instantiate::<4>(&WEAPON).unwrap();
+        assert_eq!(world.get::<Armor>(spawned[0]).unwrap(), None);
+    }
+}
@@ -0,0 +1,141 @@
+use core::any::{Any, TypeId};
+
+use derive_more::{Display, From};
+
+use super::Reflect;
+
+/// The error type which is returned when type of provided value
+/// differs from the type of the reflected value.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(
+    r#"type of reflected value was mismatched: \
+        provided type is "{provided_type_name}", \
+        but reflected value is of type "{expected_type_name}""#
+)]
+pub struct TypeMismatchError {
+    expected_type_name: &'static str,
+    expected_type_id: TypeId,
+    provided_type_name: &'static str,
+    provided_type_id: TypeId,
+}
+
+impl TypeMismatchError {
+    /// Creates new error when type of provided value differs from the type of the reflected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(expected: &dyn Reflect, provided: &dyn Reflect) -> Self {
+        Self {
+            expected_type_name: expected.type_name(),
+            expected_type_id: (expected as &dyn Any).type_id(),
+            provided_type_name: provided.type_name(),
+            provided_type_id: (provided as &dyn Any).type_id(),
+        }
+    }
+
+    /// Returns type name of the reflected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected_type_name(self) -> &'static str {
+        self.expected_type_name
+    }
+
+    /// Returns [`TypeId`] of the reflected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn expected_type_id(self) -> TypeId {
+        self.expected_type_id
+    }
+
+    /// Returns type name of provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn provided_type_name(self) -> &'static str {
+        self.provided_type_name
+    }
+
+    /// Returns [`TypeId`] of provided value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn provided_type_id(self) -> TypeId {
+        self.provided_type_id
+    }
+}
+
+/// The error type which is returned when field of the reflected value was not found.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(r#"field was not found in reflected value of type "{type_name}""#)]
+pub struct FieldNotFoundError {
+    type_name: &'static str,
+}
+
+impl FieldNotFoundError {
+    /// Creates new error when field of provided reflected value was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(value: &dyn Reflect) -> Self {
+        let type_name = value.type_name();
+        Self { type_name }
+    }
+
+    /// Returns type name of the value which does not have requested field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// The error type which is returned when setting the field of the reflected value by path.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+pub enum SetPathError {
+    /// Some field of the path was not found.
+    FieldNotFound(FieldNotFoundError),
+    /// Type of provided value differs from the type of the field.
+    TypeMismatch(TypeMismatchError),
+}
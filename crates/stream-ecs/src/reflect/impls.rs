@@ -0,0 +1,64 @@
+use crate::entity::{CompactEntity, DefaultEntity};
+
+use super::{Reflect, TypeMismatchError};
+
+macro_rules! impl_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Reflect for $ty {
+                fn set(&mut self, value: &dyn Reflect) -> Result<(), TypeMismatchError> {
+                    let Some(&value) = value.downcast_ref::<Self>() else {
+                        return Err(TypeMismatchError::new(self, value));
+                    };
+                    *self = value;
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str,
+);
+
+impl<Index, Generation> Reflect for DefaultEntity<Index, Generation>
+where
+    Index: Copy + 'static,
+    Generation: Copy + 'static,
+{
+    fn set(&mut self, value: &dyn Reflect) -> Result<(), TypeMismatchError> {
+        let Some(&value) = value.downcast_ref::<Self>() else {
+            return Err(TypeMismatchError::new(self, value));
+        };
+        *self = value;
+        Ok(())
+    }
+}
+
+impl Reflect for CompactEntity {
+    fn set(&mut self, value: &dyn Reflect) -> Result<(), TypeMismatchError> {
+        let Some(&value) = value.downcast_ref::<Self>() else {
+            return Err(TypeMismatchError::new(self, value));
+        };
+        *self = value;
+        Ok(())
+    }
+}
@@ -0,0 +1,428 @@
+//! Provides utilities for runtime reflection of components and resources in ECS.
+//!
+//! Reflection allows to inspect and edit values generically,
+//! without knowing their concrete types at compile time:
+//! names and types of value fields can be enumerated, and fields can be read or written
+//! by their names or by paths consisting of field names separated by dots.
+
+use core::{
+    any::{Any, type_name},
+    iter::FusedIterator,
+};
+
+use crate::utils::type_name::TypeName;
+
+/// Derive macro for [`Reflect`] trait.
+#[cfg(feature = "derive")]
+pub use stream_ecs_macros::Reflect;
+
+pub use self::error::{FieldNotFoundError, SetPathError, TypeMismatchError};
+#[cfg(feature = "alloc")]
+pub use self::registry::{TypeRegistration, TypeRegistry, TypeRegistryIter};
+
+mod error;
+mod impls;
+#[cfg(feature = "alloc")]
+mod registry;
+
+/// Trait for values which can be inspected and edited at runtime.
+///
+/// This trait is guaranteed to be object safe, so it can be used as trait object.
+/// Values without any fields (such as primitives) are treated as leaves
+/// which can only be replaced as a whole with [`set`][Reflect::set()] method.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait Reflect: Any {
+    /// Returns type name of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn type_name(&self) -> &'static str {
+        TypeName::type_name(self)
+    }
+
+    /// Returns count of fields of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn field_len(&self) -> usize {
+        0
+    }
+
+    /// Returns information about the field of the value by its index.
+    /// Returns [`None`] if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn field_info(&self, index: usize) -> Option<FieldInfo> {
+        let _ = index;
+        None
+    }
+
+    /// Retrieves a reference to the field of the value by its index.
+    /// Returns [`None`] if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        let _ = index;
+        None
+    }
+
+    /// Retrieves a mutable reference to the field of the value by its index.
+    /// Returns [`None`] if index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        let _ = index;
+        None
+    }
+
+    /// Replaces the value with provided one.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if type of provided value
+    /// differs from the type of this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn set(&mut self, value: &dyn Reflect) -> Result<(), TypeMismatchError>;
+}
+
+impl dyn Reflect {
+    /// Checks if the value is of provided type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is<T>(&self) -> bool
+    where
+        T: Reflect,
+    {
+        let any: &dyn Any = self;
+        any.is::<T>()
+    }
+
+    /// Returns a reference to the value if it is of provided type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: Reflect,
+    {
+        let any: &dyn Any = self;
+        any.downcast_ref()
+    }
+
+    /// Returns a mutable reference to the value if it is of provided type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Reflect,
+    {
+        let any: &mut dyn Any = self;
+        any.downcast_mut()
+    }
+
+    /// Returns an iterator of information about all the fields of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn fields(&self) -> FieldIter<'_> {
+        FieldIter {
+            value: self,
+            index: 0,
+        }
+    }
+
+    /// Retrieves a reference to the field of the value by its name.
+    /// Returns [`None`] if the value has no field with such name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        let index = self.field_index(name)?;
+        self.field_at(index)
+    }
+
+    /// Retrieves a mutable reference to the field of the value by its name.
+    /// Returns [`None`] if the value has no field with such name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        let index = self.field_index(name)?;
+        self.field_at_mut(index)
+    }
+
+    /// Retrieves a reference to the nested field of the value by provided path.
+    ///
+    /// Path consists of field names separated by dots, for example `transform.position.x`.
+    /// Empty path refers to the value itself.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some field of the path was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn path(&self, path: &str) -> Result<&dyn Reflect, FieldNotFoundError> {
+        let mut value = self;
+        for name in path.split('.').filter(|name| !name.is_empty()) {
+            let Some(field) = value.field(name) else {
+                return Err(FieldNotFoundError::new(value));
+            };
+            value = field;
+        }
+        Ok(value)
+    }
+
+    /// Retrieves a mutable reference to the nested field of the value by provided path.
+    ///
+    /// Path consists of field names separated by dots, for example `transform.position.x`.
+    /// Empty path refers to the value itself.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some field of the path was not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect, FieldNotFoundError> {
+        let mut value = self;
+        for name in path.split('.').filter(|name| !name.is_empty()) {
+            let Some(index) = value.field_index(name) else {
+                return Err(FieldNotFoundError::new(value));
+            };
+            let Some(field) = value.field_at_mut(index) else {
+                unreachable!("index of the field should be in bounds");
+            };
+            value = field;
+        }
+        Ok(value)
+    }
+
+    /// Replaces the nested field of the value by provided path with provided value.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some field of the path was not found
+    /// or type of provided value differs from the type of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn set_path(&mut self, path: &str, value: &dyn Reflect) -> Result<(), SetPathError> {
+        let field = self.path_mut(path)?;
+        field.set(value)?;
+        Ok(())
+    }
+
+    fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields().position(|info| info.name() == name)
+    }
+}
+
+/// Information about the field of some [reflected](Reflect) value.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    name: &'static str,
+    type_name: &'static str,
+}
+
+impl FieldInfo {
+    /// Creates new information about the field of provided type with provided name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new<T>(name: &'static str) -> Self
+    where
+        T: Reflect,
+    {
+        let type_name = type_name::<T>();
+        Self { name, type_name }
+    }
+
+    /// Returns name of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns type name of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// Iterator of information about all the fields of some [reflected](Reflect) value.
+///
+/// This struct is created by [`fields`][dyn Reflect::fields()] method.
+#[derive(Clone)]
+pub struct FieldIter<'value> {
+    value: &'value dyn Reflect,
+    index: usize,
+}
+
+impl Iterator for FieldIter<'_> {
+    type Item = FieldInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { value, index } = self;
+        let info = value.field_info(*index)?;
+        *index += 1;
+        Some(info)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { value, index } = self;
+        let len = value.field_len().saturating_sub(*index);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for FieldIter<'_> {}
+
+impl FusedIterator for FieldIter<'_> {}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::{FieldInfo, Reflect, SetPathError};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Transform {
+        position: Position,
+        scale: (),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Health(u32);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Marker;
+
+    #[test]
+    fn fields() {
+        let transform = Transform {
+            position: Position { x: 1.0, y: 2.0 },
+            scale: (),
+        };
+        let value: &dyn Reflect = &transform;
+        let fields: std::vec::Vec<_> = value.fields().collect();
+        assert_eq!(
+            fields,
+            [
+                FieldInfo::new::<Position>("position"),
+                FieldInfo::new::<()>("scale"),
+            ],
+        );
+        let y = value.path("position.y").unwrap();
+        assert_eq!(y.downcast_ref::<f32>(), Some(&2.0));
+        assert!(value.path("position.z").is_err());
+
+        let health: &dyn Reflect = &Health(10);
+        assert_eq!(health.fields().map(FieldInfo::name).next(), Some("0"));
+        let marker: &dyn Reflect = &Marker;
+        assert_eq!(marker.fields().len(), 0);
+    }
+
+    #[test]
+    fn set() {
+        let mut transform = Transform {
+            position: Position { x: 1.0, y: 2.0 },
+            scale: (),
+        };
+        let value: &mut dyn Reflect = &mut transform;
+        value.set_path("position.x", &3.0_f32).unwrap();
+        assert!(matches!(
+            value.set_path("position.x", &3_u32),
+            Err(SetPathError::TypeMismatch(_)),
+        ));
+        assert!(matches!(
+            value.set_path("rotation", &3.0_f32),
+            Err(SetPathError::FieldNotFound(_)),
+        ));
+        value
+            .set_path("position", &Position { x: 5.0, y: 6.0 })
+            .unwrap();
+        assert_eq!(transform.position, Position { x: 5.0, y: 6.0 });
+    }
+}
@@ -0,0 +1,489 @@
+use alloc::{
+    boxed::Box,
+    collections::btree_map::{BTreeMap, Values},
+};
+use core::{
+    any::{Any, TypeId, type_name},
+    fmt,
+    iter::FusedIterator,
+};
+
+use crate::{
+    component::{
+        Component, ErasedComponent, registry::Registry as Components, storage::ErasedStorage,
+    },
+    resource::Resource,
+};
+
+use super::Reflect;
+
+/// Reflection data of some type which implements [`Reflect`] trait.
+///
+/// Registration allows to reflect erased values (such as [erased components][component])
+/// of the registered type, knowing only [type identifier](TypeId) of the type.
+///
+/// [component]: crate::component::ErasedComponent
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Clone, Copy)]
+pub struct TypeRegistration {
+    type_id: TypeId,
+    type_name: &'static str,
+    reflect: fn(&dyn Any) -> Option<&dyn Reflect>,
+    reflect_mut: fn(&mut dyn Any) -> Option<&mut dyn Reflect>,
+    component: Option<ComponentData>,
+}
+
+#[derive(Clone, Copy)]
+struct ComponentData {
+    default: fn() -> Box<dyn Reflect>,
+    erase: fn(&dyn Reflect) -> Option<&dyn ErasedComponent>,
+}
+
+impl TypeRegistration {
+    /// Creates new registration of provided type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn of<T>() -> Self
+    where
+        T: Reflect,
+    {
+        Self {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+            reflect: |value| value.downcast_ref::<T>().map(|value| value as _),
+            reflect_mut: |value| value.downcast_mut::<T>().map(|value| value as _),
+            component: None,
+        }
+    }
+
+    /// Creates new registration of provided component type.
+    ///
+    /// Unlike [`of`][TypeRegistration::of()] method, such registration
+    /// allows to create new values of the component type and attach them to entities.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn of_component<T>() -> Self
+    where
+        T: Component + Reflect + Default,
+    {
+        let component = ComponentData {
+            default: || Box::new(T::default()),
+            erase: |value| value.downcast_ref::<T>().map(|value| value as _),
+        };
+        Self {
+            component: Some(component),
+            ..Self::of::<T>()
+        }
+    }
+
+    /// Returns [`TypeId`] of the registered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_id(self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns type name of the registered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+
+    /// Reflects provided erased value.
+    /// Returns [`None`] if provided value is not of the registered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn reflect(self, value: &dyn Any) -> Option<&dyn Reflect> {
+        let Self { reflect, .. } = self;
+        reflect(value)
+    }
+
+    /// Reflects provided mutable erased value.
+    /// Returns [`None`] if provided value is not of the registered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn reflect_mut(self, value: &mut dyn Any) -> Option<&mut dyn Reflect> {
+        let Self { reflect_mut, .. } = self;
+        reflect_mut(value)
+    }
+
+    /// Checks if the registered type was registered as a [component](Component).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_component(self) -> bool {
+        let Self { component, .. } = self;
+        component.is_some()
+    }
+
+    /// Creates new default value of the registered component type.
+    /// Returns [`None`] if the type was not registered as a [component](Component).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn default_component(self) -> Option<Box<dyn Reflect>> {
+        let Self { component, .. } = self;
+        let ComponentData { default, .. } = component?;
+        Some(default())
+    }
+
+    /// Converts provided reflected value into the erased component.
+    /// Returns [`None`] if the type was not registered as a [component](Component)
+    /// or provided value is not of the registered type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn erase_component(self, value: &dyn Reflect) -> Option<&dyn ErasedComponent> {
+        let Self { component, .. } = self;
+        let ComponentData { erase, .. } = component?;
+        erase(value)
+    }
+
+    /// Retrieves a reference to the erased storage of the registered component type
+    /// from provided component registry.
+    /// Returns [`None`] if the registered type was not registered in the component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn storage<C>(self, components: &C) -> Option<&dyn ErasedStorage>
+    where
+        C: Components,
+    {
+        let Self { type_id, .. } = self;
+        components
+            .iter()
+            .find(|storage| storage.component_type_id() == type_id)
+    }
+
+    /// Retrieves a mutable reference to the erased storage of the registered component type
+    /// from provided component registry.
+    /// Returns [`None`] if the registered type was not registered in the component registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn storage_mut<C>(self, components: &mut C) -> Option<&mut dyn ErasedStorage>
+    where
+        C: Components,
+    {
+        let Self { type_id, .. } = self;
+        components
+            .iter_mut()
+            .find(|storage| storage.component_type_id() == type_id)
+    }
+}
+
+impl fmt::Debug for TypeRegistration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { type_name, .. } = self;
+        f.debug_struct("TypeRegistration")
+            .field("type_name", type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Registry of [reflection data](TypeRegistration) of types
+/// with [type identifier](TypeId) of the type as a key.
+///
+/// Registry can be stored in the world as a [resource][Resource],
+/// which allows to reflect components and resources of the world by their type identifiers.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Default, Clone)]
+pub struct TypeRegistry {
+    types: BTreeMap<TypeId, TypeRegistration>,
+}
+
+impl TypeRegistry {
+    /// Creates new empty type registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let types = BTreeMap::new();
+        Self { types }
+    }
+
+    /// Registers provided type in the registry.
+    /// Returns previous registration of the type, or [`None`] if the type was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn register<T>(&mut self) -> Option<TypeRegistration>
+    where
+        T: Reflect,
+    {
+        let Self { types } = self;
+        let registration = TypeRegistration::of::<T>();
+        types.insert(registration.type_id(), registration)
+    }
+
+    /// Registers provided component type in the registry.
+    /// Returns previous registration of the type, or [`None`] if the type was not registered.
+    ///
+    /// See [`of_component`][TypeRegistration::of_component()] method for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn register_component<T>(&mut self) -> Option<TypeRegistration>
+    where
+        T: Component + Reflect + Default,
+    {
+        let Self { types } = self;
+        let registration = TypeRegistration::of_component::<T>();
+        types.insert(registration.type_id(), registration)
+    }
+
+    /// Unregisters the type with provided [type identifier](TypeId) and returns its registration.
+    /// Returns [`None`] if such type was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn unregister(&mut self, type_id: TypeId) -> Option<TypeRegistration> {
+        let Self { types } = self;
+        types.remove(&type_id)
+    }
+
+    /// Checks if the type with provided [type identifier](TypeId) was registered in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        let Self { types } = self;
+        types.contains_key(&type_id)
+    }
+
+    /// Retrieves registration of the type with provided [type identifier](TypeId).
+    /// Returns [`None`] if such type was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get(&self, type_id: TypeId) -> Option<TypeRegistration> {
+        let Self { types } = self;
+        types.get(&type_id).copied()
+    }
+
+    /// Retrieves registration of the type with provided type name.
+    /// Returns [`None`] if such type was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_by_name(&self, type_name: &str) -> Option<TypeRegistration> {
+        self.iter()
+            .find(|registration| registration.type_name() == type_name)
+    }
+
+    /// Returns count of types registered in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn len(&self) -> usize {
+        let Self { types } = self;
+        types.len()
+    }
+
+    /// Checks if the registry is empty, or has no registered types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let Self { types } = self;
+        types.is_empty()
+    }
+
+    /// Returns an iterator of registrations of all the types registered in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> TypeRegistryIter<'_> {
+        let Self { types } = self;
+        let iter = types.values();
+        TypeRegistryIter { iter }
+    }
+}
+
+impl Resource for TypeRegistry {}
+
+impl fmt::Debug for TypeRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_names = self.iter().map(TypeRegistration::type_name);
+        f.debug_set().entries(type_names).finish()
+    }
+}
+
+/// Iterator over registrations of all the types of [type registry](TypeRegistry).
+///
+/// This struct is created by [`iter`][TypeRegistry::iter()] method of the registry.
+#[derive(Clone)]
+pub struct TypeRegistryIter<'me> {
+    iter: Values<'me, TypeId, TypeRegistration>,
+}
+
+impl Iterator for TypeRegistryIter<'_> {
+    type Item = TypeRegistration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        iter.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for TypeRegistryIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        iter.next_back().copied()
+    }
+}
+
+impl ExactSizeIterator for TypeRegistryIter<'_> {}
+
+impl FusedIterator for TypeRegistryIter<'_> {}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use core::any::TypeId;
+
+    use hlist::hlist;
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        reflect::Reflect,
+        resource::{Resource, registry::dynamic::DynamicResources},
+        world::World,
+    };
+
+    use super::TypeRegistry;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Position {
+        x: i32,
+        y: i32,
+    }
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+    struct Gravity(i32);
+
+    impl Resource for Gravity {}
+
+    #[test]
+    fn world() {
+        let mut registry = TypeRegistry::new();
+        assert!(registry.register::<Position>().is_none());
+        assert!(registry.register::<Gravity>().is_none());
+
+        let mut resources = DynamicResources::new();
+        let _ = resources.insert_erased(alloc::boxed::Box::new(registry));
+        let _ = resources.insert_erased(alloc::boxed::Box::new(Gravity(10)));
+        let components = hlist![ArrayStorage::<Position, 10>::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, resources);
+        let entity = world.create();
+        assert!(
+            world
+                .reflect_component(entity, TypeId::of::<Position>())
+                .is_none()
+        );
+
+        let _ = world.attach(entity, Position { x: 1, y: 2 }).unwrap();
+        let position = world
+            .reflect_component(entity, TypeId::of::<Position>())
+            .unwrap();
+        assert_eq!(position.type_name(), core::any::type_name::<Position>());
+        position.set_path("y", &5_i32).unwrap();
+        assert_eq!(
+            world.get::<Position>(entity).unwrap(),
+            Some(&Position { x: 1, y: 5 }),
+        );
+
+        let gravity = world.reflect_resource(TypeId::of::<Gravity>()).unwrap();
+        gravity.set_path("0", &-10_i32).unwrap();
+        assert!(world.reflect_resource(TypeId::of::<u32>()).is_none());
+    }
+}
@@ -0,0 +1,272 @@
+use core::{iter::FusedIterator, marker::PhantomData, slice};
+
+use crate::{
+    component::{Component, storage::array::ArrayStorage},
+    entity::{DefaultEntity, Entity},
+};
+
+use super::{Relation, TargetsCapacityError};
+
+/// Type of storage of the [`Targets`] component.
+///
+/// Capacity of the storage is the same `N` as the maximal count of targets of each source entity,
+/// see [`Targets`] for details.
+pub type TargetsStorage<R, const N: usize, E = DefaultEntity> =
+    ArrayStorage<Targets<R, N, E>, N, E>;
+
+/// Component which is attached to the source entity and stores targets of the relation.
+///
+/// It can be attached to exactly `N` entities of the world,
+/// and each source entity can have exactly `N` targets.
+///
+/// These limits are coupled because storage type of the component is derived from the component type,
+/// so only entities which indices are less than `N` can be source entities of the relation.
+/// Choose `N` large enough for both the count of source entities and the count of targets of each of them.
+/// Source entity always has at least one target, so this component is removed
+/// when the last target of the entity is removed.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Targets<R, const N: usize, E = DefaultEntity>
+where
+    R: Relation,
+    E: Entity,
+{
+    targets: [Option<E>; N],
+    len: usize,
+    relation: PhantomData<R>,
+}
+
+impl<R, E, const N: usize> Targets<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+{
+    /// Creates new targets component with provided target,
+    /// or returns an error if the component cannot store any targets.
+    pub(crate) fn try_new(target: E) -> Result<Self, TargetsCapacityError> {
+        let mut targets = [None; N];
+        let Some(slot) = targets.first_mut() else {
+            return Err(TargetsCapacityError);
+        };
+        *slot = Some(target);
+        Ok(Self {
+            targets,
+            len: 1,
+            relation: PhantomData,
+        })
+    }
+
+    /// Returns count of targets of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns an iterator over targets of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn iter(&self) -> TargetsIter<'_, E> {
+        let Self { targets, len, .. } = self;
+        let iter = targets[..*len].iter();
+        TargetsIter { iter }
+    }
+
+    /// Retains only targets specified by the predicate.
+    pub(crate) fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(E) -> bool,
+    {
+        let Self { targets, len, .. } = self;
+        let mut index = 0;
+        while index < *len {
+            let Some(target) = targets[index] else {
+                unreachable!("targets should be stored contiguously");
+            };
+            if f(target) {
+                index += 1;
+                continue;
+            }
+            *len -= 1;
+            targets[index] = targets[*len].take();
+        }
+    }
+}
+
+impl<R, E, const N: usize> Targets<R, N, E>
+where
+    R: Relation,
+    E: Entity + PartialEq,
+{
+    /// Checks if provided entity is a target of the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn contains(&self, target: E) -> bool {
+        self.iter().any(|item| item == target)
+    }
+
+    /// Tries to insert provided target, returning `false` if it was already present
+    /// or an error if the component cannot store any more targets.
+    pub(crate) fn try_insert(&mut self, target: E) -> Result<bool, TargetsCapacityError> {
+        if self.contains(target) {
+            return Ok(false);
+        }
+        let Self { targets, len, .. } = self;
+        let Some(slot) = targets.get_mut(*len) else {
+            return Err(TargetsCapacityError);
+        };
+        *slot = Some(target);
+        *len += 1;
+        Ok(true)
+    }
+
+    /// Removes provided target, returning `false` if it was not present.
+    pub(crate) fn remove(&mut self, target: E) -> bool {
+        let len = self.len;
+        self.retain(|item| item != target);
+        self.len != len
+    }
+}
+
+impl<R, E, const N: usize> Component for Targets<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Storage = TargetsStorage<R, N, E>;
+}
+
+impl<'data, R, E, const N: usize> IntoIterator for &'data Targets<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+{
+    type Item = E;
+
+    type IntoIter = TargetsIter<'data, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over targets of the relation.
+///
+/// This struct is created by the [`iter`][Targets::iter()] method of the [`Targets`] component.
+#[derive(Debug, Clone)]
+pub struct TargetsIter<'data, E>
+where
+    E: Entity,
+{
+    iter: slice::Iter<'data, Option<E>>,
+}
+
+impl<E> Default for TargetsIter<'_, E>
+where
+    E: Entity,
+{
+    fn default() -> Self {
+        let iter = [].iter();
+        Self { iter }
+    }
+}
+
+impl<E> Iterator for TargetsIter<'_, E>
+where
+    E: Entity,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().copied().flatten()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<E> DoubleEndedIterator for TargetsIter<'_, E>
+where
+    E: Entity,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().copied().flatten()
+    }
+}
+
+impl<E> ExactSizeIterator for TargetsIter<'_, E> where E: Entity {}
+
+impl<E> FusedIterator for TargetsIter<'_, E> where E: Entity {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entity::DefaultEntity as Entity, relation::Relation};
+
+    use super::Targets;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Likes;
+
+    impl Relation for Likes {}
+
+    #[test]
+    fn insert() {
+        let [a, b, c] = [0, 1, 2].map(|index| Entity::new(index, 0));
+        let mut targets = Targets::<Likes, 3>::try_new(a).unwrap();
+
+        assert!(targets.try_insert(b).unwrap());
+        assert!(!targets.try_insert(a).unwrap());
+        assert!(targets.try_insert(c).unwrap());
+        assert_eq!(targets.len(), 3);
+        assert!(targets.iter().eq([a, b, c]));
+    }
+
+    #[test]
+    fn insert_full() {
+        let [a, b] = [0, 1].map(|index| Entity::new(index, 0));
+        let mut targets = Targets::<Likes, 1>::try_new(a).unwrap();
+
+        assert!(targets.try_insert(b).is_err());
+        assert!(!targets.try_insert(a).unwrap());
+        assert!(Targets::<Likes, 0>::try_new(a).is_err());
+    }
+
+    #[test]
+    fn remove() {
+        let [a, b, c] = [0, 1, 2].map(|index| Entity::new(index, 0));
+        let mut targets = Targets::<Likes, 3>::try_new(a).unwrap();
+        let _ = targets.try_insert(b);
+        let _ = targets.try_insert(c);
+
+        assert!(targets.remove(a));
+        assert!(!targets.remove(a));
+        assert_eq!(targets.len(), 2);
+        assert!(targets.contains(b));
+        assert!(targets.contains(c));
+
+        targets.retain(|target| target != c);
+        assert!(targets.iter().eq([b]));
+    }
+}
@@ -0,0 +1,53 @@
+use derive_more::{Display, From};
+
+use crate::{
+    component::{bundle::NotRegisteredError, storage::array::ArrayStorageError},
+    entity::{DefaultEntity, Entity, registry::NotPresentError},
+    world::EntityError,
+};
+
+/// The error type which is returned when source entity cannot have any more targets of the relation.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display("targets capacity exceeded")]
+pub struct TargetsCapacityError;
+
+/// The error type which is returned when changing relationships of the ECS world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(E: Display))]
+pub enum RelationError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    /// Targets component of the relation was not registered in the world.
+    NotRegistered(NotRegisteredError),
+    /// Entity was not present in the world.
+    NotPresent(NotPresentError<E>),
+    /// Source entity cannot have any more targets of the relation.
+    Capacity(TargetsCapacityError),
+    /// Storage of targets component cannot store targets of the source entity.
+    Storage(ArrayStorageError),
+}
+
+impl<E> From<EntityError<E>> for RelationError<E>
+where
+    E: Entity,
+{
+    fn from(error: EntityError<E>) -> Self {
+        match error {
+            EntityError::NotRegistered(error) => Self::NotRegistered(error),
+            EntityError::NotPresent(error) => Self::NotPresent(error),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Provides utilities for relationships between entities in ECS.
+//!
+//! Relationship is a pair of entities (source and target) tagged with the type of the [relation][Relation].
+//! Unlike [hierarchy][crate::hierarchy], each source entity can have many targets of the same relation.
+//!
+//! Targets of the relation are stored in the [`Targets`] component which is attached to the source entity,
+//! so this component should be registered in the world before creating any relationships.
+//!
+//! [Cleanup policies][CleanupPolicy] are not applied automatically when an entity is destroyed
+//! with [`destroy`](crate::world::World::destroy()) method, because the world does not know
+//! which relations are used by its entities. Caller must either destroy target entities with
+//! [`destroy_target`](crate::world::World::destroy_target()) method or call
+//! [`cleanup_relation`](crate::world::World::cleanup_relation()) method after destroying entities,
+//! otherwise targets component would keep referring to destroyed entities.
+
+pub use self::{
+    component::{Targets, TargetsIter, TargetsStorage},
+    error::{RelationError, TargetsCapacityError},
+    query::Related,
+};
+
+mod component;
+mod error;
+mod query;
+
+/// Trait for types which tag relationships between entities.
+///
+/// Each type of relation has its own set of relationships,
+/// so the same pair of entities can be related by several relations at once.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait Relation: Copy + 'static {
+    /// Policy which is applied to the source entity when the target entity of the relationship is destroyed.
+    ///
+    /// Policy is applied only by [`cleanup_relation`](crate::world::World::cleanup_relation())
+    /// and [`destroy_target`](crate::world::World::destroy_target()) methods of the world.
+    const CLEANUP: CleanupPolicy = CleanupPolicy::RemovePair;
+}
+
+/// Policy which is applied to the source entity when the target entity of the relationship is destroyed.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CleanupPolicy {
+    /// Remove the relationship between the source entity and destroyed target entity.
+    #[default]
+    RemovePair,
+    /// Destroy the source entity of the relationship.
+    DestroySource,
+}
+
+#[cfg(test)]
+mod tests {
+    use hlist::{Cons, HList, Nil, hlist};
+
+    use crate::{
+        component::registry::Registry as _,
+        entity::{DefaultEntity, registry::array::ArrayRegistry},
+        world::World,
+    };
+
+    use super::{CleanupPolicy, Related, Relation, RelationError, Targets, TargetsStorage};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Likes;
+
+    impl Relation for Likes {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct OwnedBy;
+
+    impl Relation for OwnedBy {
+        const CLEANUP: CleanupPolicy = CleanupPolicy::DestroySource;
+    }
+
+    type Components = HList![TargetsStorage<Likes, 2>, TargetsStorage<OwnedBy, 4>];
+
+    fn world() -> World<ArrayRegistry<4>, Components, ()> {
+        let components = hlist![TargetsStorage::new(), TargetsStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn try_relate() {
+        let mut world = world();
+        let [a, b, c, d] = [(); 4].map(|_| world.create());
+
+        assert!(world.try_relate::<Likes, 2>(a, b).unwrap());
+        assert!(world.try_relate::<Likes, 2>(a, c).unwrap());
+        let result = world.try_relate::<Likes, 2>(a, d);
+        assert!(matches!(result, Err(RelationError::Capacity(_))));
+        assert!(world.targets::<Likes, 2>(a).unwrap().eq([b, c]));
+
+        let result = world.try_relate::<Likes, 2>(c, a);
+        assert!(matches!(result, Err(RelationError::Storage(_))));
+        assert_eq!(world.targets::<Likes, 2>(c).unwrap().len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn relate_full() {
+        let mut world = world();
+        let [a, b, c, d] = [(); 4].map(|_| world.create());
+
+        let _ = world.relate::<Likes, 2>(a, b);
+        let _ = world.relate::<Likes, 2>(a, c);
+        let _ = world.relate::<Likes, 2>(a, d);
+    }
+
+    #[test]
+    fn related() {
+        let mut world = world();
+        let [a, b, c, _] = [(); 4].map(|_| world.create());
+        let _ = world.relate::<Likes, 2>(a, b).unwrap();
+        let _ = world.relate::<Likes, 2>(a, c).unwrap();
+        let _ = world.relate::<Likes, 2>(b, c).unwrap();
+
+        let view = world
+            .view::<HList![DefaultEntity, Related<Likes, 2>]>()
+            .unwrap();
+        let items: Vec<_> = view
+            .iter()
+            .map(|Cons(entity, Cons(targets, Nil))| (entity, targets.collect::<Vec<_>>()))
+            .collect();
+        assert_eq!(items, [(a, vec![b, c]), (b, vec![c])]);
+
+        let _ = world.unrelate::<Likes, 2>(b, c).unwrap();
+        let view = world.view::<Related<Likes, 2>>().unwrap();
+        assert!(view.satisfies(a).unwrap());
+        assert!(!view.satisfies(b).unwrap());
+        assert_eq!(view.iter().count(), 1);
+    }
+
+    #[test]
+    fn remove_pair() {
+        let mut world = world();
+        let [a, b, c, _] = [(); 4].map(|_| world.create());
+        let _ = world.relate::<Likes, 2>(a, b).unwrap();
+        let _ = world.relate::<Likes, 2>(a, c).unwrap();
+        let _ = world.relate::<Likes, 2>(b, c).unwrap();
+
+        world.destroy_target::<Likes, 2>(c).unwrap();
+        assert!(!world.entities().contains(c));
+        assert!(world.entities().contains(a));
+        assert!(world.entities().contains(b));
+        assert!(world.targets::<Likes, 2>(a).unwrap().eq([b]));
+        assert_eq!(world.targets::<Likes, 2>(b).unwrap().len(), 0);
+
+        world.destroy(a).unwrap();
+        world.cleanup_relation::<Likes, 2>().unwrap();
+        let storage = world.components().get::<Targets<Likes, 2>>().unwrap();
+        assert_eq!(storage.len(), 0);
+        assert!(world.entities().contains(b));
+    }
+
+    #[test]
+    fn destroy_source() {
+        let mut world = world();
+        let [a, b, c, d] = [(); 4].map(|_| world.create());
+        let _ = world.relate::<OwnedBy, 4>(a, b).unwrap();
+        let _ = world.relate::<OwnedBy, 4>(b, c).unwrap();
+        let _ = world.relate::<OwnedBy, 4>(d, a).unwrap();
+        let _ = world.relate::<OwnedBy, 4>(d, c).unwrap();
+        let _ = world.relate::<Likes, 2>(a, b).unwrap();
+
+        world.destroy_target::<OwnedBy, 4>(b).unwrap();
+        for entity in [a, b, d] {
+            assert!(!world.entities().contains(entity));
+        }
+        assert!(world.entities().contains(c));
+        let storage = world.components().get::<Targets<OwnedBy, 4>>().unwrap();
+        assert_eq!(storage.len(), 0);
+        let storage = world.components().get::<Targets<Likes, 2>>().unwrap();
+        assert_eq!(storage.len(), 1);
+    }
+
+    #[test]
+    fn cleanup_destroy_source() {
+        let mut world = world();
+        let [a, b, c, d] = [(); 4].map(|_| world.create());
+        let _ = world.relate::<OwnedBy, 4>(a, b).unwrap();
+        let _ = world.relate::<OwnedBy, 4>(b, c).unwrap();
+        let _ = world.relate::<OwnedBy, 4>(d, b).unwrap();
+
+        world.destroy(c).unwrap();
+        assert!(world.entities().contains(b));
+        world.cleanup_relation::<OwnedBy, 4>().unwrap();
+        assert_eq!(world.entities().len(), 0);
+        let storage = world.components().get::<Targets<OwnedBy, 4>>().unwrap();
+        assert_eq!(storage.len(), 0);
+        assert!(world.targets::<OwnedBy, 4>(a).is_err());
+    }
+}
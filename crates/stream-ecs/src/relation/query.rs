@@ -0,0 +1,123 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::{registry::Registry as Components, storage::Storage},
+    entity::{DefaultEntity, Entity},
+    view::query::{AsReadonly, IntoReadonly, Query, ReadonlyQuery},
+};
+
+use super::{Relation, Targets, TargetsIter, TargetsStorage};
+
+/// Query which yields targets of the relation for each source entity.
+///
+/// Only entities which have at least one target of the relation satisfy this query.
+/// `N` is the same constant which is used by [`Targets`] component,
+/// so it limits both the count of source entities and the count of targets of each of them.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug)]
+pub struct Related<R, const N: usize, E = DefaultEntity>(PhantomData<fn() -> (R, E)>)
+where
+    R: Relation,
+    E: Entity;
+
+impl<R, E, const N: usize> Query for Related<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Entity = E;
+
+    type Item<'item> = TargetsIter<'item, E>;
+
+    type Fetch<'fetch> = &'fetch TargetsStorage<R, N, E>;
+
+    fn new_fetch<C>(components: &mut C) -> Option<Self::Fetch<'_>>
+    where
+        C: Components,
+    {
+        Self::new_readonly_fetch(components)
+    }
+
+    fn fetch<'borrow>(
+        fetch: &'borrow mut Self::Fetch<'_>,
+        entity: Self::Entity,
+    ) -> Option<Self::Item<'borrow>> {
+        Self::readonly_fetch(fetch, entity)
+    }
+
+    fn satisfies(fetch: &Self::Fetch<'_>, entity: Self::Entity) -> bool {
+        Self::readonly_ref_satisfies(fetch, entity)
+    }
+}
+
+impl<R, E, const N: usize> IntoReadonly for Related<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type Readonly = Self;
+
+    fn into_readonly(fetch: Self::Fetch<'_>) -> <Self::Readonly as Query>::Fetch<'_> {
+        fetch
+    }
+}
+
+impl<R, E, const N: usize> AsReadonly for Related<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    type ReadonlyRef<'borrow> = &'borrow TargetsStorage<R, N, E>;
+
+    fn as_readonly<'borrow>(fetch: &'borrow Self::Fetch<'_>) -> Self::ReadonlyRef<'borrow> {
+        fetch
+    }
+
+    fn readonly_ref_fetch(
+        fetch: Self::ReadonlyRef<'_>,
+        entity: Self::Entity,
+    ) -> Option<<Self::Readonly as Query>::Item<'_>> {
+        Storage::get(fetch, entity).map(Targets::iter)
+    }
+
+    fn readonly_ref_satisfies(fetch: Self::ReadonlyRef<'_>, entity: Self::Entity) -> bool {
+        Storage::is_attached(fetch, entity)
+    }
+}
+
+impl<R, E, const N: usize> ReadonlyQuery for Related<R, N, E>
+where
+    R: Relation,
+    E: Entity,
+    E::Index: TryFrom<usize> + PartialEq,
+    E::Generation: PartialOrd,
+    usize: TryFrom<E::Index>,
+{
+    fn new_readonly_fetch<C>(components: &C) -> Option<Self::Fetch<'_>>
+    where
+        C: Components,
+    {
+        Components::get::<Targets<R, N, E>>(components)
+    }
+
+    fn readonly_fetch<'fetch>(
+        fetch: &Self::Fetch<'fetch>,
+        entity: Self::Entity,
+    ) -> Option<Self::Item<'fetch>> {
+        Storage::get(*fetch, entity).map(Targets::iter)
+    }
+}
@@ -0,0 +1,304 @@
+//! Resource registry implementation backed by a map of resources.
+//!
+//! Unlike heterogenous list of resources, this registry allows to insert resources of new types at runtime
+//! without changing the type of the registry.
+//! Resources of the registry are allocated on the heap, so it is only available with `alloc` feature enabled.
+
+use alloc::{
+    boxed::Box,
+    collections::btree_map::{BTreeMap, Values, ValuesMut},
+};
+use core::{
+    any::{Any, TypeId},
+    convert::Infallible,
+    fmt,
+    iter::FusedIterator,
+};
+
+use crate::resource::{ErasedResource, Resource};
+
+use super::{Registry, RegistryMut, TryRegistryMut, With};
+
+/// Resource registry which stores resources in a map
+/// with [type identifier](TypeId) of the resource as a key.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Default)]
+pub struct DynamicResources {
+    resources: BTreeMap<TypeId, Box<dyn ErasedResource>>,
+}
+
+impl DynamicResources {
+    /// Creates new empty dynamic resource registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new() -> Self {
+        let resources = BTreeMap::new();
+        Self { resources }
+    }
+
+    /// Inserts provided erased resource into the registry.
+    /// Returns previous resource of the same type, or [`None`] if such resource was not in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn insert_erased(
+        &mut self,
+        resource: Box<dyn ErasedResource>,
+    ) -> Option<Box<dyn ErasedResource>> {
+        let Self { resources } = self;
+        let type_id = (resource.as_ref() as &dyn Any).type_id();
+        resources.insert(type_id, resource)
+    }
+
+    /// Removes the resource with provided [type identifier](TypeId) from the registry and returns it.
+    /// Returns [`None`] if such resource was not in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove_erased(&mut self, type_id: TypeId) -> Option<Box<dyn ErasedResource>> {
+        let Self { resources } = self;
+        resources.remove(&type_id)
+    }
+
+    /// Retrieves a reference to the erased resource with provided [type identifier](TypeId).
+    /// Returns [`None`] if such resource was not in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_erased(&self, type_id: TypeId) -> Option<&dyn ErasedResource> {
+        let Self { resources } = self;
+        let resource = resources.get(&type_id)?;
+        Some(resource.as_ref())
+    }
+
+    /// Retrieves a mutable reference to the erased resource with provided [type identifier](TypeId).
+    /// Returns [`None`] if such resource was not in the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_erased_mut(&mut self, type_id: TypeId) -> Option<&mut dyn ErasedResource> {
+        let Self { resources } = self;
+        let resource = resources.get_mut(&type_id)?;
+        Some(resource.as_mut())
+    }
+}
+
+impl Registry for DynamicResources {
+    fn contains<R>(&self) -> bool
+    where
+        R: Resource,
+    {
+        let Self { resources } = self;
+        resources.contains_key(&TypeId::of::<R>())
+    }
+
+    fn len(&self) -> usize {
+        let Self { resources } = self;
+        resources.len()
+    }
+
+    fn get<R>(&self) -> Option<&R>
+    where
+        R: Resource,
+    {
+        let resource = self.get_erased(TypeId::of::<R>())?;
+        let resource = resource as &dyn Any;
+        resource.downcast_ref()
+    }
+
+    fn get_mut<R>(&mut self) -> Option<&mut R>
+    where
+        R: Resource,
+    {
+        let resource = self.get_erased_mut(TypeId::of::<R>())?;
+        let resource = resource as &mut dyn Any;
+        resource.downcast_mut()
+    }
+
+    type Iter<'me>
+        = Iter<'me>
+    where
+        Self: 'me;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        let Self { resources } = self;
+        let iter = resources.values();
+        Iter { iter }
+    }
+
+    type IterMut<'me>
+        = IterMut<'me>
+    where
+        Self: 'me;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        let Self { resources } = self;
+        let iter = resources.values_mut();
+        IterMut { iter }
+    }
+}
+
+impl With for DynamicResources {
+    type Output<R>
+        = Self
+    where
+        R: Resource;
+
+    fn with<R>(mut self, resource: R) -> Self::Output<R>
+    where
+        R: Resource,
+    {
+        let _ = self.insert(resource);
+        self
+    }
+}
+
+impl RegistryMut for DynamicResources {
+    fn insert<R>(&mut self, resource: R) -> Option<R>
+    where
+        R: Resource,
+    {
+        let Self { resources } = self;
+        let resource = resources.insert(TypeId::of::<R>(), Box::new(resource))?;
+        let resource = resource as Box<dyn Any>;
+        let Ok(resource) = resource.downcast() else {
+            unreachable!("resource should be inserted by its type");
+        };
+        Some(*resource)
+    }
+
+    fn remove<R>(&mut self) -> Option<R>
+    where
+        R: Resource,
+    {
+        let resource = self.remove_erased(TypeId::of::<R>())?;
+        let resource = resource as Box<dyn Any>;
+        let Ok(resource) = resource.downcast() else {
+            unreachable!("resource should be inserted by its type");
+        };
+        Some(*resource)
+    }
+
+    fn clear(&mut self) {
+        let Self { resources } = self;
+        resources.clear()
+    }
+}
+
+impl TryRegistryMut for DynamicResources {
+    type Err = Infallible;
+
+    fn try_insert<R>(&mut self, resource: R) -> Result<Option<R>, Self::Err>
+    where
+        R: Resource,
+    {
+        let resource = self.insert(resource);
+        Ok(resource)
+    }
+}
+
+impl fmt::Debug for DynamicResources {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { resources } = self;
+        f.debug_set().entries(resources.keys()).finish()
+    }
+}
+
+/// Iterator over references of all the resources of [dynamic resource registry](DynamicResources).
+///
+/// This struct is created by [`iter`][Registry::iter()] method of the registry.
+pub struct Iter<'me> {
+    iter: Values<'me, TypeId, Box<dyn ErasedResource>>,
+}
+
+impl<'me> Iterator for Iter<'me> {
+    type Item = &'me dyn ErasedResource;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let resource = iter.next()?;
+        Some(resource.as_ref())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let resource = iter.next_back()?;
+        Some(resource.as_ref())
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+/// Iterator over mutable references of all the resources of [dynamic resource registry](DynamicResources).
+///
+/// This struct is created by [`iter_mut`][Registry::iter_mut()] method of the registry.
+pub struct IterMut<'me> {
+    iter: ValuesMut<'me, TypeId, Box<dyn ErasedResource>>,
+}
+
+impl<'me> Iterator for IterMut<'me> {
+    type Item = &'me mut dyn ErasedResource;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let resource = iter.next()?;
+        Some(resource.as_mut())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self { iter } = self;
+        iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for IterMut<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Self { iter } = self;
+        let resource = iter.next_back()?;
+        Some(resource.as_mut())
+    }
+}
+
+impl ExactSizeIterator for IterMut<'_> {
+    fn len(&self) -> usize {
+        let Self { iter } = self;
+        iter.len()
+    }
+}
+
+impl FusedIterator for IterMut<'_> {}
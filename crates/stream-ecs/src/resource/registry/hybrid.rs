@@ -0,0 +1,291 @@
+//! Resource registry implementation which combines static and dynamic registries.
+//!
+//! Hybrid registry looks up resources in the static registry (for example, heterogenous list of resources) first,
+//! falling back to the [dynamic registry](DynamicResources) which can store resources of any type.
+//! Dynamic registry allocates resources on the heap, so it is only available with `alloc` feature enabled.
+
+use core::{convert::Infallible, iter::Chain, mem};
+
+use crate::resource::Resource;
+
+use super::{Registry, RegistryMut, TryRegistryMut, With, dynamic::DynamicResources};
+
+/// Resource registry which checks provided static registry first
+/// and falls back to the [dynamic registry](DynamicResources).
+///
+/// Resources of the static registry are always present in the hybrid registry:
+/// inserting such resource replaces its value in the static registry,
+/// and they can not be removed from the hybrid registry.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Default)]
+pub struct Hybrid<S> {
+    statics: S,
+    dynamic: DynamicResources,
+}
+
+impl<S> Hybrid<S> {
+    /// Creates new hybrid registry from provided static registry with empty dynamic registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn new(statics: S) -> Self {
+        let dynamic = DynamicResources::new();
+        Self { statics, dynamic }
+    }
+
+    /// Creates new hybrid registry from provided static and dynamic registries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn from_parts(statics: S, dynamic: DynamicResources) -> Self {
+        Self { statics, dynamic }
+    }
+
+    /// Returns a reference to the static registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn statics(&self) -> &S {
+        let Self { statics, .. } = self;
+        statics
+    }
+
+    /// Returns a mutable reference to the static registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn statics_mut(&mut self) -> &mut S {
+        let Self { statics, .. } = self;
+        statics
+    }
+
+    /// Returns a reference to the dynamic registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn dynamic(&self) -> &DynamicResources {
+        let Self { dynamic, .. } = self;
+        dynamic
+    }
+
+    /// Returns a mutable reference to the dynamic registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn dynamic_mut(&mut self) -> &mut DynamicResources {
+        let Self { dynamic, .. } = self;
+        dynamic
+    }
+
+    /// Returns static and dynamic registries of the hybrid registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn into_parts(self) -> (S, DynamicResources) {
+        let Self { statics, dynamic } = self;
+        (statics, dynamic)
+    }
+}
+
+impl<S> Registry for Hybrid<S>
+where
+    S: Registry,
+{
+    fn contains<R>(&self) -> bool
+    where
+        R: Resource,
+    {
+        let Self { statics, dynamic } = self;
+        statics.contains::<R>() || dynamic.contains::<R>()
+    }
+
+    fn len(&self) -> usize {
+        let Self { statics, dynamic } = self;
+        statics.len() + dynamic.len()
+    }
+
+    fn get<R>(&self) -> Option<&R>
+    where
+        R: Resource,
+    {
+        let Self { statics, dynamic } = self;
+        match statics.get() {
+            Some(resource) => Some(resource),
+            None => dynamic.get(),
+        }
+    }
+
+    fn get_mut<R>(&mut self) -> Option<&mut R>
+    where
+        R: Resource,
+    {
+        let Self { statics, dynamic } = self;
+        match statics.get_mut() {
+            Some(resource) => Some(resource),
+            None => dynamic.get_mut(),
+        }
+    }
+
+    type Iter<'me>
+        = Chain<S::Iter<'me>, <DynamicResources as Registry>::Iter<'me>>
+    where
+        Self: 'me;
+
+    fn iter(&self) -> Self::Iter<'_> {
+        let Self { statics, dynamic } = self;
+        statics.iter().chain(dynamic.iter())
+    }
+
+    type IterMut<'me>
+        = Chain<S::IterMut<'me>, <DynamicResources as Registry>::IterMut<'me>>
+    where
+        Self: 'me;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        let Self { statics, dynamic } = self;
+        statics.iter_mut().chain(dynamic.iter_mut())
+    }
+}
+
+impl<S> With for Hybrid<S>
+where
+    S: With,
+{
+    type Output<R>
+        = Hybrid<S::Output<R>>
+    where
+        R: Resource;
+
+    fn with<R>(self, resource: R) -> Self::Output<R>
+    where
+        R: Resource,
+    {
+        let Self { statics, dynamic } = self;
+        let statics = statics.with(resource);
+        Hybrid { statics, dynamic }
+    }
+}
+
+impl<S> RegistryMut for Hybrid<S>
+where
+    S: Registry,
+{
+    fn insert<R>(&mut self, resource: R) -> Option<R>
+    where
+        R: Resource,
+    {
+        let Self { statics, dynamic } = self;
+        match statics.get_mut() {
+            Some(previous) => Some(mem::replace(previous, resource)),
+            None => dynamic.insert(resource),
+        }
+    }
+
+    fn remove<R>(&mut self) -> Option<R>
+    where
+        R: Resource,
+    {
+        let Self { dynamic, .. } = self;
+        dynamic.remove()
+    }
+
+    fn clear(&mut self) {
+        let Self { dynamic, .. } = self;
+        dynamic.clear()
+    }
+}
+
+impl<S> TryRegistryMut for Hybrid<S>
+where
+    S: Registry,
+{
+    type Err = Infallible;
+
+    fn try_insert<R>(&mut self, resource: R) -> Result<Option<R>, Self::Err>
+    where
+        R: Resource,
+    {
+        let resource = self.insert(resource);
+        Ok(resource)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hlist::hlist;
+
+    use crate::{
+        entity::registry::array::ArrayRegistry,
+        resource::{
+            Resource,
+            registry::{Registry, RegistryMut},
+        },
+        world::World,
+    };
+
+    use super::Hybrid;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Gravity(i32);
+
+    impl Resource for Gravity {}
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Score(u32);
+
+    impl Resource for Score {}
+
+    #[test]
+    fn fallback() {
+        let mut resources = Hybrid::new(hlist![Gravity(10)]);
+        assert_eq!(resources.insert(Gravity(20)), Some(Gravity(10)));
+        assert_eq!(resources.insert(Score(1)), None);
+        assert_eq!(resources.insert(Score(2)), Some(Score(1)));
+        assert_eq!(resources.len(), 2);
+        assert_eq!(resources.iter().count(), 2);
+        assert_eq!(resources.statics().get(), Some(&Gravity(20)));
+        assert_eq!(resources.dynamic().get(), Some(&Score(2)));
+
+        assert_eq!(resources.remove::<Gravity>(), None);
+        assert_eq!(resources.remove::<Score>(), Some(Score(2)));
+        assert!(resources.contains::<Gravity>());
+        assert!(!resources.contains::<Score>());
+    }
+
+    #[test]
+    fn world() {
+        let resources = Hybrid::new(hlist![Gravity(10)]);
+        let mut world = World::with(ArrayRegistry::<10>::new(), (), resources);
+        assert_eq!(world.insert_res(Score(1)), None);
+        assert_eq!(world.get_res::<Score>(), Some(&Score(1)));
+        assert_eq!(world.get_res::<Gravity>(), Some(&Gravity(10)));
+        assert_eq!(world.remove_res::<Score>(), Some(Score(1)));
+        assert!(!world.contains_res::<Score>());
+    }
+}
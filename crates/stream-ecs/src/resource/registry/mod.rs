@@ -4,6 +4,11 @@ use hlist::ops::Here;
 
 use super::{ErasedResource, Resource};
 
+#[cfg(feature = "alloc")]
+pub mod dynamic;
+#[cfg(feature = "alloc")]
+pub mod hybrid;
+
 mod impls;
 
 /// Resource registry of the world.
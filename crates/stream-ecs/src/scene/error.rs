@@ -0,0 +1,245 @@
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use derive_more::{Display, From};
+
+use crate::entity::{DefaultEntity, Entity, registry::NotPresentError};
+
+/// The error type which is returned when value of some type cannot be written into the scene.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(r#"value of type "{type_name}" is not supported by the scene format"#)]
+pub struct UnsupportedTypeError {
+    type_name: &'static str,
+}
+
+impl UnsupportedTypeError {
+    /// Creates new error when value of provided type cannot be written into the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(type_name: &'static str) -> Self {
+        Self { type_name }
+    }
+
+    /// Returns name of the type which is not supported by the scene format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+}
+
+/// The error type which is returned when component references an entity
+/// which is not a part of the saved scene.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy)]
+#[display(bound(E: Display))]
+#[display("entity {entity} is referenced by a component but is not a part of the scene")]
+pub struct ExternalEntityError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    entity: E,
+}
+
+impl<E> ExternalEntityError<E>
+where
+    E: Entity,
+{
+    /// Creates new error when provided entity is referenced but is not a part of the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(entity: E) -> Self {
+        Self { entity }
+    }
+
+    /// Returns the entity which is not a part of the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn entity(self) -> E {
+        self.entity
+    }
+}
+
+/// The error type which is returned when saving a set of entities into the scene.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(E: Display))]
+pub enum SaveError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    /// Entity of the scene was not present in the world.
+    NotPresent(NotPresentError<E>),
+    /// Some value cannot be written into the scene.
+    UnsupportedType(UnsupportedTypeError),
+    /// Component references an entity which is not a part of the scene.
+    ExternalEntity(ExternalEntityError<E>),
+}
+
+/// Kind of the error which is returned when loading the scene.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum LoadErrorKind {
+    /// Scene contains unexpected token.
+    #[display("unexpected token, expected {_0}")]
+    UnexpectedToken(&'static str),
+    /// Scene ends unexpectedly.
+    #[display("unexpected end of the scene")]
+    UnexpectedEnd,
+    /// Type was not registered in the type registry.
+    #[display("type was not registered in the type registry")]
+    UnknownType,
+    /// Type was not registered as a component in the type registry.
+    #[display("type was not registered as a component")]
+    NotComponent,
+    /// Component was not registered in the world.
+    #[display("component was not registered in the world")]
+    NotRegistered,
+    /// Resource was not inserted into the world.
+    #[display("resource is not present in the world")]
+    MissingResource,
+    /// Field was not found in the reflected value.
+    #[display("field was not found")]
+    UnknownField,
+    /// Value does not match the type of the reflected value.
+    #[display("value does not match the type")]
+    InvalidValue,
+    /// Entity with provided position is not defined in the scene.
+    #[display("entity {_0} is not defined in the scene")]
+    UnknownEntity(usize),
+    /// World does not have enough capacity for entities or components of the scene.
+    #[display("world does not have enough capacity")]
+    Capacity,
+}
+
+/// The error type which is returned when loading the scene.
+///
+/// Error contains the line of the scene where the error occurred,
+/// and the name of the type which failed to load, if any.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadError {
+    line: usize,
+    type_name: Option<String>,
+    kind: LoadErrorKind,
+}
+
+impl LoadError {
+    /// Creates new error of provided kind which occurred at provided line of the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(line: usize, kind: LoadErrorKind) -> Self {
+        Self {
+            line,
+            type_name: None,
+            kind,
+        }
+    }
+
+    /// Attaches provided name of the type which failed to load to the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn with_type_name(self, type_name: impl Into<String>) -> Self {
+        let type_name = Some(type_name.into());
+        Self { type_name, ..self }
+    }
+
+    /// Returns the line of the scene where the error occurred, starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the name of the type which failed to load.
+    /// Returns [`None`] if the error is not related to any type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    /// Returns kind of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn kind(&self) -> LoadErrorKind {
+        self.kind
+    }
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            line,
+            type_name,
+            kind,
+        } = self;
+        write!(f, "failed to load the scene at line {line}: {kind}")?;
+        if let Some(type_name) = type_name {
+            write!(f, r#" (type "{type_name}")"#)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+use alloc::vec::Vec;
+use core::any::Any;
+
+use crate::{
+    component::registry::Registry as Components,
+    entity::{Entity, ErasedEntity, registry::TryRegistry as TryEntities},
+    reflect::{Reflect, TypeRegistration, TypeRegistry},
+    resource::registry::Registry as Resources,
+};
+
+use super::{
+    LoadError, LoadErrorKind,
+    parse::{Document, EntityItems, Item, Value, ValueKind, parse},
+    value::{parse_word, write_word},
+};
+
+/// Loads the scene into the world, returning all the created entities in the order of the scene.
+///
+/// Scene is fully checked before loading, including capacity of the entity registry
+/// and capacity of the component storages (if the registry is able to predict entities which will be created next),
+/// so nothing is created or changed if the scene contains any error.
+pub fn load<E, C, R>(
+    entities: &mut E,
+    components: &mut C,
+    resources: &mut R,
+    text: &str,
+) -> Result<Vec<E::Entity>, LoadError>
+where
+    E: TryEntities,
+    E::Entity: Reflect,
+    C: Components,
+    R: Resources,
+{
+    let Document {
+        resources: scene_resources,
+        entities: scene_entities,
+    } = parse(text)?;
+    let len = scene_entities.len();
+    let registry = resources.get::<TypeRegistry>();
+
+    let mut resolved_resources = Vec::with_capacity(scene_resources.len());
+    for item in &scene_resources {
+        let registration = resolve(registry, item)?;
+        let Some(resource) = find_resource(&*resources, registration) else {
+            let error = LoadError::new(item.line, LoadErrorKind::MissingResource);
+            return Err(error.with_type_name(&*item.type_name));
+        };
+        check::<E::Entity>(&item.value, resource, len)
+            .map_err(|error| error.with_type_name(&*item.type_name))?;
+        resolved_resources.push((registration, item));
+    }
+
+    let mut resolved_components = Vec::with_capacity(len);
+    for EntityItems {
+        components: items, ..
+    } in &scene_entities
+    {
+        let mut resolved = Vec::with_capacity(items.len());
+        for item in items {
+            let registration = resolve(registry, item)?;
+            let Some(component) = registration.default_component() else {
+                let error = LoadError::new(item.line, LoadErrorKind::NotComponent);
+                return Err(error.with_type_name(&*item.type_name));
+            };
+            if registration.storage(&*components).is_none() {
+                let error = LoadError::new(item.line, LoadErrorKind::NotRegistered);
+                return Err(error.with_type_name(&*item.type_name));
+            }
+            check::<E::Entity>(&item.value, &*component, len)
+                .map_err(|error| error.with_type_name(&*item.type_name))?;
+            resolved.push((registration, component, item));
+        }
+        resolved_components.push(resolved);
+    }
+
+    if entities.check_capacity(len).is_err() {
+        let count = (1..len)
+            .find(|&count| entities.check_capacity(count).is_err())
+            .unwrap_or(len);
+        let line = scene_entities[count - 1].line;
+        let error = LoadError::new(line, LoadErrorKind::Capacity);
+        return Err(error);
+    }
+    if let Some(next_entities) = entities.next_entities_hint(len) {
+        let mut attached = Vec::<(TypeRegistration, Vec<_>)>::new();
+        for (entity, resolved) in next_entities.zip(&resolved_components) {
+            for &(registration, _, item) in resolved {
+                let type_id = registration.type_id();
+                match attached
+                    .iter_mut()
+                    .find(|(other, _)| other.type_id() == type_id)
+                {
+                    Some((_, items)) => items.push((entity, item)),
+                    None => attached.push((registration, Vec::from([(entity, item)]))),
+                }
+            }
+        }
+        for (registration, items) in &attached {
+            let Some(storage) = registration.storage(&*components) else {
+                unreachable!("component should be registered in the world");
+            };
+            let can_attach = |count: usize| {
+                let mut entities = items[..count]
+                    .iter()
+                    .map(|(entity, _)| entity as &dyn ErasedEntity);
+                !matches!(storage.can_attach_all(&mut entities), Ok(false))
+            };
+            if can_attach(items.len()) {
+                continue;
+            }
+            let count = (1..items.len())
+                .find(|&count| !can_attach(count))
+                .unwrap_or(items.len());
+            let (_, item) = items[count - 1];
+            let error = LoadError::new(item.line, LoadErrorKind::Capacity);
+            return Err(error.with_type_name(&*item.type_name));
+        }
+    }
+
+    let created: Vec<_> = (0..len).map(|_| entities.create()).collect();
+    for (&entity, resolved) in created.iter().zip(resolved_components) {
+        for (registration, mut component, item) in resolved {
+            apply(&item.value, &mut *component, &created)
+                .map_err(|error| error.with_type_name(&*item.type_name))?;
+            let Some(component) = registration.erase_component(&*component) else {
+                unreachable!("default component should be of the registered type");
+            };
+            let Some(storage) = registration.storage_mut(components) else {
+                unreachable!("component should be registered in the world");
+            };
+            let Ok(()) = storage.attach(&entity, component) else {
+                unreachable!("storage should accept the component of its type");
+            };
+        }
+    }
+    for (registration, item) in resolved_resources {
+        let Some(resource) = find_resource_mut(resources, registration) else {
+            unreachable!("resource should be present in the world");
+        };
+        apply(&item.value, resource, &created)
+            .map_err(|error| error.with_type_name(&*item.type_name))?;
+    }
+    Ok(created)
+}
+
+fn resolve(registry: Option<&TypeRegistry>, item: &Item) -> Result<TypeRegistration, LoadError> {
+    let Item {
+        line, type_name, ..
+    } = item;
+    let registration = registry.and_then(|registry| registry.get_by_name(type_name));
+    registration.ok_or_else(|| {
+        let error = LoadError::new(*line, LoadErrorKind::UnknownType);
+        error.with_type_name(&**type_name)
+    })
+}
+
+fn find_resource<R>(resources: &R, registration: TypeRegistration) -> Option<&dyn Reflect>
+where
+    R: Resources,
+{
+    resources.iter().find_map(|resource| {
+        let resource: &dyn Any = resource;
+        registration.reflect(resource)
+    })
+}
+
+fn find_resource_mut<R>(
+    resources: &mut R,
+    registration: TypeRegistration,
+) -> Option<&mut dyn Reflect>
+where
+    R: Resources,
+{
+    resources.iter_mut().find_map(|resource| {
+        let resource: &mut dyn Any = resource;
+        registration.reflect_mut(resource)
+    })
+}
+
+fn is_leaf<E>(target: &dyn Reflect) -> bool
+where
+    E: Entity + Reflect,
+{
+    target.is::<E>() || target.is::<char>() || write_word(target).is_some()
+}
+
+fn check<E>(value: &Value, target: &dyn Reflect, len: usize) -> Result<(), LoadError>
+where
+    E: Entity + Reflect,
+{
+    let &Value { line, ref kind } = value;
+    let is_valid = match kind {
+        &ValueKind::Entity(index) => {
+            if index >= len {
+                let error = LoadError::new(line, LoadErrorKind::UnknownEntity(index));
+                return Err(error);
+            }
+            target.is::<E>()
+        }
+        ValueKind::Char(_) => target.is::<char>(),
+        ValueKind::Word(word) => parse_word(target, word).is_some(),
+        ValueKind::Struct(fields) => {
+            if is_leaf::<E>(target) {
+                let error = LoadError::new(line, LoadErrorKind::InvalidValue);
+                return Err(error);
+            }
+            for (name, value) in fields {
+                let Some(field) = target.field(name) else {
+                    let error = LoadError::new(value.line, LoadErrorKind::UnknownField);
+                    return Err(error);
+                };
+                check::<E>(value, field, len)?;
+            }
+            true
+        }
+    };
+    if !is_valid {
+        let error = LoadError::new(line, LoadErrorKind::InvalidValue);
+        return Err(error);
+    }
+    Ok(())
+}
+
+fn apply<E>(value: &Value, target: &mut dyn Reflect, created: &[E]) -> Result<(), LoadError>
+where
+    E: Entity + Reflect,
+{
+    let &Value { line, ref kind } = value;
+    let result = match kind {
+        &ValueKind::Entity(index) => {
+            let Some(entity) = created.get(index) else {
+                let error = LoadError::new(line, LoadErrorKind::UnknownEntity(index));
+                return Err(error);
+            };
+            target.set(entity)
+        }
+        ValueKind::Char(char) => target.set(char),
+        ValueKind::Word(word) => {
+            let Some(word) = parse_word(target, word) else {
+                let error = LoadError::new(line, LoadErrorKind::InvalidValue);
+                return Err(error);
+            };
+            target.set(&*word)
+        }
+        ValueKind::Struct(fields) => {
+            for (name, value) in fields {
+                let Some(field) = target.field_mut(name) else {
+                    let error = LoadError::new(value.line, LoadErrorKind::UnknownField);
+                    return Err(error);
+                };
+                apply(value, field, created)?;
+            }
+            Ok(())
+        }
+    };
+    result.map_err(|_| LoadError::new(line, LoadErrorKind::InvalidValue))
+}
@@ -0,0 +1,206 @@
+//! Provides utilities for scenes — human-readable text representation of entity sets in ECS.
+//!
+//! Scene contains [reflected](crate::reflect::Reflect) resources of the world
+//! and a set of entities with their reflected components.
+//! Only types registered in the [type registry](crate::reflect::TypeRegistry) of the world take part in the scene,
+//! and components must be [registered as components](crate::reflect::TypeRegistry::register_component())
+//! to be loaded back.
+//!
+//! Scene is written in a RON-like format which is convenient to read, edit and diff:
+//!
+//! ```text
+//! (
+//!     resources: {
+//!         "game::Gravity": (0: -9.8),
+//!     },
+//!     entities: [
+//!         {
+//!             "game::Position": (x: 1.0, y: 2.0),
+//!             "game::Target": (entity: Entity(1)),
+//!         },
+//!         {
+//!             "game::Position": (x: 0.0, y: 0.0),
+//!         },
+//!     ],
+//! )
+//! ```
+//!
+//! Entities are referenced by their position in the scene,
+//! so entity references inside components are remapped to newly created entities when the scene is loaded.
+
+pub use self::error::{
+    ExternalEntityError, LoadError, LoadErrorKind, SaveError, UnsupportedTypeError,
+};
+
+pub(crate) use self::{load::load, save::save};
+
+mod error;
+mod load;
+mod parse;
+mod save;
+mod value;
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use alloc::boxed::Box;
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::{DefaultEntity, registry::array::ArrayRegistry},
+        reflect::{Reflect, TypeRegistry},
+        resource::{Resource, registry::dynamic::DynamicResources},
+        world::World,
+    };
+
+    use super::LoadErrorKind;
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Target {
+        entity: DefaultEntity,
+        symbol: char,
+    }
+
+    impl Component for Target {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Small(u8);
+
+    impl Component for Small {
+        type Storage = ArrayStorage<Self, 1>;
+    }
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+    struct Gravity(f32);
+
+    impl Resource for Gravity {}
+
+    type Components = HList![ArrayStorage<Position, 10>, ArrayStorage<Target, 10>];
+
+    fn new_world() -> World<ArrayRegistry<10>, Components, DynamicResources> {
+        let mut registry = TypeRegistry::new();
+        let _ = registry.register_component::<Position>();
+        let _ = registry.register_component::<Target>();
+        let _ = registry.register::<Gravity>();
+
+        let mut resources = DynamicResources::new();
+        let _ = resources.insert_erased(Box::new(registry));
+        let _ = resources.insert_erased(Box::new(Gravity(-9.8)));
+        let components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, resources)
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut world = new_world();
+        let first = world.create();
+        let second = world.create();
+        let _ = world.attach(first, Position { x: 1.0, y: 2.5 }).unwrap();
+        let target = Target {
+            entity: second,
+            symbol: '\'',
+        };
+        let _ = world.attach(first, target).unwrap();
+        let _ = world.attach(second, Position { x: -3.0, y: 0.0 }).unwrap();
+
+        let text = world.save_scene(&[first, second]).unwrap();
+        assert!(text.contains(r#": (x: 1.0, y: 2.5),"#));
+        assert!(world.save_scene(&[first]).is_err());
+
+        let mut other = new_world();
+        let _ = other.create();
+        let created = other.load_scene(&text).unwrap();
+        assert_eq!(created.len(), 2);
+        assert_eq!(
+            other.get::<Position>(created[0]).unwrap(),
+            Some(&Position { x: 1.0, y: 2.5 }),
+        );
+        let target = other.get::<Target>(created[0]).unwrap().unwrap();
+        assert_eq!(target.entity, created[1]);
+        assert_eq!(target.symbol, '\'');
+        assert_eq!(
+            other.get::<Position>(created[1]).unwrap(),
+            Some(&Position { x: -3.0, y: 0.0 }),
+        );
+        assert_eq!(other.save_scene(&created).unwrap(), text);
+    }
+
+    #[test]
+    fn load_errors() {
+        let position = core::any::type_name::<Position>();
+        let text = alloc::format!(
+            "(\n    resources: {{}},\n    entities: [\n        {{\n            \"{position}\": (x: 1.0, z: 2.0),\n        }},\n    ],\n)"
+        );
+        let mut world = new_world();
+        let error = world.load_scene(&text).unwrap_err();
+        assert_eq!(error.line(), 5);
+        assert_eq!(error.type_name(), Some(position));
+        assert_eq!(error.kind(), LoadErrorKind::UnknownField);
+        assert_eq!(world.entities().len(), 0);
+
+        let text = "(\n    resources: {\n        \"Unknown\": (),\n    },\n    entities: [],\n)";
+        let error = world.load_scene(text).unwrap_err();
+        assert_eq!(error.line(), 3);
+        assert_eq!(error.type_name(), Some("Unknown"));
+        assert_eq!(error.kind(), LoadErrorKind::UnknownType);
+
+        let text = "(\n    resources: {},\n    entities: [\n        { ,\n    ],\n)";
+        let error = world.load_scene(text).unwrap_err();
+        assert_eq!(error.line(), 4);
+        assert_eq!(error.type_name(), None);
+
+        let target = core::any::type_name::<Target>();
+        let text = alloc::format!(
+            "(\n    resources: {{}},\n    entities: [\n        {{\n            \"{target}\": (symbol: '\\u{{fffffffff}}'),\n        }},\n    ],\n)"
+        );
+        let error = world.load_scene(&text).unwrap_err();
+        assert_eq!(error.line(), 5);
+        assert_eq!(error.kind(), LoadErrorKind::InvalidValue);
+    }
+
+    #[test]
+    fn load_capacity() {
+        let position = core::any::type_name::<Position>();
+        let text = alloc::format!(
+            "(\n    resources: {{}},\n    entities: [\n        {{}},\n        {{\n            \"{position}\": (x: 1.0, y: 2.0),\n        }},\n    ],\n)"
+        );
+        let mut world = new_world();
+        for _ in 0..9 {
+            let _ = world.create();
+        }
+        let error = world.load_scene(&text).unwrap_err();
+        assert_eq!(error.line(), 5);
+        assert_eq!(error.type_name(), None);
+        assert_eq!(error.kind(), LoadErrorKind::Capacity);
+        assert_eq!(world.entities().len(), 9);
+
+        let small = core::any::type_name::<Small>();
+        let text = alloc::format!(
+            "(\n    resources: {{}},\n    entities: [\n        {{\n            \"{small}\": (0: 1),\n        }},\n        {{\n            \"{small}\": (0: 2),\n        }},\n    ],\n)"
+        );
+        let mut registry = TypeRegistry::new();
+        let _ = registry.register_component::<Small>();
+        let mut resources = DynamicResources::new();
+        let _ = resources.insert_erased(Box::new(registry));
+        let components = hlist![ArrayStorage::<Small, 1>::new()];
+        let mut world = World::with(ArrayRegistry::<10>::new(), components, resources);
+        let error = world.load_scene(&text).unwrap_err();
+        assert_eq!(error.line(), 8);
+        assert_eq!(error.type_name(), Some(small));
+        assert_eq!(error.kind(), LoadErrorKind::Capacity);
+        assert!(world.entities().is_empty());
+    }
+}
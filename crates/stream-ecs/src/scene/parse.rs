@@ -0,0 +1,315 @@
+use alloc::{string::String, vec::Vec};
+use core::{iter::Peekable, str::Chars};
+
+use super::{LoadError, LoadErrorKind};
+
+/// Scene parsed from the text, but not yet applied to the world.
+pub struct Document {
+    pub resources: Vec<Item>,
+    pub entities: Vec<EntityItems>,
+}
+
+/// Entity of the scene with its reflected components.
+pub struct EntityItems {
+    pub line: usize,
+    pub components: Vec<Item>,
+}
+
+/// Reflected value of some type in the scene.
+pub struct Item {
+    pub line: usize,
+    pub type_name: String,
+    pub value: Value,
+}
+
+/// Value of some field in the scene.
+pub struct Value {
+    pub line: usize,
+    pub kind: ValueKind,
+}
+
+pub enum ValueKind {
+    Struct(Vec<(String, Value)>),
+    Entity(usize),
+    Word(String),
+    Char(char),
+}
+
+#[derive(PartialEq)]
+enum TokenKind {
+    Open(char),
+    Close(char),
+    Colon,
+    Comma,
+    Str(String),
+    Word(String),
+    Char(char),
+}
+
+struct Token {
+    line: usize,
+    kind: TokenKind,
+}
+
+/// Parses the scene from provided text.
+pub fn parse(text: &str) -> Result<Document, LoadError> {
+    let tokens = tokenize(text)?;
+    let last_line = text.lines().count().max(1);
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+        last_line,
+    };
+    let document = parser.document()?;
+    if let Some(Token { line, .. }) = parser.tokens.next() {
+        let error = LoadError::new(line, LoadErrorKind::UnexpectedToken("end of the scene"));
+        return Err(error);
+    }
+    Ok(document)
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, LoadError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    let mut line = 1;
+    while let Some(char) = chars.next() {
+        let kind = match char {
+            '\n' => {
+                line += 1;
+                continue;
+            }
+            char if char.is_whitespace() => continue,
+            '/' if chars.peek() == Some(&'/') => {
+                while chars.next_if(|&char| char != '\n').is_some() {}
+                continue;
+            }
+            '(' | '{' | '[' => TokenKind::Open(char),
+            ')' | '}' | ']' => TokenKind::Close(char),
+            ':' => TokenKind::Colon,
+            ',' => TokenKind::Comma,
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\n') | None => {
+                            let error =
+                                LoadError::new(line, LoadErrorKind::UnexpectedToken("`\"`"));
+                            return Err(error);
+                        }
+                        Some(char) => string.push(char),
+                    }
+                }
+                TokenKind::Str(string)
+            }
+            '\'' => {
+                let char = char_literal(&mut chars)
+                    .ok_or_else(|| LoadError::new(line, LoadErrorKind::InvalidValue))?;
+                TokenKind::Char(char)
+            }
+            char if is_word(char) => {
+                let mut word = String::from(char);
+                while let Some(char) = chars.next_if(|&char| is_word(char)) {
+                    word.push(char);
+                }
+                TokenKind::Word(word)
+            }
+            _ => {
+                let error = LoadError::new(line, LoadErrorKind::UnexpectedToken("value"));
+                return Err(error);
+            }
+        };
+        tokens.push(Token { line, kind });
+    }
+    Ok(tokens)
+}
+
+fn is_word(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '_' | '.' | '+' | '-')
+}
+
+fn char_literal(chars: &mut Peekable<Chars<'_>>) -> Option<char> {
+    let char = match chars.next()? {
+        '\\' => match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+                let mut code: u32 = 0;
+                loop {
+                    let char = chars.next()?;
+                    if char == '}' {
+                        break;
+                    }
+                    code = code.checked_mul(16)?.checked_add(char.to_digit(16)?)?;
+                }
+                char::from_u32(code)?
+            }
+            char @ ('\\' | '\'' | '"') => char,
+            _ => return None,
+        },
+        '\'' | '\n' => return None,
+        char => char,
+    };
+    if chars.next()? != '\'' {
+        return None;
+    }
+    Some(char)
+}
+
+struct Parser<I>
+where
+    I: Iterator<Item = Token>,
+{
+    tokens: Peekable<I>,
+    last_line: usize,
+}
+
+impl<I> Parser<I>
+where
+    I: Iterator<Item = Token>,
+{
+    fn document(&mut self) -> Result<Document, LoadError> {
+        self.expect(TokenKind::Open('('), "`(`")?;
+        self.keyword("resources")?;
+        self.expect(TokenKind::Colon, "`:`")?;
+        let resources = self.map()?;
+        self.expect(TokenKind::Comma, "`,`")?;
+        self.keyword("entities")?;
+        self.expect(TokenKind::Colon, "`:`")?;
+        self.expect(TokenKind::Open('['), "`[`")?;
+        let mut entities = Vec::new();
+        while !self.eat(&TokenKind::Close(']')) {
+            let line = self
+                .tokens
+                .peek()
+                .map_or(self.last_line, |token| token.line);
+            let components = self.map()?;
+            entities.push(EntityItems { line, components });
+            self.separator(']')?;
+        }
+        self.eat(&TokenKind::Comma);
+        self.expect(TokenKind::Close(')'), "`)`")?;
+        Ok(Document {
+            resources,
+            entities,
+        })
+    }
+
+    fn map(&mut self) -> Result<Vec<Item>, LoadError> {
+        self.expect(TokenKind::Open('{'), "`{`")?;
+        let mut items = Vec::new();
+        while !self.eat(&TokenKind::Close('}')) {
+            let Token { line, kind } = self.next()?;
+            let TokenKind::Str(type_name) = kind else {
+                let error = LoadError::new(line, LoadErrorKind::UnexpectedToken("type name"));
+                return Err(error);
+            };
+            self.expect(TokenKind::Colon, "`:`")
+                .map_err(|error| error.with_type_name(&*type_name))?;
+            let value = self
+                .value()
+                .map_err(|error| error.with_type_name(&*type_name))?;
+            items.push(Item {
+                line,
+                type_name,
+                value,
+            });
+            self.separator('}')?;
+        }
+        Ok(items)
+    }
+
+    fn value(&mut self) -> Result<Value, LoadError> {
+        let Token { line, kind } = self.next()?;
+        let kind = match kind {
+            TokenKind::Open('(') => {
+                let mut fields = Vec::new();
+                while !self.eat(&TokenKind::Close(')')) {
+                    let Token { line, kind } = self.next()?;
+                    let TokenKind::Word(name) = kind else {
+                        let error =
+                            LoadError::new(line, LoadErrorKind::UnexpectedToken("field name"));
+                        return Err(error);
+                    };
+                    self.expect(TokenKind::Colon, "`:`")?;
+                    let value = self.value()?;
+                    fields.push((name, value));
+                    self.separator(')')?;
+                }
+                ValueKind::Struct(fields)
+            }
+            TokenKind::Word(word) if word == "Entity" => {
+                self.expect(TokenKind::Open('('), "`(`")?;
+                let Token { line, kind } = self.next()?;
+                let index = match kind {
+                    TokenKind::Word(index) => index.parse().ok(),
+                    _ => None,
+                };
+                let Some(index) = index else {
+                    let error =
+                        LoadError::new(line, LoadErrorKind::UnexpectedToken("entity index"));
+                    return Err(error);
+                };
+                self.expect(TokenKind::Close(')'), "`)`")?;
+                ValueKind::Entity(index)
+            }
+            TokenKind::Word(word) => ValueKind::Word(word),
+            TokenKind::Char(char) => ValueKind::Char(char),
+            _ => {
+                let error = LoadError::new(line, LoadErrorKind::UnexpectedToken("value"));
+                return Err(error);
+            }
+        };
+        Ok(Value { line, kind })
+    }
+
+    fn keyword(&mut self, keyword: &'static str) -> Result<(), LoadError> {
+        let Token { line, kind } = self.next()?;
+        match kind {
+            TokenKind::Word(word) if word == keyword => Ok(()),
+            _ => Err(LoadError::new(
+                line,
+                LoadErrorKind::UnexpectedToken(keyword),
+            )),
+        }
+    }
+
+    fn separator(&mut self, close: char) -> Result<(), LoadError> {
+        if self.eat(&TokenKind::Comma) {
+            return Ok(());
+        }
+        match self.tokens.peek() {
+            Some(Token { kind, .. }) if *kind == TokenKind::Close(close) => Ok(()),
+            Some(&Token { line, .. }) => {
+                Err(LoadError::new(line, LoadErrorKind::UnexpectedToken("`,`")))
+            }
+            None => Err(LoadError::new(self.last_line, LoadErrorKind::UnexpectedEnd)),
+        }
+    }
+
+    fn expect(&mut self, expected: TokenKind, name: &'static str) -> Result<(), LoadError> {
+        let Token { line, kind } = self.next()?;
+        if kind != expected {
+            let error = LoadError::new(line, LoadErrorKind::UnexpectedToken(name));
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn eat(&mut self, expected: &TokenKind) -> bool {
+        self.tokens
+            .next_if(|token| token.kind == *expected)
+            .is_some()
+    }
+
+    fn next(&mut self) -> Result<Token, LoadError> {
+        let Self { tokens, last_line } = self;
+        tokens
+            .next()
+            .ok_or_else(|| LoadError::new(*last_line, LoadErrorKind::UnexpectedEnd))
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::any::Any;
+
+use crate::{
+    component::registry::Registry as Components,
+    entity::{
+        Entity,
+        registry::{NotPresentError, Registry as Entities},
+    },
+    reflect::{Reflect, TypeRegistry},
+    resource::registry::Registry as Resources,
+};
+
+use super::{ExternalEntityError, SaveError, UnsupportedTypeError, value::write_word};
+
+/// Writes provided entities with their reflected components
+/// and reflected resources of the world into the scene.
+///
+/// Only components and resources which types were registered
+/// in the type registry of the world are written into the scene.
+pub fn save<E, C, R>(
+    entities: &E,
+    components: &C,
+    resources: &R,
+    scene: &[E::Entity],
+) -> Result<String, SaveError<E::Entity>>
+where
+    E: Entities,
+    E::Entity: Reflect + PartialEq,
+    C: Components,
+    R: Resources,
+{
+    if let Some(&entity) = scene.iter().find(|&&entity| !entities.contains(entity)) {
+        let error = NotPresentError::new(entity);
+        return Err(error.into());
+    }
+    let registry = resources.get::<TypeRegistry>();
+    let mut writer = Writer {
+        out: String::new(),
+        scene,
+    };
+
+    writer.out.push_str("(\n    resources: {\n");
+    for resource in resources.iter() {
+        let resource: &dyn Any = resource;
+        let Some(resource) = registry
+            .and_then(|registry| registry.get(resource.type_id()))
+            .and_then(|registration| registration.reflect(resource))
+        else {
+            continue;
+        };
+        writer.item(resource, 2)?;
+    }
+    writer.out.push_str("    },\n    entities: [\n");
+    for &entity in scene {
+        writer.out.push_str("        {\n");
+        for storage in components.iter() {
+            let Ok(Some(component)) = storage.get(&entity) else {
+                continue;
+            };
+            let component: &dyn Any = component;
+            let Some(component) = registry
+                .and_then(|registry| registry.get(storage.component_type_id()))
+                .and_then(|registration| registration.reflect(component))
+            else {
+                continue;
+            };
+            writer.item(component, 3)?;
+        }
+        writer.out.push_str("        },\n");
+    }
+    writer.out.push_str("    ],\n)\n");
+
+    let Writer { out, .. } = writer;
+    Ok(out)
+}
+
+struct Writer<'scene, E> {
+    out: String,
+    scene: &'scene [E],
+}
+
+impl<E> Writer<'_, E>
+where
+    E: Entity + Reflect + PartialEq,
+{
+    fn item(&mut self, value: &dyn Reflect, depth: usize) -> Result<(), SaveError<E>> {
+        let Self { out, .. } = self;
+        for _ in 0..depth {
+            out.push_str("    ");
+        }
+        out.push('"');
+        out.push_str(value.type_name());
+        out.push_str("\": ");
+        self.value(value)?;
+        self.out.push_str(",\n");
+        Ok(())
+    }
+
+    fn value(&mut self, value: &dyn Reflect) -> Result<(), SaveError<E>> {
+        let Self { out, scene } = self;
+        if let Some(&entity) = value.downcast_ref::<E>() {
+            let Some(index) = scene.iter().position(|&other| other == entity) else {
+                let error = ExternalEntityError::new(entity);
+                return Err(error.into());
+            };
+            out.push_str("Entity(");
+            out.push_str(&index.to_string());
+            out.push(')');
+            return Ok(());
+        }
+        if let Some(word) = write_word(value) {
+            out.push_str(&word);
+            return Ok(());
+        }
+        if let Some(char) = value.downcast_ref::<char>() {
+            out.push_str(&format!("{char:?}"));
+            return Ok(());
+        }
+        if value.is::<&'static str>() {
+            let error = UnsupportedTypeError::new(value.type_name());
+            return Err(error.into());
+        }
+
+        out.push('(');
+        for (index, info) in value.fields().enumerate() {
+            if index > 0 {
+                self.out.push_str(", ");
+            }
+            self.out.push_str(info.name());
+            self.out.push_str(": ");
+            let Some(field) = value.field_at(index) else {
+                unreachable!("index of the field should be in bounds");
+            };
+            self.value(field)?;
+        }
+        self.out.push(')');
+        Ok(())
+    }
+}
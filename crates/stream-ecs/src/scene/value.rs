@@ -0,0 +1,77 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{fmt::Display, str::FromStr};
+
+use crate::reflect::Reflect;
+
+/// Writes provided value as a single word if it is of some primitive type.
+pub fn write_word(value: &dyn Reflect) -> Option<String> {
+    display::<bool>(value)
+        .or_else(|| display::<u8>(value))
+        .or_else(|| display::<u16>(value))
+        .or_else(|| display::<u32>(value))
+        .or_else(|| display::<u64>(value))
+        .or_else(|| display::<u128>(value))
+        .or_else(|| display::<usize>(value))
+        .or_else(|| display::<i8>(value))
+        .or_else(|| display::<i16>(value))
+        .or_else(|| display::<i32>(value))
+        .or_else(|| display::<i64>(value))
+        .or_else(|| display::<i128>(value))
+        .or_else(|| display::<isize>(value))
+        .or_else(|| float::<f32>(value))
+        .or_else(|| float::<f64>(value))
+}
+
+/// Parses a single word as a value of the same primitive type as provided target.
+/// Returns [`None`] if the target is not of some primitive type or the word is invalid.
+pub fn parse_word(target: &dyn Reflect, word: &str) -> Option<Box<dyn Reflect>> {
+    parse::<bool>(target, word)
+        .or_else(|| parse::<u8>(target, word))
+        .or_else(|| parse::<u16>(target, word))
+        .or_else(|| parse::<u32>(target, word))
+        .or_else(|| parse::<u64>(target, word))
+        .or_else(|| parse::<u128>(target, word))
+        .or_else(|| parse::<usize>(target, word))
+        .or_else(|| parse::<i8>(target, word))
+        .or_else(|| parse::<i16>(target, word))
+        .or_else(|| parse::<i32>(target, word))
+        .or_else(|| parse::<i64>(target, word))
+        .or_else(|| parse::<i128>(target, word))
+        .or_else(|| parse::<isize>(target, word))
+        .or_else(|| parse::<f32>(target, word))
+        .or_else(|| parse::<f64>(target, word))
+}
+
+fn display<T>(value: &dyn Reflect) -> Option<String>
+where
+    T: Reflect + Display,
+{
+    let value = value.downcast_ref::<T>()?;
+    Some(value.to_string())
+}
+
+fn float<T>(value: &dyn Reflect) -> Option<String>
+where
+    T: Reflect + Display,
+{
+    let value = value.downcast_ref::<T>()?;
+    let mut word = value.to_string();
+    if !word.contains(['.', 'e', 'N', 'i']) {
+        word.push_str(".0");
+    }
+    Some(word)
+}
+
+fn parse<T>(target: &dyn Reflect, word: &str) -> Option<Box<dyn Reflect>>
+where
+    T: Reflect + FromStr,
+{
+    if !target.is::<T>() {
+        return None;
+    }
+    let value: T = word.parse().ok()?;
+    Some(Box::new(value))
+}
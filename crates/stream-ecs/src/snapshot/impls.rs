@@ -0,0 +1,48 @@
+use hlist::{Cons, Nil};
+
+use crate::component::storage::TrackedStorage;
+
+use super::SnapshotComponents;
+
+impl SnapshotComponents for Nil {
+    fn record_changed(&self, _: &mut Self) -> usize {
+        0
+    }
+
+    fn restore_changed(&mut self, _: &Self) -> usize {
+        0
+    }
+}
+
+impl<Head, Tail> SnapshotComponents for Cons<Head, Tail>
+where
+    Head: TrackedStorage + Clone,
+    Tail: SnapshotComponents,
+{
+    fn record_changed(&self, snapshot: &mut Self) -> usize {
+        let Cons(head, tail) = self;
+        let Cons(snapshot_head, snapshot_tail) = snapshot;
+
+        let mut count = tail.record_changed(snapshot_tail);
+        if head.change_tick() != snapshot_head.change_tick() {
+            snapshot_head.clone_from(head);
+            count += 1;
+        }
+        count
+    }
+
+    fn restore_changed(&mut self, snapshot: &Self) -> usize {
+        let Cons(head, tail) = self;
+        let Cons(snapshot_head, snapshot_tail) = snapshot;
+
+        let mut count = tail.restore_changed(snapshot_tail);
+        let tick = head.change_tick();
+        if tick != snapshot_head.change_tick() {
+            let tick = tick.max(snapshot_head.change_tick()).next();
+            head.clone_from(snapshot_head);
+            head.set_change_tick(tick);
+            count += 1;
+        }
+        count
+    }
+}
@@ -0,0 +1,48 @@
+//! Provides utilities for snapshots of ECS worlds.
+//!
+//! Snapshot stores copies of the entity, component and resource registries of the [world](crate::world::World),
+//! so the world can be restored from it later (for example, to roll back the world state in netcode).
+//!
+//! Snapshots are designed to be reused: the world is copied into an existing snapshot,
+//! and only storages which [change tick](crate::component::storage::ChangeTick) was advanced
+//! are copied when recording the delta.
+
+pub use self::snapshot::Snapshot;
+
+mod impls;
+mod snapshot;
+
+/// Component registry which can be recorded into the [snapshot](Snapshot)
+/// storage by storage, copying only the storages which were changed.
+///
+/// This trait is implemented for heterogenous lists of [tracked storages](crate::component::storage::TrackedStorage).
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+pub trait SnapshotComponents: Clone {
+    /// Copies storages which change tick differs from the change tick of the same storage of provided snapshot.
+    /// Returns count of copied storages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn record_changed(&self, snapshot: &mut Self) -> usize;
+
+    /// Restores storages which change tick differs from the change tick of the same storage of provided snapshot.
+    /// Returns count of restored storages.
+    ///
+    /// Change tick of each restored storage is advanced past both its previous tick and the tick of the snapshot,
+    /// so it can be distinguished from any state recorded earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    fn restore_changed(&mut self, snapshot: &Self) -> usize;
+}
@@ -0,0 +1,133 @@
+#![allow(clippy::module_inception)]
+
+/// Snapshot of the [world](crate::world::World) which can be used to restore the world later.
+///
+/// Snapshot is created by the [`snapshot`](crate::world::World::snapshot()) method of the world
+/// and can be updated in place by the [`snapshot_into`](crate::world::World::snapshot_into())
+/// and [`snapshot_delta_into`](crate::world::World::snapshot_delta_into()) methods of the world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone)]
+pub struct Snapshot<E, C, R> {
+    pub(crate) entities: E,
+    pub(crate) components: C,
+    pub(crate) resources: R,
+}
+
+impl<E, C, R> Snapshot<E, C, R> {
+    /// Retrieves a reference of the entity registry recorded by this snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn entities(&self) -> &E {
+        let Self { entities, .. } = self;
+        entities
+    }
+
+    /// Retrieves a reference of the component registry recorded by this snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn components(&self) -> &C {
+        let Self { components, .. } = self;
+        components
+    }
+
+    /// Retrieves a reference of the resource registry recorded by this snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub const fn resources(&self) -> &R {
+        let Self { resources, .. } = self;
+        resources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        world::World,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Velocity(i32);
+
+    impl Component for Velocity {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    type Components = HList![ArrayStorage<Position, 10>, ArrayStorage<Velocity, 10>];
+
+    fn world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn restore() {
+        let mut world = world();
+        let first = world.create();
+        let second = world.create();
+        let _ = world.attach(first, Position(1)).unwrap();
+        let _ = world.attach(second, Velocity(2)).unwrap();
+
+        let snapshot = world.snapshot();
+        world.destroy(first).unwrap();
+        let third = world.create();
+        assert_eq!(third.index(), first.index());
+        assert_ne!(third.generation(), first.generation());
+        let _ = world.attach(third, Position(3)).unwrap();
+
+        world.restore(&snapshot);
+        assert!(world.contains(first));
+        assert!(world.contains(second));
+        assert!(!world.contains(third));
+        assert_eq!(world.get::<Position>(first).unwrap(), Some(&Position(1)));
+        assert_eq!(world.get::<Velocity>(second).unwrap(), Some(&Velocity(2)));
+
+        world.destroy(first).unwrap();
+        assert_eq!(world.create(), third);
+    }
+
+    #[test]
+    fn delta() {
+        let mut world = world();
+        let entity = world.create();
+        let mut snapshot = world.snapshot();
+        assert_eq!(world.snapshot_delta_into(&mut snapshot), 0);
+
+        let _ = world.attach(entity, Velocity(1)).unwrap();
+        assert_eq!(world.snapshot_delta_into(&mut snapshot), 1);
+        assert_eq!(world.snapshot_delta_into(&mut snapshot), 0);
+
+        let _ = world.attach(entity, Velocity(2)).unwrap();
+        world.restore(&snapshot);
+        assert_eq!(world.get::<Velocity>(entity).unwrap(), Some(&Velocity(1)));
+        assert_eq!(world.get::<Position>(entity).unwrap(), None);
+        assert_eq!(world.snapshot_delta_into(&mut snapshot), 1);
+    }
+}
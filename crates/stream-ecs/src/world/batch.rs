@@ -0,0 +1,289 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    component::{
+        bundle::Bundle, registry::Registry as Components, storage::bundle::Bundle as StorageBundle,
+    },
+    entity::Entity,
+};
+
+/// Iterator over entities spawned by the world in a batch.
+///
+/// Bundles are attached to newly created entities while iterating,
+/// but all the remaining bundles are attached when the iterator is dropped.
+#[derive(Debug)]
+pub(super) struct SpawnBatch<'state, I, N, C>
+where
+    I: Iterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: Iterator,
+    N::Item: Entity,
+    C: Components,
+{
+    bundles: I,
+    entities: N,
+    components: &'state mut C,
+}
+
+impl<'state, I, N, C> SpawnBatch<'state, I, N, C>
+where
+    I: Iterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: Iterator,
+    N::Item: Entity,
+    C: Components,
+{
+    pub(super) fn new(bundles: I, entities: N, components: &'state mut C) -> Self {
+        Self {
+            bundles,
+            entities,
+            components,
+        }
+    }
+}
+
+impl<I, N, C> Iterator for SpawnBatch<'_, I, N, C>
+where
+    I: Iterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: Iterator,
+    N::Item: Entity,
+    C: Components,
+{
+    type Item = N::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Self {
+            bundles,
+            entities,
+            components,
+        } = self;
+
+        let bundle = bundles.next()?;
+        let entity = entities.next()?;
+        // capacity of the bundle storages was checked before creating the entities
+        let Ok(_) = I::Item::attach(*components, entity, bundle) else {
+            unreachable!("registration of the bundle was checked before creating the entities");
+        };
+        Some(entity)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Self {
+            bundles, entities, ..
+        } = self;
+
+        let (bundles_lower, bundles_upper) = bundles.size_hint();
+        let (entities_lower, entities_upper) = entities.size_hint();
+        let lower = bundles_lower.min(entities_lower);
+        let upper = match (bundles_upper, entities_upper) {
+            (Some(bundles), Some(entities)) => Some(bundles.min(entities)),
+            (Some(upper), None) | (None, Some(upper)) => Some(upper),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<I, N, C> ExactSizeIterator for SpawnBatch<'_, I, N, C>
+where
+    I: ExactSizeIterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: ExactSizeIterator,
+    N::Item: Entity,
+    C: Components,
+{
+}
+
+impl<I, N, C> FusedIterator for SpawnBatch<'_, I, N, C>
+where
+    I: FusedIterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: FusedIterator,
+    N::Item: Entity,
+    C: Components,
+{
+}
+
+impl<I, N, C> Drop for SpawnBatch<'_, I, N, C>
+where
+    I: Iterator,
+    I::Item: Bundle,
+    <I::Item as Bundle>::Storages: StorageBundle<Entity = N::Item>,
+    N: Iterator,
+    N::Item: Entity,
+    C: Components,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::any::TypeId;
+    use std::hash::RandomState;
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{
+            Component,
+            registry::Registry as _,
+            storage::array::{ArrayStorage, HashArrayStorage},
+        },
+        entity::{
+            builder::TryBuildError,
+            registry::{
+                Registry as _,
+                array::{ArrayRegistry, ArrayRegistryError},
+                tests::NoHintRegistry,
+            },
+        },
+        world::World,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Speed(i32);
+
+    impl Component for Speed {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Tag;
+
+    impl Component for Tag {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Health(i32);
+
+    impl Component for Health {
+        type Storage = ArrayStorage<Self, 2>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Score(i32);
+
+    impl Component for Score {
+        type Storage = HashArrayStorage<Self, RandomState, 3>;
+    }
+
+    type Components = HList![
+        ArrayStorage<Position, 10>,
+        ArrayStorage<Speed, 10>,
+        ArrayStorage<Health, 2>,
+    ];
+
+    fn world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![
+            ArrayStorage::new(),
+            ArrayStorage::new(),
+            ArrayStorage::new()
+        ];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn spawn_batch() {
+        let mut world = world();
+        let bundles = (0..4).map(|index| hlist![Position(index), Speed(-index)]);
+        let entities: std::vec::Vec<_> = world.spawn_batch(bundles).unwrap().collect();
+        assert_eq!(entities.len(), 4);
+        for (index, &entity) in (0..).zip(&entities) {
+            assert_eq!(
+                world.get::<Position>(entity).unwrap(),
+                Some(&Position(index))
+            );
+            assert_eq!(world.get::<Speed>(entity).unwrap(), Some(&Speed(-index)));
+        }
+
+        let batch = world.spawn_batch([Position(10), Position(11)]).unwrap();
+        drop(batch);
+        assert_eq!(world.entities().len(), 6);
+        let attached = world
+            .entities()
+            .iter()
+            .filter(|&entity| world.get::<Position>(entity).unwrap().is_some())
+            .count();
+        assert_eq!(attached, 6);
+    }
+
+    #[test]
+    fn spawn_batch_error() {
+        let mut world = world();
+        let Err(error) = world.spawn_batch([hlist![Position(0), Tag]]) else {
+            panic!("bundle should not be registered");
+        };
+        assert!(matches!(error, TryBuildError::NotRegistered(_)));
+        assert!(world.entities().is_empty());
+
+        let Err(error) = world.spawn_batch([Speed(0); 11]) else {
+            panic!("registry capacity should be exceeded");
+        };
+        assert!(matches!(
+            error,
+            TryBuildError::Entities(ArrayRegistryError::Capacity),
+        ));
+        assert!(world.entities().is_empty());
+    }
+
+    #[test]
+    fn spawn_batch_storage_capacity() {
+        let mut world = world();
+        let bundles = (0..3).map(|index| hlist![Position(index), Health(index)]);
+        let Err(error) = world.spawn_batch(bundles) else {
+            panic!("storage capacity should be exceeded");
+        };
+        assert!(matches!(error, TryBuildError::Storage(_)));
+        assert!(world.entities().is_empty());
+        assert!(world.components().get::<Position>().unwrap().is_empty());
+        assert!(world.components().get::<Health>().unwrap().is_empty());
+
+        let bundles = (0..2).map(|index| hlist![Position(index), Health(index)]);
+        let entities: std::vec::Vec<_> = world.spawn_batch(bundles).unwrap().collect();
+        assert_eq!(entities.len(), 2);
+    }
+
+    #[test]
+    fn spawn_batch_without_hint() {
+        let components = hlist![
+            ArrayStorage::<Position, 10>::new(),
+            HashArrayStorage::<Score, RandomState, 3>::new(),
+        ];
+        let mut world = World::with(NoHintRegistry::<10>::default(), components, ());
+
+        let Err(TryBuildError::Storage(error)) = world.spawn_batch([Position(0)]) else {
+            panic!("array storage capacity cannot be checked without a hint");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Position>());
+        let Err(TryBuildError::Storage(error)) = world.spawn_batch([Score(0); 4]) else {
+            panic!("hash storage capacity should be exceeded");
+        };
+        assert_eq!(error.type_id(), TypeId::of::<Score>());
+        assert!(world.entities().is_empty());
+
+        let entities: std::vec::Vec<_> = world.spawn_batch([Score(1); 3]).unwrap().collect();
+        assert_eq!(entities.len(), 3);
+        for entity in entities {
+            assert_eq!(world.get::<Score>(entity).unwrap(), Some(&Score(1)));
+        }
+        let result = world.spawn_batch([Score(2)]).map(drop);
+        assert!(matches!(result, Err(TryBuildError::Storage(_))));
+        assert_eq!(world.entities().len(), 3);
+    }
+}
@@ -0,0 +1,207 @@
+use core::any::TypeId;
+
+/// Checksum of the single component storage of the world.
+///
+/// Checksums of storages allow to narrow a desync of the worlds
+/// to the type of component responsible for it.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StorageChecksum {
+    type_name: &'static str,
+    type_id: TypeId,
+    checksum: u64,
+}
+
+impl StorageChecksum {
+    /// Creates new checksum of the storage of provided type of component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn new(type_name: &'static str, type_id: TypeId, checksum: u64) -> Self {
+        Self {
+            type_name,
+            type_id,
+            checksum,
+        }
+    }
+
+    /// Returns type name of components which are stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_name(self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns [`TypeId`] of components which are stored in the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn type_id(self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns checksum of the storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn checksum(self) -> u64 {
+        self.checksum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::Hasher;
+    use std::hash::DefaultHasher;
+
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{
+            Component, hash_checksum,
+            storage::array::{ArrayStorage, DenseArrayStorage},
+        },
+        entity::registry::array::{ArrayRegistry, DenseArrayRegistry},
+        world::World,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Hash)]
+    struct Health(u32);
+
+    impl Component for Health {
+        type Storage = DenseArrayStorage<Self, 10>;
+
+        const CHECKSUM: Option<fn(&Self, &mut dyn Hasher)> = Some(hash_checksum::<Self>);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Speed(f32);
+
+    impl Component for Speed {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    type Components = HList![DenseArrayStorage<Health, 10>, ArrayStorage<Speed, 10>];
+
+    fn world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![DenseArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn layout_independent() {
+        let mut first = world();
+        let mut second = world();
+        let entities = [first.create(), first.create(), first.create()];
+        for entity in entities {
+            assert_eq!(second.create(), entity);
+        }
+
+        for (index, &entity) in entities.iter().enumerate() {
+            let _ = first.attach(entity, Health(index as u32)).unwrap();
+        }
+        for (index, &entity) in entities.iter().enumerate().rev() {
+            let _ = second.attach(entity, Health(index as u32)).unwrap();
+        }
+        let _ = first.attach(entities[0], Speed(1.0)).unwrap();
+        let _ = second.attach(entities[0], Speed(2.0)).unwrap();
+
+        assert_eq!(
+            first.checksum::<DefaultHasher>(),
+            second.checksum::<DefaultHasher>(),
+        );
+        assert_eq!(first.storage_checksums::<DefaultHasher>().count(), 1);
+    }
+
+    #[test]
+    fn desync() {
+        let mut first = world();
+        let mut second = world();
+        let entity = first.create();
+        let _ = second.create();
+        let _ = first.attach(entity, Health(1)).unwrap();
+        let _ = second.attach(entity, Health(2)).unwrap();
+
+        assert_eq!(
+            first.entities_checksum::<DefaultHasher>(),
+            second.entities_checksum::<DefaultHasher>(),
+        );
+        assert_ne!(
+            first.checksum::<DefaultHasher>(),
+            second.checksum::<DefaultHasher>(),
+        );
+        let mismatch = first
+            .storage_checksums::<DefaultHasher>()
+            .zip(second.storage_checksums::<DefaultHasher>())
+            .find(|(first, second)| first != second)
+            .map(|(storage, _)| storage.type_name());
+        assert_eq!(mismatch, Some(core::any::type_name::<Health>()));
+    }
+
+    #[test]
+    fn dead_entities() {
+        let mut first = world();
+        let mut second = world();
+        let entities = [first.create(), first.create()];
+        for entity in entities {
+            assert_eq!(second.create(), entity);
+            let _ = first.attach(entity, Health(1)).unwrap();
+            let _ = second.attach(entity, Health(1)).unwrap();
+        }
+
+        first.destroy(entities[0]).unwrap();
+        second.entities_mut().destroy(entities[0]).unwrap();
+        assert_eq!(second.get::<Health>(entities[1]).unwrap(), Some(&Health(1)));
+        assert_eq!(
+            first.checksum::<DefaultHasher>(),
+            second.checksum::<DefaultHasher>(),
+        );
+    }
+
+    #[test]
+    fn registry_order_independent() {
+        let components = || hlist![DenseArrayStorage::new(), ArrayStorage::new()];
+        let mut first: World<DenseArrayRegistry<10>, Components, ()> =
+            World::with(DenseArrayRegistry::new(), components(), ());
+        let mut second: World<DenseArrayRegistry<10>, Components, ()> =
+            World::with(DenseArrayRegistry::new(), components(), ());
+        let entities = [(); 4].map(|()| first.create());
+        for entity in entities {
+            assert_eq!(second.create(), entity);
+            let _ = first.attach(entity, Health(1)).unwrap();
+            let _ = second.attach(entity, Health(1)).unwrap();
+        }
+
+        first.destroy(entities[0]).unwrap();
+        first.destroy(entities[1]).unwrap();
+        second.destroy(entities[1]).unwrap();
+        second.destroy(entities[0]).unwrap();
+        assert!(!first.entities().iter().eq(second.entities().iter()));
+        assert_eq!(
+            first.entities_checksum::<DefaultHasher>(),
+            second.entities_checksum::<DefaultHasher>(),
+        );
+        assert_eq!(
+            first.checksum::<DefaultHasher>(),
+            second.checksum::<DefaultHasher>(),
+        );
+    }
+}
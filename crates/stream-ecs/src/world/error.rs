@@ -1,7 +1,10 @@
 use derive_more::{Display, From};
 
 use crate::{
-    component::bundle::{NotRegisteredError, TryBundleError},
+    component::{
+        bundle::{NotRegisteredError, TryBundleError},
+        storage::{StaleEntityError, StorageCapacityError},
+    },
     entity::{DefaultEntity, Entity, registry::NotPresentError},
 };
 
@@ -24,6 +27,28 @@ where
     NotPresent(NotPresentError<E>),
 }
 
+/// The error type which is returned when operating with entities in the ECS world
+/// with respect to generations of entities stored in component storages.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(E: Display))]
+pub enum CheckedEntityError<E = DefaultEntity>
+where
+    E: Entity,
+{
+    /// Component was not registered in the world.
+    NotRegistered(NotRegisteredError),
+    /// Entity was not present in the world.
+    NotPresent(NotPresentError<E>),
+    /// Entity was stale, so the storage has a component attached to the newer entity.
+    Stale(StaleEntityError<E>),
+}
+
 /// The error type which is returned when trying to attach a bundle to the entity in the world.
 ///
 /// # Examples
@@ -58,3 +83,21 @@ where
         }
     }
 }
+
+/// The error type which is returned when trying to merge other world into the current world.
+///
+/// # Examples
+///
+/// ```
+/// todo!()
+/// ```
+#[derive(Debug, Display, Clone, Copy, From)]
+#[display(bound(Err: Display))]
+pub enum MergeError<Err> {
+    /// Entity registry failed to create new entities.
+    #[from(ignore)]
+    #[display("entity registry failed to create new entities: {_0}")]
+    Entities(Err),
+    /// Component storage does not have enough capacity for moved components.
+    Storage(StorageCapacityError),
+}
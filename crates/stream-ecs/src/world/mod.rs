@@ -2,12 +2,15 @@
 
 pub use self::{
     builder::EntityBuilder,
-    error::{EntityError, TryAttachError},
+    checksum::StorageChecksum,
+    error::{CheckedEntityError, EntityError, MergeError, TryAttachError},
     view::{View, ViewRef},
     world::World,
 };
 
+mod batch;
 mod builder;
+mod checksum;
 mod error;
 mod view;
 mod world;
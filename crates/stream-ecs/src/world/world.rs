@@ -1,7 +1,33 @@
 #![allow(clippy::module_inception)]
 
+use core::{
+    hash::{Hash, Hasher},
+    iter,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::any::{Any, TypeId};
+
+use arrayvec::ArrayVec;
+
+#[cfg(feature = "alloc")]
+use crate::{
+    binary::MigrationRegistry,
+    component::dynamic::{DynamicFetch, DynamicQuery, DynamicQueryIter, DynamicStorages},
+    entity::map::EntityMap,
+    reflect::TypeRegistry,
+    scene::{self, LoadError, SaveError},
+};
+
+#[cfg(feature = "alloc")]
+use super::error::MergeError;
+
 use crate::{
+    binary::{self, ComponentSchema, DecodeError, EncodeError},
     component::{
+        Component, ErasedComponent,
         bundle::{
             Bundle, GetBundle, GetBundleMut, NotRegisteredError, ProvideBundle, ProvideBundleMut,
             TryBundle, TryBundleError,
@@ -10,13 +36,22 @@ use crate::{
             Registry as Components, RegistryMut as ComponentsMut,
             TryRegistryMut as TryComponentsMut, With as WithComponents,
         },
-        storage::bundle::{Bundle as StorageBundle, TryBundle as StorageTryBundle},
+        storage::{
+            CheckedStorage, Storage, StorageCapacityError, StorageEntry,
+            bundle::{Bundle as StorageBundle, TryBundle as StorageTryBundle},
+        },
     },
     entity::{
+        Entity, ErasedEntity,
         builder::{TryBuildError, TryEntityBuildError},
         entry::{Entry, EntryMut},
-        registry::{NotPresentError, Registry as Entities, TryRegistry as TryEntities},
+        map::EntityMapper,
+        registry::{self, NotPresentError, Registry as Entities, TryRegistry as TryEntities},
     },
+    hierarchy::{self, BreadthFirstIter, ChildrenIter, DepthFirstIter, HierarchyError, Parent},
+    prefab::{self, InstantiateError, Prefab},
+    reflect::Reflect,
+    relation::{CleanupPolicy, Relation, RelationError, Targets, TargetsIter},
     resource::{
         bundle::{
             Bundle as ResourceBundle, GetBundle as ResourceGetBundle,
@@ -28,12 +63,15 @@ use crate::{
             With as WithResources,
         },
     },
+    snapshot::{Snapshot, SnapshotComponents},
     view::query::{Query, ReadonlyQuery},
 };
 
 use super::{
+    batch::SpawnBatch,
     builder::EntityBuilder,
-    error::{EntityError, TryAttachError},
+    checksum::StorageChecksum,
+    error::{CheckedEntityError, EntityError, TryAttachError},
     view::View,
 };
 
@@ -877,6 +915,414 @@ where
         Ok(bundle)
     }
 
+    /// Retrieves a reference to the component attached to provided entity.
+    /// Returns [`None`] if provided entity does not have component of such type.
+    ///
+    /// Unlike [`get`][World::get()] method, this method reports stale entities
+    /// (when the storage has a component attached to the newer entity with the same index)
+    /// as an error instead of returning [`None`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity is stale, does not present in the world
+    /// or the component was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn get_checked<T>(
+        &self,
+        entity: E::Entity,
+    ) -> Result<Option<&T>, CheckedEntityError<E::Entity>>
+    where
+        T: Component,
+        T::Storage: CheckedStorage<Entity = E::Entity>,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        let Some(storage) = components.get::<T>() else {
+            let error = NotRegisteredError::new::<T>();
+            return Err(error.into());
+        };
+        let component = storage.get_checked(entity)?;
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        Ok(component)
+    }
+
+    /// Retrieves an entry of the component attached to provided entity
+    /// for in-place manipulation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or the component was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn component_entry<T>(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<StorageEntry<'_, T::Storage>, EntityError<E::Entity>>
+    where
+        T: Component,
+        T::Storage: Storage<Entity = E::Entity>,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let Some(storage) = components.get_mut::<T>() else {
+            let error = NotRegisteredError::new::<T>();
+            return Err(error.into());
+        };
+        let entry = storage.entry(entity);
+        Ok(entry)
+    }
+
+    /// Creates new entity in the world with copies of all components attached to provided entity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if storage of any component attached to provided entity
+    /// does not have enough capacity for the new entity.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_clone_entity`][World::try_clone_entity()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn clone_entity(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<E::Entity, NotPresentError<E::Entity>> {
+        match self.try_clone_entity(entity) {
+            Ok(clone) => Ok(clone),
+            Err(TryAttachError::NotPresent(error)) => Err(error),
+            Err(TryAttachError::NotRegistered(error)) => panic!("{error}"),
+            Err(TryAttachError::Storage(error)) => panic!("{error}"),
+        }
+    }
+
+    /// Tries to create new entity in the world with copies of all components attached to provided entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or storage of any component attached to provided entity
+    /// does not have enough capacity for the new entity.
+    /// New entity is destroyed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`clone_entity`][World::clone_entity()] method.
+    pub fn try_clone_entity(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<E::Entity, TryAttachError<StorageCapacityError, E::Entity>> {
+        let Self { entities, .. } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let clone = entities.create();
+        if let Err(error) = self.try_clone_into(entity, clone) {
+            let Self { entities, .. } = self;
+            let _ = entities.destroy(clone);
+            return Err(error);
+        }
+        Ok(clone)
+    }
+
+    /// Copies all components attached to the `from` entity to the `to` entity.
+    ///
+    /// Components of the `to` entity which are not attached to the `from` entity are left untouched.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if storage of any component attached to the `from` entity
+    /// does not have enough capacity for the `to` entity.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_clone_into`][World::try_clone_into()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn clone_into(
+        &mut self,
+        from: E::Entity,
+        to: E::Entity,
+    ) -> Result<(), NotPresentError<E::Entity>> {
+        match self.try_clone_into(from, to) {
+            Ok(()) => Ok(()),
+            Err(TryAttachError::NotPresent(error)) => Err(error),
+            Err(TryAttachError::NotRegistered(error)) => panic!("{error}"),
+            Err(TryAttachError::Storage(error)) => panic!("{error}"),
+        }
+    }
+
+    /// Tries to copy all components attached to the `from` entity to the `to` entity.
+    ///
+    /// Components of the `to` entity which are not attached to the `from` entity are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world
+    /// or storage of any component attached to the `from` entity
+    /// does not have enough capacity for the `to` entity.
+    /// Components of the `to` entity are not changed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`clone_into`][World::clone_into()] method.
+    pub fn try_clone_into(
+        &mut self,
+        from: E::Entity,
+        to: E::Entity,
+    ) -> Result<(), TryAttachError<StorageCapacityError, E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        for entity in [from, to] {
+            if !entities.contains(entity) {
+                let error = NotPresentError::new(entity);
+                return Err(error.into());
+            }
+        }
+        for storage in components.iter() {
+            // storage which tracks another type of entity cannot have components of provided entity
+            let Ok(true) = storage.is_attached(&from) else {
+                continue;
+            };
+            let mut to = iter::once(&to as &dyn ErasedEntity);
+            if let Ok(false) = storage.can_attach_all(&mut to) {
+                let error = StorageCapacityError::from_erased(storage);
+                return Err(TryAttachError::Storage(error));
+            }
+        }
+        for storage in components.iter_mut() {
+            let Ok(true) = storage.is_attached(&from) else {
+                continue;
+            };
+            let Ok(_) = storage.clone_component(&from, &to) else {
+                unreachable!("storage with attached component should track provided entity type");
+            };
+        }
+        Ok(())
+    }
+
+    /// Creates new entity in the world with copies of components of the bundle attached to provided entity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if storage of any bundle component
+    /// does not have enough capacity for the new entity.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_clone_entity_bundle`][World::try_clone_entity_bundle()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or one of bundle components was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn clone_entity_bundle<B>(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<E::Entity, EntityError<E::Entity>>
+    where
+        B: Bundle,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        match self.try_clone_entity_bundle::<B>(entity) {
+            Ok(clone) => Ok(clone),
+            Err(TryAttachError::NotRegistered(error)) => Err(error.into()),
+            Err(TryAttachError::NotPresent(error)) => Err(error.into()),
+            Err(TryAttachError::Storage(error)) => panic!("{error}"),
+        }
+    }
+
+    /// Tries to create new entity in the world with copies of components of the bundle attached to provided entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world,
+    /// one of bundle components was not registered in the world
+    /// or storage of any bundle component does not have enough capacity for the new entity.
+    /// New entity is destroyed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`clone_entity_bundle`][World::clone_entity_bundle()] method.
+    pub fn try_clone_entity_bundle<B>(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<E::Entity, TryAttachError<StorageCapacityError, E::Entity>>
+    where
+        B: Bundle,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let _ = B::is_attached(components, entity)?;
+        let clone = entities.create();
+        if let Err(error) = B::check_capacity(components, [clone]) {
+            let _ = entities.destroy(clone);
+            return Err(error.into());
+        }
+        let _ = B::duplicate(components, entity, clone)?;
+        Ok(clone)
+    }
+
+    /// Copies components of the bundle attached to the `from` entity to the `to` entity.
+    ///
+    /// Returns `true` if all components of the bundle were attached to the `from` entity.
+    /// Components which are not attached to the `from` entity are left untouched on the `to` entity.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if storage of any bundle component
+    /// does not have enough capacity for the `to` entity.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_clone_bundle_into`][World::try_clone_bundle_into()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world
+    /// or one of bundle components was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn clone_bundle_into<B>(
+        &mut self,
+        from: E::Entity,
+        to: E::Entity,
+    ) -> Result<bool, EntityError<E::Entity>>
+    where
+        B: Bundle,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        match self.try_clone_bundle_into::<B>(from, to) {
+            Ok(is_cloned) => Ok(is_cloned),
+            Err(TryAttachError::NotRegistered(error)) => Err(error.into()),
+            Err(TryAttachError::NotPresent(error)) => Err(error.into()),
+            Err(TryAttachError::Storage(error)) => panic!("{error}"),
+        }
+    }
+
+    /// Tries to copy components of the bundle attached to the `from` entity to the `to` entity.
+    ///
+    /// Returns `true` if all components of the bundle were attached to the `from` entity.
+    /// Components which are not attached to the `from` entity are left untouched on the `to` entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world,
+    /// one of bundle components was not registered in the world
+    /// or storage of any bundle component does not have enough capacity for the `to` entity.
+    /// Components of the `to` entity are not changed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`clone_bundle_into`][World::clone_bundle_into()] method.
+    pub fn try_clone_bundle_into<B>(
+        &mut self,
+        from: E::Entity,
+        to: E::Entity,
+    ) -> Result<bool, TryAttachError<StorageCapacityError, E::Entity>>
+    where
+        B: Bundle,
+        B::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        for entity in [from, to] {
+            if !entities.contains(entity) {
+                let error = NotPresentError::new(entity);
+                return Err(error.into());
+            }
+        }
+        B::check_capacity(components, [to])?;
+        let is_cloned = B::duplicate(components, from, to)?;
+        Ok(is_cloned)
+    }
+
     /// Retrieves a reference to the bundle which components are attached to provided entity.
     /// Returns [`None`] if provided entity does not have any of bundle components.
     ///
@@ -1051,17 +1497,75 @@ where
     {
         self.builder_from(bundle).try_build()
     }
-}
 
-impl<E, C, R> World<E, C, R>
-where
-    E: Entities,
-    C: ComponentsMut,
-    R: ResourcesMut,
-{
-    /// Checks if the world contains no entities, components or resources.
+    /// Spawns new entities with provided bundles in the current world.
+    /// Returns an iterator over newly created entities.
+    ///
+    /// Registration of the bundle components, capacity of the entity registry
+    /// and capacity of the bundle component storages are checked once before any entity is created,
+    /// so all the bundles are either attached to new entities or none of them.
+    /// Capacity of the storages is checked for entities which will be created next
+    /// if the entity registry is able to [predict](Entities::next_entities_hint()) them.
+    /// Otherwise, storages are checked for the worst case,
+    /// so storages which place components by the index of the entity cannot be used.
+    /// Bundles are attached to the entities while iterating,
+    /// but all the remaining bundles are attached when the iterator is dropped.
     ///
-    /// # Examples
+    /// # Errors
+    ///
+    /// This function will return an error
+    /// if one of components in the bundle was not registered in the world,
+    /// the world will fail to create new entities for all provided bundles
+    /// or storage of some bundle component does not have enough capacity for all provided bundles.
+    /// No entities are created in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn spawn_batch<I>(
+        &mut self,
+        bundles: I,
+    ) -> Result<impl ExactSizeIterator<Item = E::Entity>, TryBuildError<E::Err, StorageCapacityError>>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator,
+        I::Item: Bundle,
+        <I::Item as Bundle>::Storages: StorageBundle<Entity = E::Entity>,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        // null entity is never attached, so only the registration of the bundle is checked here
+        let _ = I::Item::is_attached(components, E::Entity::null())?;
+        let bundles = bundles.into_iter();
+        let count = bundles.len();
+        entities
+            .check_capacity(count)
+            .map_err(TryBuildError::Entities)?;
+        let next_entities = registry::next_entities_or_null(&*entities, count);
+        I::Item::check_capacity(components, next_entities)?;
+        let entities = entities
+            .try_create_many(count)
+            .map_err(TryBuildError::Entities)?;
+        let batch = SpawnBatch::new(bundles, entities, components);
+        Ok(batch)
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    C: ComponentsMut,
+    R: ResourcesMut,
+{
+    /// Checks if the world contains no entities, components or resources.
+    ///
+    /// # Examples
     ///
     /// ```
     /// todo!()
@@ -1094,3 +1598,1536 @@ where
         resources.clear();
     }
 }
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+    <E::Entity as Entity>::Index: TryFrom<usize> + PartialEq,
+    <E::Entity as Entity>::Generation: PartialOrd,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+    C: Components,
+{
+    /// Sets the parent of provided child entity,
+    /// appending the child to the end of children of the parent.
+    /// Returns previous parent of the child entity, or [`None`] if the child entity had no parent.
+    ///
+    /// Hierarchy can store exactly `N` child entities.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if hierarchy cannot store provided child or parent entity,
+    /// i.e. if index of any of them is not less than `N`.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_set_parent`][World::try_set_parent()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world,
+    /// hierarchy components were not registered in the world
+    /// or provided parent entity is the child entity itself or one of its descendants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn set_parent<const N: usize>(
+        &mut self,
+        child: E::Entity,
+        parent: E::Entity,
+    ) -> Result<Option<E::Entity>, HierarchyError<E::Entity>> {
+        match self.try_set_parent::<N>(child, parent) {
+            Err(HierarchyError::Capacity(err)) => panic!("{err}"),
+            result => result,
+        }
+    }
+
+    /// Tries to set the parent of provided child entity,
+    /// appending the child to the end of children of the parent.
+    /// Returns previous parent of the child entity, or [`None`] if the child entity had no parent.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world,
+    /// hierarchy components were not registered in the world,
+    /// provided parent entity is the child entity itself or one of its descendants
+    /// or hierarchy cannot store provided child or parent entity.
+    /// Hierarchy is not changed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`set_parent`][World::set_parent()] method.
+    pub fn try_set_parent<const N: usize>(
+        &mut self,
+        child: E::Entity,
+        parent: E::Entity,
+    ) -> Result<Option<E::Entity>, HierarchyError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        for entity in [child, parent] {
+            if !entities.contains(entity) {
+                let error = NotPresentError::new(entity);
+                return Err(error.into());
+            }
+        }
+        let (parents, children) = hierarchy::storages_mut::<_, _, N>(components)?;
+        let previous = hierarchy::try_set_parent(parents, children, child, parent)?;
+        Ok(previous)
+    }
+
+    /// Removes provided child entity from children of its parent.
+    /// Returns previous parent of the child entity, or [`None`] if the child entity had no parent.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn remove_parent<const N: usize>(
+        &mut self,
+        child: E::Entity,
+    ) -> Result<Option<E::Entity>, EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(child) {
+            let error = NotPresentError::new(child);
+            return Err(error.into());
+        }
+        let (parents, children) = hierarchy::storages_mut::<_, _, N>(components)?;
+        let previous = hierarchy::remove_parent(parents, children, child);
+        Ok(previous)
+    }
+
+    /// Returns the parent of provided entity, or [`None`] if provided entity has no parent.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn parent<const N: usize>(
+        &self,
+        entity: E::Entity,
+    ) -> Result<Option<E::Entity>, EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let (parents, _) = hierarchy::storages::<_, _, N>(components)?;
+        let parent = parents.get(entity).map(Parent::get);
+        Ok(parent)
+    }
+
+    /// Returns an iterator over children of provided entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn children<const N: usize>(
+        &self,
+        entity: E::Entity,
+    ) -> Result<ChildrenIter<'_, N, E::Entity>, EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let (parents, children) = hierarchy::storages::<_, _, N>(components)?;
+        let iter = ChildrenIter::new(parents, children, entity);
+        Ok(iter)
+    }
+
+    /// Returns an iterator over descendants of provided entity in depth-first order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn descendants_depth_first<const N: usize>(
+        &self,
+        entity: E::Entity,
+    ) -> Result<DepthFirstIter<'_, N, E::Entity>, EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let (parents, children) = hierarchy::storages::<_, _, N>(components)?;
+        let iter = DepthFirstIter::new(parents, children, entity);
+        Ok(iter)
+    }
+
+    /// Returns an iterator over descendants of provided entity in breadth-first order.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn descendants_breadth_first<const N: usize>(
+        &self,
+        entity: E::Entity,
+    ) -> Result<BreadthFirstIter<'_, N, E::Entity>, EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let (parents, children) = hierarchy::storages::<_, _, N>(components)?;
+        let iter = BreadthFirstIter::new(parents, children, entity);
+        Ok(iter)
+    }
+
+    /// Destroys provided entity with all of its descendants,
+    /// removing provided entity from children of its parent.
+    ///
+    /// Like [`destroy`][World::destroy()] method, this method does not remove components
+    /// of destroyed entities except hierarchy components.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or hierarchy components were not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn destroy_recursive<const N: usize>(
+        &mut self,
+        entity: E::Entity,
+    ) -> Result<(), EntityError<E::Entity>> {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(entity) {
+            let error = NotPresentError::new(entity);
+            return Err(error.into());
+        }
+        let (parents, children) = hierarchy::storages_mut::<_, _, N>(components)?;
+        loop {
+            let mut leaf = entity;
+            while let Some(list) = children.get(leaf) {
+                leaf = list.first();
+            }
+            let _ = hierarchy::remove_parent(parents, children, leaf);
+            let _ = entities.destroy(leaf);
+            if leaf == entity {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+    <E::Entity as Entity>::Index: TryFrom<usize> + PartialEq,
+    <E::Entity as Entity>::Generation: PartialOrd,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+    C: Components,
+{
+    /// Creates relationship of provided relation between source and target entities.
+    /// Returns `false` if these entities were already related by provided relation.
+    ///
+    /// Each source entity can have exactly `N` targets of the relation.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if source entity cannot have any more targets of the relation
+    /// or storage of [`Targets`] component cannot store targets of the source entity.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_relate`][World::try_relate()] method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world
+    /// or [`Targets`] component of the relation was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[track_caller]
+    pub fn relate<T, const N: usize>(
+        &mut self,
+        source: E::Entity,
+        target: E::Entity,
+    ) -> Result<bool, EntityError<E::Entity>>
+    where
+        T: Relation,
+    {
+        match self.try_relate::<T, N>(source, target) {
+            Ok(inserted) => Ok(inserted),
+            Err(RelationError::NotRegistered(error)) => Err(error.into()),
+            Err(RelationError::NotPresent(error)) => Err(error.into()),
+            Err(RelationError::Capacity(error)) => panic!("{error}"),
+            Err(RelationError::Storage(error)) => panic!("{error}"),
+        }
+    }
+
+    /// Tries to create relationship of provided relation between source and target entities.
+    /// Returns `false` if these entities were already related by provided relation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if any of provided entities does not present in the world,
+    /// [`Targets`] component of the relation was not registered in the world,
+    /// source entity cannot have any more targets of the relation
+    /// or storage of [`Targets`] component cannot store targets of the source entity.
+    /// Relationships are not changed in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`relate`][World::relate()] method.
+    pub fn try_relate<T, const N: usize>(
+        &mut self,
+        source: E::Entity,
+        target: E::Entity,
+    ) -> Result<bool, RelationError<E::Entity>>
+    where
+        T: Relation,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        for entity in [source, target] {
+            if !entities.contains(entity) {
+                let error = NotPresentError::new(entity);
+                return Err(error.into());
+            }
+        }
+        let Some(storage) = components.get_mut::<Targets<T, N, E::Entity>>() else {
+            let error = NotRegisteredError::new::<Targets<T, N, E::Entity>>();
+            return Err(error.into());
+        };
+        let inserted = match storage.get_mut(source) {
+            Some(targets) => targets.try_insert(target)?,
+            None => {
+                let targets = Targets::try_new(target)?;
+                let _ = storage.try_attach(source, targets)?;
+                true
+            }
+        };
+        Ok(inserted)
+    }
+
+    /// Removes relationship of provided relation between source and target entities.
+    /// Returns `false` if these entities were not related by provided relation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided source entity does not present in the world
+    /// or [`Targets`] component of the relation was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn unrelate<T, const N: usize>(
+        &mut self,
+        source: E::Entity,
+        target: E::Entity,
+    ) -> Result<bool, EntityError<E::Entity>>
+    where
+        T: Relation,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(source) {
+            let error = NotPresentError::new(source);
+            return Err(error.into());
+        }
+        let Some(storage) = components.get_mut::<Targets<T, N, E::Entity>>() else {
+            let error = NotRegisteredError::new::<Targets<T, N, E::Entity>>();
+            return Err(error.into());
+        };
+        let Some(targets) = storage.get_mut(source) else {
+            return Ok(false);
+        };
+        let removed = targets.remove(target);
+        if targets.len() == 0 {
+            let _ = storage.remove(source);
+        }
+        Ok(removed)
+    }
+
+    /// Returns an iterator over targets of provided relation of provided source entity.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided source entity does not present in the world
+    /// or [`Targets`] component of the relation was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn targets<T, const N: usize>(
+        &self,
+        source: E::Entity,
+    ) -> Result<TargetsIter<'_, E::Entity>, EntityError<E::Entity>>
+    where
+        T: Relation,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(source) {
+            let error = NotPresentError::new(source);
+            return Err(error.into());
+        }
+        let Some(storage) = components.get::<Targets<T, N, E::Entity>>() else {
+            let error = NotRegisteredError::new::<Targets<T, N, E::Entity>>();
+            return Err(error.into());
+        };
+        let iter = storage.get(source).map(Targets::iter).unwrap_or_default();
+        Ok(iter)
+    }
+
+    /// Applies [cleanup policy][Relation::CLEANUP] of provided relation
+    /// to all relationships which target entities were destroyed.
+    ///
+    /// Relationships which source entities were destroyed are removed as well.
+    /// Source entities destroyed by the policy are cleaned up too,
+    /// so destruction is propagated through the chain of relationships.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error
+    /// if [`Targets`] component of the relation was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn cleanup_relation<T, const N: usize>(&mut self) -> Result<(), NotRegisteredError>
+    where
+        T: Relation,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        let Some(storage) = components.get_mut::<Targets<T, N, E::Entity>>() else {
+            let error = NotRegisteredError::new::<Targets<T, N, E::Entity>>();
+            return Err(error);
+        };
+        loop {
+            let mut destroyed = false;
+            storage.retain(|source, targets| {
+                if !entities.contains(source) {
+                    return false;
+                }
+                let len = targets.len();
+                targets.retain(|target| entities.contains(target));
+                if targets.len() == len {
+                    return true;
+                }
+                match T::CLEANUP {
+                    CleanupPolicy::RemovePair => targets.len() > 0,
+                    CleanupPolicy::DestroySource => {
+                        let _ = entities.destroy(source);
+                        destroyed = true;
+                        false
+                    }
+                }
+            });
+            if !destroyed {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Destroys provided target entity and applies [cleanup policy][Relation::CLEANUP]
+    /// of provided relation to all the relationships which target is destroyed.
+    ///
+    /// Like [`destroy`][World::destroy()] method, this method does not remove components
+    /// of destroyed entities except [`Targets`] component of the relation.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if provided entity does not present in the world
+    /// or [`Targets`] component of the relation was not registered in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn destroy_target<T, const N: usize>(
+        &mut self,
+        target: E::Entity,
+    ) -> Result<(), EntityError<E::Entity>>
+    where
+        T: Relation,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+
+        if !entities.contains(target) {
+            let error = NotPresentError::new(target);
+            return Err(error.into());
+        }
+        if components.get::<Targets<T, N, E::Entity>>().is_none() {
+            let error = NotRegisteredError::new::<Targets<T, N, E::Entity>>();
+            return Err(error.into());
+        }
+        let _ = entities.destroy(target);
+        self.cleanup_relation::<T, N>()?;
+        Ok(())
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    E::Entity: PartialEq,
+    <E::Entity as Entity>::Index: TryFrom<usize> + PartialEq,
+    <E::Entity as Entity>::Generation: PartialOrd,
+    usize: TryFrom<<E::Entity as Entity>::Index>,
+    C: Components,
+{
+    /// Instantiates provided prefab in the world.
+    /// Returns all the created entities in depth-first order, so the root entity is always the first.
+    ///
+    /// Entities instantiated from child prefabs become children of the entity instantiated from the parent prefab,
+    /// so hierarchy components must be registered in the world if prefab has children.
+    /// Prefab can create exactly `N` entities, which is also the capacity of the hierarchy.
+    ///
+    /// Capacity of the component storages is checked for entities which will be created next
+    /// if the entity registry is able to [predict](Entities::next_entities_hint()) them.
+    /// Otherwise, storages are checked for the worst case,
+    /// so storages which place components by the index of the entity cannot be used.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some component of the prefab was not registered in the world,
+    /// prefab creates more than `N` entities,
+    /// the world will fail to create new entities for the prefab
+    /// or storage of some component of the prefab or of the hierarchy
+    /// does not have enough capacity for new entities.
+    /// Nothing is created in the world in such case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn instantiate<const N: usize>(
+        &mut self,
+        prefab: &Prefab<'_>,
+    ) -> Result<ArrayVec<E::Entity, N>, InstantiateError<E::Err>>
+    where
+        E: TryEntities,
+    {
+        self.instantiate_with(prefab, &[])
+    }
+
+    /// Instantiates provided prefab in the world with provided per-instance overrides.
+    /// Returns all the created entities in depth-first order, so the root entity is always the first.
+    ///
+    /// Overrides are attached to the root entity after components of the prefab,
+    /// replacing components of the same type.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some component of the prefab or some override
+    /// was not registered in the world, prefab creates more than `N` entities,
+    /// the world will fail to create new entities for the prefab
+    /// or storage of some component does not have enough capacity for new entities.
+    /// Nothing is created in the world in such case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn instantiate_with<const N: usize>(
+        &mut self,
+        prefab: &Prefab<'_>,
+        overrides: &[&dyn ErasedComponent],
+    ) -> Result<ArrayVec<E::Entity, N>, InstantiateError<E::Err>>
+    where
+        E: TryEntities,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        prefab::instantiate(entities, components, prefab, overrides)
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Clone,
+    C: SnapshotComponents,
+    R: Clone,
+{
+    /// Creates new snapshot of the current world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn snapshot(&self) -> Snapshot<E, C, R> {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        Snapshot {
+            entities: entities.clone(),
+            components: components.clone(),
+            resources: resources.clone(),
+        }
+    }
+
+    /// Records the whole state of the current world into provided snapshot,
+    /// reusing the snapshot instead of creating a new one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn snapshot_into(&self, snapshot: &mut Snapshot<E, C, R>) {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        snapshot.entities.clone_from(entities);
+        snapshot.components.clone_from(components);
+        snapshot.resources.clone_from(resources);
+    }
+
+    /// Records the state of the current world into provided snapshot,
+    /// copying only component storages which change tick was advanced since the snapshot was recorded.
+    /// Returns count of copied component storages.
+    ///
+    /// Entity and resource registries are always copied.
+    /// Provided snapshot should be created from the current world,
+    /// otherwise change ticks of the snapshot are meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn snapshot_delta_into(&self, snapshot: &mut Snapshot<E, C, R>) -> usize {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        snapshot.entities.clone_from(entities);
+        snapshot.resources.clone_from(resources);
+        components.record_changed(&mut snapshot.components)
+    }
+
+    /// Restores the state of the current world from provided snapshot.
+    ///
+    /// Only component storages which change tick differs from the snapshot are copied,
+    /// and their change ticks are advanced so any later delta will record them again.
+    /// Entity and resource registries are always copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn restore(&mut self, snapshot: &Snapshot<E, C, R>) {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        entities.clone_from(&snapshot.entities);
+        let _ = components.restore_changed(&snapshot.components);
+        resources.clone_from(&snapshot.resources);
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    <E::Entity as Entity>::Index: Hash,
+    <E::Entity as Entity>::Generation: Hash,
+    C: Components,
+{
+    /// Computes deterministic checksum of the world.
+    ///
+    /// Checksum includes all the entities of the world (their indices and generations)
+    /// and all the components which [opted in](Component::CHECKSUM) to the checksum.
+    /// Hashes of entities and their components are combined regardless of their order,
+    /// so checksum depends neither on the order in which components are stored
+    /// nor on the iteration order of the entity registry,
+    /// and worlds with the same state have the same checksum regardless of their layout.
+    /// Components which are still attached to destroyed entities are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn checksum<H>(&self) -> u64
+    where
+        H: Hasher + Default,
+    {
+        let mut state = H::default();
+        state.write_u64(self.entities_checksum::<H>());
+        for storage in self.storage_checksums::<H>() {
+            state.write_u64(storage.checksum());
+        }
+        state.finish()
+    }
+
+    /// Computes deterministic checksum of all the entities of the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn entities_checksum<H>(&self) -> u64
+    where
+        H: Hasher + Default,
+    {
+        let Self { entities, .. } = self;
+        unordered_checksum::<H, _, _>(entities.iter(), hash_entity)
+    }
+
+    /// Returns an iterator over checksums of each component storage
+    /// which components [opted in](Component::CHECKSUM) to the checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn storage_checksums<H>(&self) -> impl Iterator<Item = StorageChecksum> + '_
+    where
+        H: Hasher + Default,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        components
+            .iter()
+            .filter(|storage| storage.has_checksum())
+            .map(|storage| {
+                // components which are still attached to destroyed entities are skipped
+                let attached = entities.iter().filter_map(|entity| {
+                    let Ok(Some(component)) = storage.get(&entity) else {
+                        return None;
+                    };
+                    Some((entity, component))
+                });
+                let checksum =
+                    unordered_checksum::<H, _, _>(attached, |(entity, component), state| {
+                        hash_entity(entity, state);
+                        component.checksum(state);
+                    });
+                let type_name = storage.component_type_name();
+                let type_id = storage.component_type_id();
+                StorageChecksum::new(type_name, type_id, checksum)
+            })
+    }
+}
+
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    C: Components,
+{
+    /// Encodes all the entities of the world and their components described by provided schema
+    /// into the [binary format](binary), returning count of bytes written into the buffer.
+    ///
+    /// Components which were not registered in the world are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer is too small to encode the world,
+    /// some value is not supported by the binary format
+    /// or some component references an entity which does not present in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn encode(
+        &self,
+        schema: &[ComponentSchema<E::Entity>],
+        buf: &mut [u8],
+    ) -> Result<usize, EncodeError<E::Entity>>
+    where
+        E::Entity: Reflect + PartialEq,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        binary::encode(entities, components, schema, buf)
+    }
+
+    /// Decodes entities and their components from the [binary format](binary),
+    /// returning all the created entities in the order they were encoded.
+    ///
+    /// Entity references inside components are remapped to the created entities.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the buffer does not contain a valid encoded world,
+    /// it was encoded with another version of the format or schema of some component,
+    /// it contains more than `N` entities
+    /// or the entity registry of the world will fail to create all the encoded entities.
+    /// Components encoded with older versions of the component type cannot be decoded
+    /// (see [`decode_migrated`](World::decode_migrated()) to migrate them).
+    /// Nothing is created in the world in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn decode<const N: usize>(
+        &mut self,
+        schema: &[ComponentSchema<E::Entity>],
+        buf: &[u8],
+    ) -> Result<ArrayVec<E::Entity, N>, DecodeError>
+    where
+        E: TryEntities,
+        E::Entity: Reflect,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        binary::decode(entities, components, schema, &(), buf)
+    }
+
+    /// Decodes entities and their components from the [binary format](binary),
+    /// migrating components encoded with older [versions](Component::VERSION) of the component type
+    /// with provided migration registry.
+    ///
+    /// This is the migrating version of [`decode`](World::decode()) method.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error in the same cases as [`decode`](World::decode()) method,
+    /// or if some component cannot be migrated to the current version of its type.
+    /// Nothing is created in the world in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn decode_migrated<const N: usize>(
+        &mut self,
+        schema: &[ComponentSchema<E::Entity>],
+        migrations: &MigrationRegistry<E::Entity>,
+        buf: &[u8],
+    ) -> Result<ArrayVec<E::Entity, N>, DecodeError>
+    where
+        E: TryEntities,
+        E::Entity: Reflect,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        binary::decode(entities, components, schema, migrations, buf)
+    }
+
+    /// Rewrites entity keys stored in all the components of the world with provided mapper,
+    /// returning count of remapped components.
+    ///
+    /// Only components which [opted in](Component::MAP_ENTITIES) to remapping of entity references are remapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn map_entities(&mut self, mapper: &dyn EntityMapper) -> usize {
+        let Self { components, .. } = self;
+        components
+            .iter_mut()
+            .map(|storage| storage.map_entities(mapper))
+            .sum()
+    }
+
+    /// Moves all the entities of other world and their components into the current world,
+    /// returning the map of entity keys of other world to newly created entities.
+    ///
+    /// Only components which are registered in both worlds are moved.
+    /// Components which cannot be moved are left in other world
+    /// together with their entities, and all other entities of other world are destroyed.
+    /// Components which [opted in](Component::MAP_ENTITIES) to remapping of entity references
+    /// rewrite keys of moved entities with the returned map.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the world will fail to create new entities
+    /// for all the entities of other world
+    /// or storage of some component does not have enough capacity for moved components.
+    ///
+    /// If you wish to handle an error rather than panicking,
+    /// you should use [`try_merge_from`][World::try_merge_from()] method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    pub fn merge_from<OE, OC, OR>(&mut self, other: &mut World<OE, OC, OR>) -> EntityMap<E::Entity>
+    where
+        OE: Entities<Entity = E::Entity>,
+        OC: Components,
+        E::Entity: Ord,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        let World {
+            entities: other_entities,
+            components: other_components,
+            ..
+        } = other;
+
+        let mut map = EntityMap::new();
+        for old in other_entities.iter() {
+            let new = entities.create();
+            let _ = map.insert(old, new);
+        }
+        merge_components(components, other_entities, other_components, &map);
+        map
+    }
+
+    /// Tries to move all the entities of other world and their components into the current world,
+    /// returning the map of entity keys of other world to newly created entities.
+    ///
+    /// Capacity of the entity registry and capacity of the component storages are checked
+    /// before any entity is created, so no entities or components are moved on failure.
+    /// Capacity of the storages is checked for entities which will be created next
+    /// if the entity registry is able to [predict](Entities::next_entities_hint()) them.
+    /// Otherwise, storages are checked for the worst case,
+    /// so storages which place components by the index of the entity cannot be used.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the world will fail to create new entities
+    /// for all the entities of other world
+    /// or storage of some component does not have enough capacity for moved components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    ///
+    /// This is the fallible version of [`merge_from`][World::merge_from()] method.
+    #[cfg(feature = "alloc")]
+    pub fn try_merge_from<OE, OC, OR>(
+        &mut self,
+        other: &mut World<OE, OC, OR>,
+    ) -> Result<EntityMap<E::Entity>, MergeError<E::Err>>
+    where
+        E: TryEntities,
+        OE: Entities<Entity = E::Entity>,
+        OC: Components,
+        E::Entity: Ord,
+    {
+        let Self {
+            entities,
+            components,
+            ..
+        } = self;
+        let World {
+            entities: other_entities,
+            components: other_components,
+            ..
+        } = other;
+
+        let count = other_entities.len();
+        entities
+            .check_capacity(count)
+            .map_err(MergeError::Entities)?;
+        let next_entities = registry::next_entities_or_null(&*entities, count);
+        let mut next_map = EntityMap::new();
+        for (old, new) in iter::zip(other_entities.iter(), next_entities) {
+            let _ = next_map.insert(old, new);
+        }
+        for source in other_components.iter() {
+            let type_id = source.component_type_id();
+            let target = components
+                .iter()
+                .find(|storage| storage.component_type_id() == type_id);
+            let Some(target) = target else {
+                continue;
+            };
+            let mut attached = Vec::with_capacity(source.len());
+            source.for_each(&mut |entity, _| {
+                let entity = entity as &dyn Any;
+                if let Some(new) = entity.downcast_ref().and_then(|&old| next_map.get(old)) {
+                    attached.push(new);
+                }
+            });
+            let mut attached = attached.iter().map(|entity| entity as &dyn ErasedEntity);
+            if let Ok(false) = target.can_attach_all(&mut attached) {
+                let error = StorageCapacityError::from_erased(target);
+                return Err(MergeError::Storage(error));
+            }
+        }
+
+        let mut map = EntityMap::new();
+        let new_entities = entities
+            .try_create_many(count)
+            .map_err(MergeError::Entities)?;
+        for (old, new) in iter::zip(other_entities.iter(), new_entities) {
+            let _ = map.insert(old, new);
+        }
+        merge_components(components, other_entities, other_components, &map);
+        Ok(map)
+    }
+}
+
+/// Moves components of other world into the current world with provided map of entities,
+/// destroying entities of other world which have no components left.
+#[cfg(feature = "alloc")]
+fn merge_components<E, C, OE, OC>(
+    components: &mut C,
+    other_entities: &mut OE,
+    other_components: &mut OC,
+    map: &EntityMap<E>,
+) where
+    E: Entity + Ord,
+    C: Components,
+    OE: Entities<Entity = E>,
+    OC: Components,
+{
+    for source in other_components.iter_mut() {
+        let type_id = source.component_type_id();
+        let target = components
+            .iter_mut()
+            .find(|storage| storage.component_type_id() == type_id);
+        let Some(target) = target else {
+            continue;
+        };
+        source.retain(&mut |entity, component| {
+            let entity = entity as &dyn Any;
+            let Some(new) = entity.downcast_ref().and_then(|&old| map.get(old)) else {
+                return true;
+            };
+            let Ok(()) = target.attach(&new, component) else {
+                return true;
+            };
+            if let Ok(Some(component)) = target.get_mut(&new) {
+                let _ = component.map_entities(map);
+            }
+            false
+        });
+    }
+
+    let moved: Vec<_> = other_entities
+        .iter()
+        .filter(|entity| {
+            other_components
+                .iter()
+                .all(|storage| !matches!(storage.is_attached(entity), Ok(true)))
+        })
+        .collect();
+    let _ = other_entities.destroy_many(moved);
+}
+
+#[cfg(feature = "alloc")]
+impl<E, C, R> World<E, C, R>
+where
+    E: Entities,
+    C: Components,
+    R: Resources,
+{
+    /// Creates an iterator over entities which match provided [dynamic query](DynamicQuery),
+    /// and components fetched for them.
+    ///
+    /// Components described at runtime are fetched from [dynamic storages](DynamicStorages)
+    /// which are stored in the world as a resource.
+    /// Returns [`None`] if any of components required by the query was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn dynamic_query(&self, query: &DynamicQuery) -> Option<DynamicQueryIter<'_, E>> {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+
+        let dynamic = resources.get::<DynamicStorages<E::Entity>>();
+        query.iter(entities, components, dynamic)
+    }
+
+    /// Calls provided closure for each entity which matches provided [dynamic query](DynamicQuery),
+    /// and components fetched for it, including components written by the query.
+    ///
+    /// Components described at runtime are fetched from [dynamic storages](DynamicStorages)
+    /// which are stored in the world as a resource.
+    /// Returns `false` if any of components required by the query was not registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn dynamic_query_mut<F>(&mut self, query: &DynamicQuery, f: F) -> bool
+    where
+        E::Entity: PartialEq,
+        usize: TryFrom<<E::Entity as Entity>::Index>,
+        F: FnMut(E::Entity, &mut [Option<DynamicFetch<'_>>]),
+    {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+
+        let dynamic = resources.get_mut::<DynamicStorages<E::Entity>>();
+        query.for_each_mut(entities, components, dynamic, f)
+    }
+
+    /// Retrieves a mutable reflected view of the component with provided [type identifier](TypeId)
+    /// which is attached to provided entity.
+    ///
+    /// Component is reflected by the [type registry](TypeRegistry)
+    /// which is stored in the world as a resource.
+    /// Returns [`None`] if provided entity was not present in the world,
+    /// the component type was not registered in the type registry or in the world,
+    /// or the component is not attached to the entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn reflect_component(
+        &mut self,
+        entity: E::Entity,
+        type_id: TypeId,
+    ) -> Option<&mut dyn Reflect> {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+
+        if !entities.contains(entity) {
+            return None;
+        }
+        let registration = resources.get::<TypeRegistry>()?.get(type_id)?;
+        let storage = registration.storage_mut(components)?;
+        let Ok(Some(component)) = storage.get_mut(&entity) else {
+            return None;
+        };
+        let component: &mut dyn Any = component;
+        registration.reflect_mut(component)
+    }
+
+    /// Retrieves a mutable reflected view of the resource with provided [type identifier](TypeId).
+    ///
+    /// Resource is reflected by the [type registry](TypeRegistry)
+    /// which is stored in the world as a resource.
+    /// Returns [`None`] if the resource type was not registered in the type registry
+    /// or the resource was not inserted into the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn reflect_resource(&mut self, type_id: TypeId) -> Option<&mut dyn Reflect> {
+        let Self { resources, .. } = self;
+
+        let registration = resources.get::<TypeRegistry>()?.get(type_id)?;
+        let resource = resources.iter_mut().find_map(|resource| {
+            let resource: &mut dyn Any = resource;
+            ((*resource).type_id() == type_id).then_some(resource)
+        })?;
+        registration.reflect_mut(resource)
+    }
+
+    /// Writes provided entities with their reflected components
+    /// and reflected resources of the world into the [scene](scene).
+    ///
+    /// Components and resources are reflected by the [type registry](TypeRegistry)
+    /// which is stored in the world as a resource.
+    /// Components and resources which types were not registered in the type registry are skipped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if some of provided entities were not present in the world,
+    /// some value is not supported by the scene format
+    /// or some component references an entity which is not a part of the scene.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn save_scene(&self, scene: &[E::Entity]) -> Result<String, SaveError<E::Entity>>
+    where
+        E::Entity: Reflect + PartialEq,
+    {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        scene::save(entities, components, resources, scene)
+    }
+
+    /// Loads provided [scene](scene) into the world,
+    /// returning all the created entities in the order of the scene.
+    ///
+    /// Resources of the scene are written into resources which are already present in the world.
+    /// Entity references inside components are remapped to the created entities.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the scene is malformed,
+    /// does not match types registered in the world
+    /// or the world does not have enough capacity for entities or components of the scene.
+    /// Capacity of the component storages can be checked only if the entity registry
+    /// is able to [predict](Entities::next_entities_hint()) entities which will be created next.
+    /// Nothing is created or changed in the world in this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// todo!()
+    /// ```
+    pub fn load_scene(&mut self, scene: &str) -> Result<Vec<E::Entity>, LoadError>
+    where
+        E: TryEntities,
+        E::Entity: Reflect,
+    {
+        let Self {
+            entities,
+            components,
+            resources,
+        } = self;
+        scene::load(entities, components, resources, scene)
+    }
+}
+
+/// Hashes each item with the fresh state and combines these hashes with the count of items,
+/// so the checksum does not depend on the order of items.
+fn unordered_checksum<H, I, F>(items: I, mut hash: F) -> u64
+where
+    H: Hasher + Default,
+    I: IntoIterator,
+    F: FnMut(I::Item, &mut H),
+{
+    let (count, sum) = items
+        .into_iter()
+        .fold((0, 0), |(count, sum): (usize, u64), item| {
+            let mut state = H::default();
+            hash(item, &mut state);
+            (count + 1, sum.wrapping_add(state.finish()))
+        });
+    let mut state = H::default();
+    state.write_usize(count);
+    state.write_u64(sum);
+    state.finish()
+}
+
+fn hash_entity<E, H>(entity: E, state: &mut H)
+where
+    E: Entity,
+    E::Index: Hash,
+    E::Generation: Hash,
+    H: Hasher,
+{
+    entity.index().hash(state);
+    entity.generation().hash(state);
+}
+
+#[cfg(test)]
+mod tests {
+    use hlist::{HList, hlist};
+
+    use crate::{
+        component::{Component, storage::array::ArrayStorage},
+        entity::registry::array::ArrayRegistry,
+        world::{EntityError, TryAttachError, World},
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Counter(u32);
+
+    impl Component for Counter {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Position(i32);
+
+    impl Component for Position {
+        type Storage = ArrayStorage<Self, 2>;
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Tag;
+
+    impl Component for Tag {
+        type Storage = ArrayStorage<Self, 10>;
+    }
+
+    type Components = HList![ArrayStorage<Counter, 10>, ArrayStorage<Position, 2>];
+
+    fn world() -> World<ArrayRegistry<10>, Components, ()> {
+        let components = hlist![ArrayStorage::new(), ArrayStorage::new()];
+        World::with(ArrayRegistry::new(), components, ())
+    }
+
+    #[test]
+    fn component_entry() {
+        let mut world = world();
+        let entity = world.create();
+
+        let entry = world.component_entry::<Counter>(entity).unwrap();
+        assert_eq!(*entry.or_insert(Counter(1)), Counter(1));
+        let entry = world.component_entry::<Counter>(entity).unwrap();
+        let counter = entry
+            .and_modify(|counter| counter.0 += 1)
+            .or_insert(Counter(0));
+        assert_eq!(*counter, Counter(2));
+        assert_eq!(world.get::<Counter>(entity).unwrap(), Some(&Counter(2)));
+
+        assert!(matches!(
+            world.component_entry::<Tag>(entity),
+            Err(EntityError::NotRegistered(_)),
+        ));
+        world.destroy(entity).unwrap();
+        assert!(matches!(
+            world.component_entry::<Counter>(entity),
+            Err(EntityError::NotPresent(_)),
+        ));
+    }
+
+    #[test]
+    fn clone_entity() {
+        let mut world = world();
+        let entity = world.create();
+        let _ = world
+            .attach(entity, hlist![Counter(1), Position(2)])
+            .unwrap();
+
+        let clone = world.clone_entity(entity).unwrap();
+        assert_ne!(clone, entity);
+        let bundle = world.get::<HList![Counter, Position]>(clone).unwrap();
+        assert_eq!(bundle, Some(hlist![&Counter(1), &Position(2)]));
+
+        let other = world.create();
+        let _ = world.attach(other, Counter(5)).unwrap();
+        let _ = world.attach(entity, Counter(3)).unwrap();
+        World::clone_into(&mut world, entity, clone).unwrap();
+        assert_eq!(world.get::<Counter>(clone).unwrap(), Some(&Counter(3)));
+        World::clone_into(&mut world, other, clone).unwrap();
+        assert_eq!(world.get::<Counter>(clone).unwrap(), Some(&Counter(5)));
+        assert_eq!(world.get::<Position>(clone).unwrap(), Some(&Position(2)));
+
+        world.destroy(other).unwrap();
+        assert!(World::clone_into(&mut world, entity, other).is_err());
+        assert!(world.clone_entity(other).is_err());
+    }
+
+    #[test]
+    fn try_clone_entity_full() {
+        let mut world = world();
+        let [first, second, third] = [(); 3].map(|()| world.create());
+        let _ = world
+            .attach(first, hlist![Counter(1), Position(1)])
+            .unwrap();
+        let _ = world.attach(second, Position(2)).unwrap();
+
+        assert!(matches!(
+            world.try_clone_into(first, third),
+            Err(TryAttachError::Storage(_)),
+        ));
+        assert_eq!(world.get::<Counter>(third).unwrap(), None);
+        world.try_clone_into(first, second).unwrap();
+        assert_eq!(world.get::<Counter>(second).unwrap(), Some(&Counter(1)));
+        assert_eq!(world.get::<Position>(second).unwrap(), Some(&Position(1)));
+
+        assert!(matches!(
+            world.try_clone_entity(first),
+            Err(TryAttachError::Storage(_)),
+        ));
+        assert_eq!(world.entities().len(), 3);
+        let _ = world.remove::<Position>(first).unwrap();
+        let clone = world.try_clone_entity(first).unwrap();
+        assert_eq!(world.get::<Counter>(clone).unwrap(), Some(&Counter(1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clone_entity_full() {
+        let mut world = world();
+        let [first, second] = [(); 2].map(|()| world.create());
+        let _ = world.attach(first, Position(1)).unwrap();
+        let _ = world.attach(second, Position(2)).unwrap();
+
+        let _ = world.clone_entity(first);
+    }
+
+    #[test]
+    fn clone_bundle() {
+        let mut world = world();
+        let [first, second, third] = [(); 3].map(|()| world.create());
+        let _ = world
+            .attach(first, hlist![Counter(1), Position(1)])
+            .unwrap();
+
+        let clone = world.clone_entity_bundle::<Counter>(first).unwrap();
+        assert_eq!(world.get::<Counter>(clone).unwrap(), Some(&Counter(1)));
+        assert_eq!(world.get::<Position>(clone).unwrap(), None);
+
+        let cloned = world
+            .clone_bundle_into::<HList![Counter, Position]>(first, second)
+            .unwrap();
+        assert!(cloned);
+        let bundle = world.get::<HList![Counter, Position]>(second).unwrap();
+        assert_eq!(bundle, Some(hlist![&Counter(1), &Position(1)]));
+        let cloned = world.clone_bundle_into::<Counter>(third, second).unwrap();
+        assert!(!cloned);
+        assert_eq!(world.get::<Counter>(second).unwrap(), Some(&Counter(1)));
+
+        assert!(matches!(
+            world.try_clone_bundle_into::<Position>(first, third),
+            Err(TryAttachError::Storage(_)),
+        ));
+        assert!(matches!(
+            world.try_clone_entity_bundle::<Position>(first),
+            Err(TryAttachError::Storage(_)),
+        ));
+        assert!(matches!(
+            world.clone_entity_bundle::<Tag>(first),
+            Err(EntityError::NotRegistered(_)),
+        ));
+        assert_eq!(world.entities().len(), 4);
+    }
+}